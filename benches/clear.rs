@@ -0,0 +1,35 @@
+//! Compares the ways of emptying a large list: dropping it, calling `clear()`, 
+//! and popping every element from the front.  Run with `cargo bench`; there are 
+//! no dependencies, so each case is timed with `Instant` and the fastest of a 
+//! few runs is reported.
+
+use std::{hint::black_box, time::{Duration, Instant}};
+
+use cdl_list_rs::cdl_list::CdlList;
+
+const SIZE: u32 = 1_000_000;
+const RUNS: usize = 5;
+
+fn time<F: FnMut(CdlList<u32>)>(name: &str, mut empty: F) {
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        let list : CdlList<u32> = (0..SIZE).collect();
+        let start = Instant::now();
+        empty(black_box(list));
+        best = best.min(start.elapsed());
+    }
+    println!("{:<12} {:>10.2?}  ({:.1} ns/element)", name, best, best.as_nanos() as f64 / SIZE as f64);
+}
+
+fn main() {
+    time("drop", drop);
+    time("clear", |mut list| {
+        list.clear();
+        black_box(&list);
+    });
+    time("pop_front", |mut list| {
+        while let Some(val) = list.pop_front() {
+            black_box(val);
+        }
+    });
+}
@@ -0,0 +1,38 @@
+//! Compares summing a large list through the iterators' own `fold` against the 
+//! default one built on `next()`, for both `iter()` and `into_iter()`.  Run with 
+//! `cargo bench --bench sum`; as in `clear.rs`, each case is timed with `Instant` 
+//! and the fastest of a few runs is reported.
+
+use std::{hint::black_box, iter, mem, time::{Duration, Instant}};
+
+use cdl_list_rs::cdl_list::CdlList;
+
+const SIZE: u64 = 1_000_000;
+const RUNS: usize = 5;
+
+// sum gets the list by reference so that dropping it isn't timed for iter(); 
+// the into_iter() cases take its elements out, freeing them as they go.
+fn time<F: FnMut(&mut CdlList<u64>) -> u64>(name: &str, mut sum: F) {
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        let mut list : CdlList<u64> = (0..SIZE).collect();
+        let start = Instant::now();
+        let total = sum(black_box(&mut list));
+        best = best.min(start.elapsed());
+        assert_eq!(total, SIZE * (SIZE - 1) / 2);
+    }
+    println!("{:<16} {:>10.2?}  ({:.1} ns/element)", name, best, best.as_nanos() as f64 / SIZE as f64);
+}
+
+fn main() {
+    time("iter fold", |list| list.iter().map(|x| *x.borrow()).sum());
+    time("iter next", |list| {
+        let mut it = list.iter();
+        iter::from_fn(|| it.next()).map(|x| *x.borrow()).sum()
+    });
+    time("into_iter fold", |list| mem::replace(list, CdlList::new()).into_iter().sum());
+    time("into_iter next", |list| {
+        let mut it = mem::replace(list, CdlList::new()).into_iter();
+        iter::from_fn(|| it.next()).sum()
+    });
+}
@@ -0,0 +1,67 @@
+//! Applies an arbitrary sequence of operations to an arbitrary list, checking 
+//! every result against a `VecDeque` model.
+//! 
+//! Run with `cargo fuzz run list_ops` from the repository root.
+
+#![no_main]
+
+use std::collections::VecDeque;
+
+use arbitrary::Arbitrary;
+use cdl_list_rs::cdl_list::CdlList;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    PushFront(u8),
+    PushBack(u8),
+    PopFront,
+    PopBack,
+    InsertAt(usize, u8),
+    RemoveAt(usize),
+}
+
+fuzz_target!(|input: (CdlList<u8>, Vec<Op>)| {
+    let (mut list, ops) = input;
+
+    // build the model from the generated list without destroying it
+    let mut model : VecDeque<u8> = VecDeque::new();
+    for _ in 0..list.size() {
+        let v = list.pop_front().unwrap();
+        model.push_back(v);
+        list.push_back(v);
+    }
+
+    for op in ops {
+        match op {
+            Op::PushFront(v) => {
+                list.push_front(v);
+                model.push_front(v);
+            },
+            Op::PushBack(v) => {
+                list.push_back(v);
+                model.push_back(v);
+            },
+            Op::PopFront => assert_eq!(list.pop_front(), model.pop_front()),
+            Op::PopBack => assert_eq!(list.pop_back(), model.pop_back()),
+            Op::InsertAt(i, v) => {
+                let i = i % (model.len() + 1);
+                list.insert_at(i, v).unwrap();
+                model.insert(i, v);
+            },
+            Op::RemoveAt(i) => {
+                let i = i % (model.len() + 1);
+                assert_eq!(list.remove_at(i), model.remove(i));
+            },
+        }
+
+        assert_eq!(list.size(), model.len());
+        assert_eq!(list.peek_front().map(|v| *v), model.front().copied());
+        assert_eq!(list.peek_back().map(|v| *v), model.back().copied());
+    }
+
+    while let Some(v) = model.pop_front() {
+        assert_eq!(list.pop_front(), Some(v));
+    }
+    assert!(list.is_empty());
+});
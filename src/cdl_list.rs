@@ -20,7 +20,40 @@
 //! which is always a weak pointer to the head, so no reference cycle is created.  For 
 //! more on `Rc<T>`, `RefCell<T>`, and reference cycles, see [the Rust book](https://doc.rust-lang.org/book/ch15-04-rc.html).
 
-use std::{cell::{RefCell, Ref}, rc::{Rc, Weak}, fmt::{Debug, self}};
+#[cfg(feature = "num")]
+mod num;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "instrumentation")]
+mod instrumentation;
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "proptest")]
+mod proptest;
+mod codec;
+mod cursor;
+mod diff;
+mod frozen;
+mod handle;
+mod iter;
+mod io;
+mod view;
+
+pub use codec::{Decode, Encode};
+pub use cursor::{Cursor, CursorMut};
+pub use diff::Edit;
+pub use frozen::FrozenCdlList;
+pub use handle::NodeHandle;
+pub use iter::{Cycle, Drain, ExtractIf, IntoIter, Iter, IterMut};
+pub use view::ListView;
+#[cfg(feature = "instrumentation")]
+pub use instrumentation::ListStats;
+#[cfg(feature = "proptest")]
+pub use self::proptest::cdl_list_strategy;
+
+use std::{cell::{RefCell, Ref, RefMut}, rc::{Rc, Weak}, fmt::{Debug, self}, hash::{Hash, Hasher}, ops::{Add, Bound, ControlFlow, Range, RangeBounds, Sub}, marker::PhantomData, cmp::Ordering, collections::{BinaryHeap, LinkedList, VecDeque}};
 
 #[derive(Debug)]
 enum LinkType<T> {
@@ -28,7 +61,7 @@ enum LinkType<T> {
     WeakLink(Weak<RefCell<T>>)
 }
 
-impl<T: Debug> std::clone::Clone for LinkType<Node<T>> {
+impl<T> std::clone::Clone for LinkType<Node<T>> {
     fn clone(&self) -> Self {
         match self {
             Self::StrongLink(sl) => Self::StrongLink(sl.clone()),
@@ -37,13 +70,109 @@ impl<T: Debug> std::clone::Clone for LinkType<Node<T>> {
     }
 }
 
+impl<T> LinkType<T> {
+    // Returns a strong reference to the linked node, regardless of link type
+    fn upgrade(&self) -> Rc<RefCell<T>> {
+        match self {
+            Self::StrongLink(sl) => Rc::clone(sl),
+            Self::WeakLink(wl) => Weak::upgrade(wl).unwrap(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
-struct Node<T: Debug> {
+struct Node<T> {
     next: Option<LinkType<Node<T>>>, 
     prev: Option<LinkType<Node<T>>>,
+    owner: Option<Rc<Owner>>,
     data: T
 }
 
+// Identifies the list a node is linked into, so that a NodeHandle can tell 
+// whether it belongs to a given list.  Each list has its own Owner, and a node 
+// takes on the Owner of whichever list last linked it in.
+//
+// Appending a list hands all of its nodes over at once without visiting them, so 
+// instead the appended list's Owner is forwarded to the Owner of the list they 
+// joined, and the appended list starts over with a fresh one.  A node belongs to 
+// the list whose Owner is at the end of its Owner's forwarding chain.  Only a 
+// list's current Owner is ever forwarded to, and an Owner is never forwarded 
+// again once replaced, so the chains can't loop.
+#[derive(Debug, Default)]
+struct Owner {
+    forward: RefCell<Option<Rc<Owner>>>
+}
+
+impl Owner {
+    // Follows owner's forwarding chain to its end, then points every Owner along 
+    // the way straight at the end so later lookups are shorter.
+    fn root(owner: &Rc<Owner>) -> Rc<Owner> {
+        let mut root = Rc::clone(owner);
+        loop {
+            let next = match root.forward.borrow().as_ref() {
+                Some(next) => Rc::clone(next),
+                None => break,
+            };
+            root = next;
+        }
+
+        let mut node = Rc::clone(owner);
+        while !Rc::ptr_eq(&node, &root) {
+            let next = node.forward.replace(Some(Rc::clone(&root))).unwrap();
+            node = next;
+        }
+        root
+    }
+}
+
+// Unwinds a forwarding chain one Owner at a time, since dropping it recursively 
+// could overflow the stack after many appends.
+impl Drop for Owner {
+    fn drop(&mut self) {
+        let mut next = self.forward.take();
+        while let Some(owner) = next {
+            next = Rc::try_unwrap(owner).ok().and_then(|owner| owner.forward.take());
+        }
+    }
+}
+
+// The head and tail of a chain of nodes that isn't linked into a list yet.
+type Chain<T> = (Rc<RefCell<Node<T>>>, Rc<RefCell<Node<T>>>);
+
+// The open chains of a sort, merge, or walk in progress, taken out of list 
+// until the work is done.  Dropping the guard links whatever chains are left 
+// end to end and closes them into list's ring, so if a callback panics the 
+// list keeps every element it still has, like a slice does, and no chain is 
+// freed recursively.
+struct OpenChains<'a, T> {
+    list: &'a mut CdlList<T>,
+    size: usize,
+    merged: Option<Chain<T>>,
+    left: Option<Rc<RefCell<Node<T>>>>,
+    right: Option<Rc<RefCell<Node<T>>>>,
+    rest: Option<Rc<RefCell<Node<T>>>>
+}
+
+impl<T> Drop for OpenChains<'_, T> {
+    fn drop(&mut self) {
+        let mut chains = [self.merged.take().map(|(head, _)| head), self.left.take(), self.right.take(), self.rest.take()]
+            .into_iter()
+            .flatten();
+        let Some(head) = chains.next() else {
+            return;
+        };
+
+        let mut end = Rc::clone(&head);
+        for chain in chains {
+            while let Some(next) = Node::chain_next(&end) {
+                end = next;
+            }
+            end.borrow_mut().next = Some(LinkType::StrongLink(chain));
+        }
+        self.list.close_chain(head, self.size);
+    }
+}
+
 impl<T: Debug> std::fmt::Display for Node<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let temp = format!("{:?}", &self.data);
@@ -51,27 +180,335 @@ impl<T: Debug> std::fmt::Display for Node<T> {
     }
 }
 
-impl<T: Debug> Node<T> {
+impl<T> Node<T> {
     fn new(t: T) -> Node<T> {
         Self {
             next: None, 
             prev: None, 
+            owner: None,
             data: t
         }
     }
+
+    // node->next, which is only a weak link when node is the tail
+    fn next_of(node: &Rc<RefCell<Node<T>>>) -> Rc<RefCell<Node<T>>> {
+        node.borrow().next.as_ref().unwrap().upgrade()
+    }
+
+    // node->prev, which is always a weak link
+    fn prev_of(node: &Rc<RefCell<Node<T>>>) -> Rc<RefCell<Node<T>>> {
+        node.borrow().prev.as_ref().unwrap().upgrade()
+    }
+
+    // Adds a new node holding t, belonging to owner, to the end of a chain, 
+    // starting the chain if there isn't one yet.  Like a list's nodes, the 
+    // chain's next links are strong and its prev links are weak, but its ends 
+    // are left open.
+    fn push_to_chain(chain: &mut Option<Chain<T>>, t: T, owner: &Rc<Owner>) {
+        let ref_n = Rc::new(RefCell::new(Node::new(t)));
+        ref_n.borrow_mut().owner = Some(Rc::clone(owner));
+
+        *chain = Some(match chain.take() {
+            Some((head, tail)) => {
+                ref_n.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&tail)));
+                tail.borrow_mut().next = Some(LinkType::StrongLink(Rc::clone(&ref_n)));
+                (head, ref_n)
+            },
+            None => (Rc::clone(&ref_n), ref_n),
+        });
+    }
+
+    // Returns the node after node in an open chain, or None at the chain's end.
+    fn chain_next(node: &Rc<RefCell<Node<T>>>) -> Option<Rc<RefCell<Node<T>>>> {
+        match &node.borrow().next {
+            Some(LinkType::StrongLink(next)) => Some(Rc::clone(next)),
+            _ => None,
+        }
+    }
+
+    // Cuts an open chain after its first n nodes, returning the rest of it, or 
+    // None if the chain has no more than n nodes.
+    fn cut_run(first: &Rc<RefCell<Node<T>>>, n: usize) -> Option<Rc<RefCell<Node<T>>>> {
+        let mut node = Rc::clone(first);
+        for _ in 1..n {
+            node = Node::chain_next(&node)?;
+        }
+
+        let rest = node.borrow_mut().next.take();
+        match rest {
+            Some(LinkType::StrongLink(rest)) => Some(rest),
+            _ => None,
+        }
+    }
+
+    // Merges two sorted open chains onto the end of merged, taking from left 
+    // unless right's next node is strictly less, so the merge is stable.  Only 
+    // next links are updated.  Each node stays in left, right, or merged while 
+    // compare runs, so a panic can't lose it.  on_right is called with each node 
+    // taken from right and its index among the nodes this call merges.
+    fn merge_runs<F, R>(merged: &mut Option<Chain<T>>, left: &mut Option<Rc<RefCell<Node<T>>>>, right: &mut Option<Rc<RefCell<Node<T>>>>, compare: &mut F, on_right: &mut R)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        R: FnMut(usize, &Rc<RefCell<Node<T>>>),
+    {
+        let mut index = 0;
+        while let (Some(l), Some(r)) = (&*left, &*right) {
+            let from_right = compare(&r.borrow().data, &l.borrow().data) == Ordering::Less;
+            let side = if from_right { &mut *right } else { &mut *left };
+            let node = side.take().unwrap();
+            *side = Node::chain_next(&node);
+            node.borrow_mut().next = None;
+            Node::link_to_chain(merged, Rc::clone(&node));
+            if from_right {
+                on_right(index, &node);
+            }
+            index += 1;
+        }
+
+        // whatever is left of either side is already in order
+        let from_right = left.is_none();
+        if let Some(rest) = left.take().or(right.take()) {
+            let mut end = Rc::clone(&rest);
+            Node::link_to_chain(merged, rest);
+            loop {
+                if from_right {
+                    on_right(index, &end);
+                }
+                index += 1;
+                match Node::chain_next(&end) {
+                    Some(next) => end = next,
+                    None => break,
+                }
+            }
+            if let Some((_, tail)) = merged {
+                *tail = end;
+            }
+        }
+    }
+
+    // Links node, which may start a longer chain, after the end of chain.
+    fn link_to_chain(chain: &mut Option<Chain<T>>, node: Rc<RefCell<Node<T>>>) {
+        *chain = Some(match chain.take() {
+            Some((head, tail)) => {
+                tail.borrow_mut().next = Some(LinkType::StrongLink(Rc::clone(&node)));
+                (head, node)
+            },
+            None => (Rc::clone(&node), node),
+        });
+    }
 }
 
-/// A circular doubly linked list as defined in the [module-level documentation](`crate::cdl_list`).
-#[derive(Debug)]
-pub struct CdlList<T: Debug> {
+/// A shared borrow of an element somewhere inside a [`CdlList`], returned by 
+/// methods like [`CdlList::get_from_back()`].
+/// 
+/// [`CdlList::peek_front()`] can return a [`std::cell::Ref<T>`] directly because the 
+/// head node is owned by the list itself.  Every other node is only reachable through 
+/// the `RefCell`s of the nodes before it, so a `Ref` to it cannot outlive the walk that 
+/// found it.  Instead, an `ElemRef` keeps the node alive and borrows its data on 
+/// demand with [`ElemRef::borrow()`].  It also borrows the list, so the list cannot 
+/// be modified while any `ElemRef` exists.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list : CdlList<u32> = CdlList::new();
+/// list.push_back(1);
+/// list.push_back(2);
+/// list.push_back(3);
+/// 
+/// let e = list.get_from_back(1).unwrap();
+/// assert_eq!(*e.borrow(), 2);
+/// ```
+/// 
+/// The list stays borrowed until the `ElemRef` is dropped, not just until its 
+/// last use, so the element can't be removed out from under it:
+/// 
+/// ```compile_fail
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list = CdlList::from([1, 2, 3]);
+/// 
+/// let e = list.get(1).unwrap();
+/// assert_eq!(*e.borrow(), 2);
+/// list.remove_at(1);
+/// ```
+pub struct ElemRef<'a, T> {
+    node: Rc<RefCell<Node<T>>>,
+    _list: PhantomData<&'a CdlList<T>>
+}
+
+impl<'a, T> ElemRef<'a, T> {
+    fn new(node: Rc<RefCell<Node<T>>>) -> ElemRef<'a, T> {
+        ElemRef { node, _list: PhantomData }
+    }
+
+    /// Immutably borrows the element's data.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| &node.data)
+    }
+}
+
+impl<T: Debug> Debug for ElemRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ElemRef").field(&*self.borrow()).finish()
+    }
+}
+
+// ElemRef holds a strong reference to its node.  This keeps the list borrowed 
+// until the ElemRef is dropped, rather than until its last use, so the node 
+// can't be removed while it is still referenced.
+impl<T> Drop for ElemRef<'_, T> {
+    fn drop(&mut self) {}
+}
+
+/// A mutable borrow of an element somewhere inside a [`CdlList`], returned by 
+/// methods like [`CdlList::peek_handle_mut()`].  This is the mutable counterpart 
+/// of [`ElemRef`]: it mutably borrows the list, and lends out the element's data 
+/// with [`ElemMut::borrow_mut()`].
+pub struct ElemMut<'a, T> {
+    node: Rc<RefCell<Node<T>>>,
+    _list: PhantomData<&'a mut CdlList<T>>
+}
+
+impl<'a, T> ElemMut<'a, T> {
+    fn new(node: Rc<RefCell<Node<T>>>) -> ElemMut<'a, T> {
+        ElemMut { node, _list: PhantomData }
+    }
+
+    /// Immutably borrows the element's data.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| &node.data)
+    }
+
+    /// Mutably borrows the element's data.
+    pub fn borrow_mut(&mut self) -> RefMut<'_, T> {
+        RefMut::map(self.node.borrow_mut(), |node| &mut node.data)
+    }
+}
+
+impl<T: Debug> Debug for ElemMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ElemMut").field(&*self.borrow()).finish()
+    }
+}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for ElemMut<'_, T> {
+    fn drop(&mut self) {}
+}
+
+/// A circular doubly linked list as defined in the [module-level documentation](mod@crate::cdl_list).
+/// 
+/// The elements can be of any type.  Only formatting the list, with its `Debug` 
+/// and `Display` impls, requires them to implement `Debug`.
+pub struct CdlList<T> {
     head: Option<Rc<RefCell<Node<T>>>>,
     tail: Option<Rc<RefCell<Node<T>>>>,
-    size: usize
+    size: usize,
+    owner: Rc<Owner>,
+    observer: Option<Observer<T>>,
+    #[cfg(feature = "instrumentation")]
+    counters: instrumentation::Counters,
+    #[cfg(feature = "tracing")]
+    trace_values: Option<trace::FormatValue<T>>
+}
+
+impl<T: Debug> Debug for CdlList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CdlList")
+            .field("head", &self.head)
+            .field("tail", &self.tail)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A callback registered with [`CdlList::set_observer()`].
+pub type Observer<T> = Box<dyn FnMut(ListEvent<&T>)>;
+
+/// A change to a [`CdlList`], reported to the observer registered with 
+/// [`CdlList::set_observer()`].  `V` is a reference to the element that was 
+/// added or removed, except for [`ListEvent::Cleared`], which reports every 
+/// element at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ListEvent<V> {
+    /// An element was pushed to the front (`front == true`) or back of the list.
+    Pushed {
+        /// Whether the element was pushed to the front of the list
+        front: bool, 
+        /// The pushed element
+        value: V
+    },
+    /// An element was popped from the front (`front == true`) or back of the list.
+    Popped {
+        /// Whether the element was popped from the front of the list
+        front: bool, 
+        /// The popped element
+        value: V
+    },
+    /// An element was inserted, and is now at `index`.
+    InsertedAt {
+        /// The element's index after insertion
+        index: usize, 
+        /// The inserted element
+        value: V
+    },
+    /// The element at `index` was removed.
+    RemovedAt {
+        /// The element's index before removal
+        index: usize, 
+        /// The removed element
+        value: V
+    },
+    /// Every element was removed by [`CdlList::clear()`].
+    Cleared {
+        /// The number of elements removed
+        len: usize
+    },
+}
+
+/// The reason [`CdlList::move_range()`] rejected a move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveRangeError {
+    /// The source range is reversed or extends past the end of the list
+    SourceOutOfBounds,
+    /// The destination index is greater than the list's size
+    DestinationOutOfBounds,
+    /// The destination index falls strictly inside the source range
+    DestinationInSource,
 }
 
-impl<T: Debug> std::ops::Drop for CdlList<T> {
+impl fmt::Display for MoveRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::SourceOutOfBounds => "source range out of bounds",
+            Self::DestinationOutOfBounds => "destination index out of bounds",
+            Self::DestinationInSource => "destination index inside source range",
+        })
+    }
+}
+
+impl std::error::Error for MoveRangeError {}
+
+/// An index passed to a [`CdlList`] method was past the end of the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    /// The rejected index
+    pub index: usize,
+    /// The size of the list at the time
+    pub size: usize
+}
+
+impl fmt::Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index {} out of bounds for size {}", self.index, self.size)
+    }
+}
+
+impl std::error::Error for IndexOutOfBounds {}
+
+impl<T> std::ops::Drop for CdlList<T> {
     fn drop(&mut self) {
-        while self.pop_front().is_some() {}
+        self.free_nodes();
     }
 }
 
@@ -84,7 +521,7 @@ impl<T: Debug> fmt::Display for CdlList<T> {
         write!(f, "... <=> ")?;
 
         // get pointer to head
-        let mut node = Rc::clone(&self.head.as_ref().unwrap());
+        let mut node = Rc::clone(self.head.as_ref().unwrap());
 
         // print each data point (by using Debug)
         let mut count: usize = 0;
@@ -96,11 +533,9 @@ impl<T: Debug> fmt::Display for CdlList<T> {
             }
 
             let next = node.as_ref().borrow().next.clone().unwrap();
-            match next {
-                LinkType::StrongLink(sl) => {
-                    node = sl;
-                }, 
-                _ => () // on last iteration, next is a weak link
+            // on last iteration, next is a weak link
+            if let LinkType::StrongLink(sl) = next {
+                node = sl;
             }
 
             count += 1;
@@ -110,7 +545,159 @@ impl<T: Debug> fmt::Display for CdlList<T> {
     }
 }
 
-impl<T: Debug> CdlList<T> {
+impl<T> Default for CdlList<T> {
+    /// Returns an empty list, like [`CdlList::new()`].
+    fn default() -> Self {
+        CdlList::new()
+    }
+}
+
+impl<T: Clone> Clone for CdlList<T> {
+    /// Returns a deep copy of the list: every element is cloned into a new ring of 
+    /// nodes, so changing either list never affects the other.  The copy has no 
+    /// observer, and handles to the original's elements don't refer to the copy's.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from([1, 2, 3]);
+    /// let mut copy = list.clone();
+    /// 
+    /// copy.push_back(4);
+    /// *copy.peek_front_mut().unwrap() = 0;
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 2 <=> 3 <=> ...");
+    /// ```
+    fn clone(&self) -> Self {
+        self.repeat(1)
+    }
+}
+
+impl<T: PartialEq> PartialEq for CdlList<T> {
+    /// Returns whether both lists have the same elements in the same order, 
+    /// starting from their heads.  A rotation of a list isn't equal to it; see 
+    /// [`CdlList::eq_cyclic_undirected()`] for comparing rings regardless of where 
+    /// they start.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_front(2);
+    /// list.push_back(3);
+    /// list.push_front(1);
+    /// 
+    /// assert!(list == CdlList::from([1, 2, 3]));
+    /// assert!(list != CdlList::from([2, 3, 1]));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_by(other, |a, b| a == b)
+    }
+}
+
+impl<T: Eq> Eq for CdlList<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for CdlList<T> {
+    /// Returns whether the list holds the slice's elements, in order from head to 
+    /// tail.  The sizes are compared first, and the walk stops at the first 
+    /// element that differs.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([3, 1, 2]);
+    /// assert!(list == [3, 1, 2][..]);
+    /// 
+    /// list.rotate_left(1);
+    /// assert!(list == [1, 2, 3][..]);
+    /// ```
+    fn eq(&self, other: &[U]) -> bool {
+        self.size == other.len() && self.iter().zip(other).all(|(a, b)| *a.borrow() == *b)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<&[U]> for CdlList<T> {
+    /// Like comparing with `[U]`, so a list can be compared with a borrowed slice 
+    /// directly.
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for CdlList<T> {
+    /// Like comparing with `[U]`, so lists can be checked against array literals.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from("abc");
+    /// assert_eq!(list, ['a', 'b', 'c']);
+    /// ```
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for CdlList<T> {
+    /// Like comparing with `[U]`, using the vector's elements in order.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list : CdlList<u32> = (1..=3).collect();
+    /// assert_eq!(list, vec![1, 2, 3]);
+    /// assert_ne!(list, Vec::new());
+    /// ```
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for CdlList<T> {
+    /// Compares two lists lexicographically from their heads, the same way `Vec` 
+    /// and `LinkedList` compare.  The first pair of elements that differ decides 
+    /// the result, and if one list is a prefix of the other, the shorter one is 
+    /// less.  Returns `None` as soon as a pair of elements can't be compared.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// assert!(CdlList::from([1, 2, 3]) < CdlList::from([1, 3]));
+    /// assert!(CdlList::from([1, 2]) < CdlList::from([1, 2, 0]));
+    /// assert!(CdlList::from([f64::NAN]).partial_cmp(&CdlList::from([1.0])).is_none());
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        for (a, b) in self.iter().zip(other) {
+            match a.borrow().partial_cmp(&*b.borrow()) {
+                Some(Ordering::Equal) => {}
+                ord => return ord,
+            }
+        }
+        self.size.partial_cmp(&other.size)
+    }
+}
+
+impl<T: Ord> Ord for CdlList<T> {
+    /// Compares two lists lexicographically from their heads, like 
+    /// [`CdlList::cmp_by()`] with [`Ord::cmp()`].  This lets lists be sorted or 
+    /// kept in a `BTreeSet`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::collections::BTreeSet;
+    /// 
+    /// let set : BTreeSet<CdlList<u32>> = [vec![2], vec![1, 5], vec![1]].into_iter().map(CdlList::from).collect();
+    /// assert_eq!(*set.first().unwrap(), [1]);
+    /// assert_eq!(*set.last().unwrap(), [2]);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_by(other, T::cmp)
+    }
+}
+
+impl<T: Hash> Hash for CdlList<T> {
+    /// Hashes the size of the list followed by each element from head to tail, so 
+    /// equal lists hash the same.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.size);
+        self.walk(|val| val.hash(state));
+    }
+}
+
+impl<T> CdlList<T> {
     /// Returns a new CdlList without any values.  List should be defined as mutable 
     /// to add elements to it.
     /// 
@@ -120,7 +707,17 @@ impl<T: Debug> CdlList<T> {
     /// let mut list : CdlList<u32> = CdlList::new();
     /// ```
     pub fn new() -> CdlList<T> {
-        CdlList { head: None, tail: None, size: 0 }
+        CdlList {
+            head: None,
+            tail: None,
+            size: 0,
+            owner: Rc::default(),
+            observer: None,
+            #[cfg(feature = "instrumentation")]
+            counters: Default::default(),
+            #[cfg(feature = "tracing")]
+            trace_values: None
+        }
     }
 
     /// Returns whether or not the list is empty.
@@ -175,6 +772,9 @@ impl<T: Debug> CdlList<T> {
     /// ```
     pub fn push_front(&mut self, t: T) {
         self.push(t, true);
+
+        let head = Rc::clone(self.head.as_ref().unwrap());
+        self.notify_node(&head, |value| ListEvent::Pushed { front: true, value });
     }
 
     /// Pushes an element to the back of the list, making it the new tail and 
@@ -198,14 +798,74 @@ impl<T: Debug> CdlList<T> {
     /// ```
     pub fn push_back(&mut self, t: T) {
         self.push(t, false);
+
+        let tail = Rc::clone(self.tail.as_ref().unwrap());
+        self.notify_node(&tail, |value| ListEvent::Pushed { front: false, value });
+    }
+
+    /// Prepends the iterator's items to the front of the list, keeping them in the 
+    /// order the iterator gives them, so the first item becomes the new head.  This 
+    /// is the front counterpart of [`Extend::extend()`], and like it, links the 
+    /// items into a chain first and splices it on before the head in one step. 
+    /// Each item is then reported to the list's observer as pushed to the front, 
+    /// last item first, which is the order [`CdlList::push_front()`] would need to 
+    /// build the same list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([4, 5]);
+    /// list.extend_front(1..=3);
+    /// list.extend([6, 7]);
+    /// assert_eq!(list, CdlList::from([1, 2, 3, 4, 5, 6, 7]));
+    /// ```
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let old_tail = self.tail.clone();
+
+        let mut chain = None;
+        let mut len = 0;
+        for val in iter {
+            Node::push_to_chain(&mut chain, val, &self.owner);
+            len += 1;
+        }
+
+        let Some((head, tail)) = chain else {
+            return;
+        };
+        self.link_chain_back(Rc::clone(&head), Rc::clone(&tail), len);
+
+        // the chain went in after the old tail, so moving the seam to just before 
+        // the chain puts it in front instead
+        if let Some(old_tail) = old_tail {
+            let old_head = self.head.replace(Rc::clone(&head)).unwrap();
+            tail.borrow_mut().next = Some(LinkType::StrongLink(old_head));
+            old_tail.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(&head)));
+            self.tail = Some(old_tail);
+        }
+
+        let mut node = tail;
+        for i in 0..len {
+            self.record_push();
+            self.notify_node(&node, |value| ListEvent::Pushed { front: true, value });
+            if i + 1 < len {
+                node = Node::prev_of(&node);
+            }
+        }
     }
 
     fn push(&mut self, t : T, insert_front: bool) {
         let n = Node::new(t);
         let ref_n = Rc::new(RefCell::new(n));
-        
+
+        self.push_node(ref_n, insert_front);
+        self.record_push();
+    }
+
+    // Links an existing, detached node in at the head or tail.  This lets
+    // nodes be moved between lists without reallocating them.
+    fn push_node(&mut self, ref_n: Rc<RefCell<Node<T>>>, insert_front: bool) {
         let ref_n_clone = Rc::clone(&ref_n);
         let mut ref_n_mut = ref_n_clone.as_ref().borrow_mut();
+        ref_n_mut.owner = Some(Rc::clone(&self.owner));
 
         if self.is_empty() {
             // node's next and prev links point to self
@@ -221,8 +881,8 @@ impl<T: Debug> CdlList<T> {
             self.head = Some(Rc::clone(&ref_n));
             self.tail = Some(ref_n);
         } else {
-            let head_ref = Rc::clone(&self.head.as_ref().unwrap());
-            let tail_ref = Rc::clone(&self.tail.as_ref().unwrap());
+            let head_ref = Rc::clone(self.head.as_ref().unwrap());
+            let tail_ref = Rc::clone(self.tail.as_ref().unwrap());
 
             // node->prev = current tail always
             let weak_tail = Rc::downgrade(&tail_ref);
@@ -246,6 +906,11 @@ impl<T: Debug> CdlList<T> {
                     // Fix: head->next points to self right now
                     let weak_n = Rc::downgrade(&ref_n);
                     head_ref_mut.next = Some(LinkType::WeakLink(weak_n));
+                } else {
+                    // tail->next must follow the head around the circle
+                    let mut tail_ref_mut = tail_ref.as_ref().borrow_mut();
+                    let weak_n = Rc::downgrade(&ref_n);
+                    tail_ref_mut.next = Some(LinkType::WeakLink(weak_n));
                 }
 
                 // adjust head pointer
@@ -265,6 +930,11 @@ impl<T: Debug> CdlList<T> {
                     //tail->prev = tail, which is wrong
                     let weak_n = Rc::downgrade(&ref_n);
                     tail_ref_mut.prev = Some(LinkType::WeakLink(weak_n));
+                } else {
+                    // head->prev must follow the tail around the circle
+                    let mut head_ref_mut = head_ref.as_ref().borrow_mut();
+                    let weak_n = Rc::downgrade(&ref_n);
+                    head_ref_mut.prev = Some(LinkType::WeakLink(weak_n));
                 }
 
                 // adjust tail pointer
@@ -304,7 +974,11 @@ impl<T: Debug> CdlList<T> {
     /// assert!(v.is_none());
     /// ```
     pub fn pop_front(&mut self) -> Option<T> {
-        self.pop(true)
+        let val = self.pop(true);
+        if let Some(value) = &val {
+            self.notify(ListEvent::Popped { front: true, value });
+        }
+        val
     }
 
     /// Removes an element N from the back of the list, making the new tail `N->prev` and 
@@ -336,126 +1010,232 @@ impl<T: Debug> CdlList<T> {
     /// assert!(v.is_none());
     /// ```
     pub fn pop_back(&mut self) -> Option<T> {
-        self.pop(false)
+        let val = self.pop(false);
+        if let Some(value) = &val {
+            self.notify(ListEvent::Popped { front: false, value });
+        }
+        val
+    }
+
+    /// Moves every element onto the end of `buf`, from head to tail, leaving the 
+    /// list empty.  `buf`'s existing contents are kept, and room for `size()` more 
+    /// elements is reserved up front, so a buffer reused across calls only grows 
+    /// when the list is larger than it has room for.  Each element is reported to 
+    /// the list's observer as popped from the front.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from("abc");
+    /// let mut buf = vec!['x'];
+    /// 
+    /// list.drain_into(&mut buf);
+    /// assert_eq!(buf, ['x', 'a', 'b', 'c']);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn drain_into(&mut self, buf: &mut Vec<T>) {
+        buf.reserve(self.size);
+        while let Some(val) = self.pop_front() {
+            buf.push(val);
+        }
+    }
+
+    /// Like [`CdlList::drain_into()`], but moves the elements from tail to head, 
+    /// reporting each one as popped from the back.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from("abc");
+    /// let mut buf = vec!['x'];
+    /// 
+    /// list.drain_back_into(&mut buf);
+    /// assert_eq!(buf, ['x', 'c', 'b', 'a']);
+    /// ```
+    pub fn drain_back_into(&mut self, buf: &mut Vec<T>) {
+        buf.reserve(self.size);
+        while let Some(val) = self.pop_back() {
+            buf.push(val);
+        }
+    }
+
+    /// Removes every element, leaving the list empty.  Rather than popping the 
+    /// elements one at a time, which repairs the links around each one, the 
+    /// chain is walked once from the head and each node is freed as soon as it 
+    /// has been passed.  Dropping a list does the same.
+    /// 
+    /// The list's observer is told about this with a single 
+    /// [`ListEvent::Cleared`], not an event per element.  Handles to the removed 
+    /// elements stop resolving, as if the elements had been popped.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 2, 3]);
+    /// let h = list.handle_at(1).unwrap();
+    /// 
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// assert!(list.peek_handle(&h).is_none());
+    /// 
+    /// list.push_back(4);
+    /// assert_eq!(list.to_string(), "... <=> 4 <=> ...");
+    /// ```
+    pub fn clear(&mut self) {
+        let len = self.free_nodes();
+        if len > 0 {
+            self.notify(ListEvent::Cleared { len });
+        }
+    }
+
+    // Frees every node in one walk from the head, returning how many there were. 
+    // Taking each strong next link before moving on leaves the node behind with 
+    // no strong references, so it is freed without recursing into the rest.
+    fn free_nodes(&mut self) -> usize {
+        let len = std::mem::take(&mut self.size);
+        self.tail = None;
+
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = match node.borrow_mut().next.take() {
+                Some(LinkType::StrongLink(sl)) => Some(sl),
+                _ => None,
+            };
+        }
+        len
     }
 
     fn pop(&mut self, pop_front: bool) -> Option<T> {
+        // A detached node has exactly one strong reference, 
+        // so we can take ownership of its inner data
+        let node = self.pop_node(pop_front)?;
+        self.record_pop();
+
+        Some(Rc::try_unwrap(node).ok().unwrap().into_inner().data)
+    }
+
+    // Unlinks the head or tail node and returns it with its links cleared.
+    fn pop_node(&mut self, pop_front: bool) -> Option<Rc<RefCell<Node<T>>>> {
         // nothing to pop if the list is empty!
         if self.is_empty() {
             return None;
         }
 
-        // decrement list size before value is returned
+        // decrement list size before node is returned
         self.size -= 1;
 
         // AKA: True list size is 1
         if self.is_empty() {
             // For only this scenario, strong count for the node is 2 (head and tail are strong links).
-            // In order to take ownership of inner value, must drop one link
+            // In order to take ownership of the node, must drop one link
             // No links need to be changed, since list is freed.
             let head = self.head.take().unwrap();
             drop(head);
             let tail = self.tail.take().unwrap();
-            let val = Rc::try_unwrap(tail).ok().unwrap().into_inner().data;
+            {
+                let mut tail_ref_mut = tail.as_ref().borrow_mut();
+                tail_ref_mut.next = None;
+                tail_ref_mut.prev = None;
+            }
 
-            return Some(val);
-        } else {
-            if pop_front {
-                //pop head
+            Some(tail)
+        } else if pop_front {
+            //pop head
 
-                // By design, strong count for head is always 1, 
-                // so we can take ownership of its node data
-                let head = self.head.take().unwrap();
-                let node = Rc::try_unwrap(head).ok().unwrap().into_inner();
-                let next = node.next.unwrap();
+            // By design, strong count for head is always 1, 
+            // so we can take ownership of its node
+            let head = self.head.take().unwrap();
+            let next = {
+                let mut head_ref_mut = head.as_ref().borrow_mut();
+                head_ref_mut.prev = None;
+                head_ref_mut.next.take().unwrap()
+            };
 
-                match next {
-                    // By design, for (true) size > 1: 
-                    // head->next is always a strong link
-                    LinkType::StrongLink(sl) => {
-                        // Fix links: 
-                        // 1. node->next->prev = tail
-                        // 2. tail->next = node->next
-
-                        // Isolate these two steps to avoid invalid runtime borrowing
-                        {
-                            let tail_ref = Rc::clone(&self.tail.as_ref().unwrap());
-                            let mut next_ref_mut = sl.as_ref().borrow_mut();
-                            let weak_tail = Rc::downgrade(&tail_ref);
-
-                            next_ref_mut.prev = Some(LinkType::WeakLink(weak_tail));
-                        }
-
-                        {
-                            let tail_ref = Rc::clone(&self.tail.as_ref().unwrap());
-                            let mut tail_ref_mut = tail_ref.as_ref().borrow_mut();
-                            let weak_ref = Rc::downgrade(&sl);
-
-                            tail_ref_mut.next = Some(LinkType::WeakLink(weak_ref));
-                        }
-                        
-                        // adjust head pointer
-                        self.head = Some(sl);
-                    }, 
-                    _ => unreachable!("head->next is always a strong link for list size > 1")
-                }
+            match next {
+                // By design, for (true) size > 1: 
+                // head->next is always a strong link
+                LinkType::StrongLink(sl) => {
+                    // Fix links: 
+                    // 1. node->next->prev = tail
+                    // 2. tail->next = node->next
 
-                return Some(node.data);
-            } else {
-                //pop tail
-
-                // By design, tail will have more strong links than head
-                //
-                // ==> [H] <==> ... <==> [T] <==
-                // ||                         ||
-                // =============================
-                //
-                // Tail->next = head is weak, so Rc::strong_count(&head) = 1
-                // Strong tail links are tail and tail->prev->next       = 2
-                //
-                // Therefore, must break tail->prev->next link before consuming tail
-                {
-                    let tail_ref = Rc::clone(&self.tail.as_ref().unwrap());
-                    let tail_ref_immut = tail_ref.as_ref().borrow();
-                    let prev = tail_ref_immut.prev.clone().unwrap();
-
-                    match prev {
-                        LinkType::WeakLink(wl) => {
-                            let up = Weak::upgrade(&wl).unwrap();
-                            let mut up_ref_mut = up.as_ref().borrow_mut();
-                            let weak_head = Rc::downgrade(&self.head.as_ref().unwrap());
-
-                            // tail->prev->next = (weak link to) head
-                            up_ref_mut.next = Some(LinkType::WeakLink(weak_head));
-                        }, 
-                        _ => unreachable!("All prev links are weak links")
-                    };
-                }
+                    // Isolate these two steps to avoid invalid runtime borrowing
+                    {
+                        let tail_ref = Rc::clone(self.tail.as_ref().unwrap());
+                        let mut next_ref_mut = sl.as_ref().borrow_mut();
+                        let weak_tail = Rc::downgrade(&tail_ref);
 
-                // Now, we have guaranteed Rc::strong_count(&tail) = 1, 
-                // so we can take ownership of inner data node
-                let tail = self.tail.take().unwrap();
-                let node = Rc::try_unwrap(tail).ok().unwrap().into_inner();
-                let prev = node.prev.unwrap();
+                        next_ref_mut.prev = Some(LinkType::WeakLink(weak_tail));
+                    }
 
-                match prev {
-                    LinkType::WeakLink(wl) => {
-                        // fix links: 
-                        // 1. head->prev = prev
-                        let head_ref = Rc::clone(&self.head.as_ref().unwrap());
-                        let mut head_ref_mut = head_ref.as_ref().borrow_mut();
+                    {
+                        let tail_ref = Rc::clone(self.tail.as_ref().unwrap());
+                        let mut tail_ref_mut = tail_ref.as_ref().borrow_mut();
+                        let weak_ref = Rc::downgrade(&sl);
 
-                        head_ref_mut.prev = Some(LinkType::WeakLink(Weak::clone(&wl)));
+                        tail_ref_mut.next = Some(LinkType::WeakLink(weak_ref));
+                    }
+                    
+                    // adjust head pointer
+                    self.head = Some(sl);
+                }, 
+                _ => unreachable!("head->next is always a strong link for list size > 1")
+            }
+
+            Some(head)
+        } else {
+            //pop tail
+
+            // By design, tail will have more strong links than head
+            //
+            // ==> [H] <==> ... <==> [T] <==
+            // ||                         ||
+            // =============================
+            //
+            // Tail->next = head is weak, so Rc::strong_count(&head) = 1
+            // Strong tail links are tail and tail->prev->next       = 2
+            //
+            // Therefore, must break tail->prev->next link before detaching tail
+            {
+                let tail_ref = Rc::clone(self.tail.as_ref().unwrap());
+                let tail_ref_immut = tail_ref.as_ref().borrow();
+                let prev = tail_ref_immut.prev.clone().unwrap();
+
+                match prev {
+                    LinkType::WeakLink(wl) => {
+                        let up = Weak::upgrade(&wl).unwrap();
+                        let mut up_ref_mut = up.as_ref().borrow_mut();
+                        let weak_head = Rc::downgrade(self.head.as_ref().unwrap());
 
-                        // adjust tail pointer
-                        let strong_ref = Weak::upgrade(&wl).unwrap();
-                        self.tail = Some(strong_ref);
+                        // tail->prev->next = (weak link to) head
+                        up_ref_mut.next = Some(LinkType::WeakLink(weak_head));
                     }, 
                     _ => unreachable!("All prev links are weak links")
-                }
+                };
+            }
+
+            // Now, we have guaranteed Rc::strong_count(&tail) = 1
+            let tail = self.tail.take().unwrap();
+            let prev = {
+                let mut tail_ref_mut = tail.as_ref().borrow_mut();
+                tail_ref_mut.next = None;
+                tail_ref_mut.prev.take().unwrap()
+            };
+
+            match prev {
+                LinkType::WeakLink(wl) => {
+                    // fix links: 
+                    // 1. head->prev = prev
+                    let head_ref = Rc::clone(self.head.as_ref().unwrap());
+                    let mut head_ref_mut = head_ref.as_ref().borrow_mut();
 
-                return Some(node.data);
+                    head_ref_mut.prev = Some(LinkType::WeakLink(Weak::clone(&wl)));
+
+                    // adjust tail pointer
+                    let strong_ref = Weak::upgrade(&wl).unwrap();
+                    self.tail = Some(strong_ref);
+                }, 
+                _ => unreachable!("All prev links are weak links")
             }
+
+            Some(tail)
         }
     }
 
@@ -494,7 +1274,7 @@ impl<T: Debug> CdlList<T> {
     /// let list : CdlList<String> = CdlList::new();
     /// assert!(list.peek_front().is_none());
     /// ```
-    pub fn peek_front(&self) -> Option<Ref<T>> {
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
         self.peek(true)
     }
 
@@ -533,34 +1313,90 @@ impl<T: Debug> CdlList<T> {
     /// let list : CdlList<String> = CdlList::new();
     /// assert!(list.peek_back().is_none());
     /// ```
-    pub fn peek_back(&self) -> Option<Ref<T>> {
+    pub fn peek_back(&self) -> Option<Ref<'_, T>> {
         self.peek(false)
     }
 
-    fn peek(&self, peek_front: bool) -> Option<Ref<T>> {
+    /// Optionally returns a [`std::cell::RefMut<T>`] to the head node's data, so the 
+    /// head can be changed in place rather than popped and pushed back.  The 
+    /// `RefMut` mutably borrows the list, so it must be dropped before the list is 
+    /// used again.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<String> = CdlList::new();
+    /// list.push_back(String::from("hello"));
+    /// list.push_back(String::from("world"));
+    /// 
+    /// list.peek_front_mut().unwrap().push('!');
+    /// assert_eq!(*list.peek_front().unwrap(), "hello!");
+    /// ```
+    /// 
+    /// Holding on to the `RefMut` while using the list is caught at compile time, 
+    /// rather than panicking when the head is borrowed again:
+    /// 
+    /// ```compile_fail
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// 
+    /// let mut h = list.peek_front_mut().unwrap();
+    /// assert_eq!(*list.peek_front().unwrap(), 1);
+    /// *h = 2;
+    /// ```
+    pub fn peek_front_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.peek_mut(true)
+    }
+
+    /// Optionally returns a [`std::cell::RefMut<T>`] to the tail node's data.  Like 
+    /// [`CdlList::peek_front_mut()`], the `RefMut` must be dropped before the list 
+    /// is used again.  In a list of one element, the head and tail are the same.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// 
+    /// *list.peek_back_mut().unwrap() += 10;
+    /// assert_eq!(*list.peek_front().unwrap(), 11);
+    /// ```
+    pub fn peek_back_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.peek_mut(false)
+    }
+
+    fn peek_mut(&mut self, peek_front: bool) -> Option<RefMut<'_, T>> {
+        let node = if peek_front { self.head.as_ref() } else { self.tail.as_ref() };
+        node.map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.data))
+    }
+
+    fn peek(&self, peek_front: bool) -> Option<Ref<'_, T>> {
         if self.is_empty() {
             return None;
         }
 
         // Graciously borrowed from the "Too Many Linked Lists" book
         if peek_front {
-            return self.head.as_ref().map(|node| {
+            self.head.as_ref().map(|node| {
                 Ref::map(node.borrow(), |node| &node.data)
-            });
+            })
         } else {
-            return self.tail.as_ref().map(|node| {
+            self.tail.as_ref().map(|node| {
                 Ref::map(node.borrow(), |node| &node.data)
-            });
+            })
         }
     }
 
     /// Inserts an element in the specified position, adjusting the existing 
     /// links and incrementing the size of the list.  Insertion point starts 
     /// from 0, so `insert_at(0, T)` inserts `T` at the start of the list, 
-    /// `insert_at(3, T)` inserts `T` as the fourth element in the list, etc.
+    /// `insert_at(3, T)` inserts `T` as the fourth element in the list, etc.  The 
+    /// list is walked to the insertion point from whichever end is closer.
+    /// 
+    /// Returns an [`IndexOutOfBounds`] if `index` is greater than `size()`, in 
+    /// which case the list is unchanged and `T` is dropped.
     /// 
     /// ```rust
-    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
     /// let mut list : CdlList<u32> = CdlList::new();
     /// 
     /// list.push_back(1); // index 0
@@ -570,31 +1406,47 @@ impl<T: Debug> CdlList<T> {
     ///                    //        ╚═══════════════════════╝
     /// 
     /// // insert 3 at index 2
-    /// list.insert_at(2, 3); // list = ╔══> 1 <══> 2 <══> 3 <══> 4 <══╗
-    ///                       //        ╚══════════════════════════════╝
+    /// list.insert_at(2, 3)?; // list = ╔══> 1 <══> 2 <══> 3 <══> 4 <══╗
+    ///                        //        ╚══════════════════════════════╝
+    /// 
+    /// // index 5 is past the end
+    /// assert_eq!(list.insert_at(5, 6), Err(IndexOutOfBounds { index: 5, size: 4 }));
     /// 
     /// assert_eq!(list.size(), 4);
     /// assert_eq!(list.pop_back().unwrap(), 4);
     /// assert_eq!(list.pop_back().unwrap(), 3);
+    /// # Ok::<(), IndexOutOfBounds>(())
     /// ```
-    pub fn insert_at(&mut self, index: usize, val : T) {
+    pub fn insert_at(&mut self, index: usize, val : T) -> Result<(), IndexOutOfBounds> {
+        if index > self.size {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+
+        if let Some(node) = self.insert(index, val) {
+            self.notify_node(&node, |value| ListEvent::InsertedAt { index, value });
+        }
+        Ok(())
+    }
+
+    // Does the work of insert_at, returning the new node if one was inserted.
+    fn insert(&mut self, index: usize, val : T) -> Option<Rc<RefCell<Node<T>>>> {
         if index == 0 {
-            self.push_front(val);
-            return;
+            self.push(val, true);
+            return self.head.clone();
         }
         if index == self.size() {
-            self.push_back(val);
-            return;
+            self.push(val, false);
+            return self.tail.clone();
         }
         if index > self.size() {
-            //Should probably throw an error
-            return;
+            return None;
         }
 
         //create new node
         let n = Node::new(val);
         let ref_n = Rc::new(RefCell::new(n));
         let mut ref_n_mut = ref_n.as_ref().borrow_mut();
+        ref_n_mut.owner = Some(Rc::clone(&self.owner));
 
         // Starting point is based on where insertion point is
         //      i.e. if insertion point at back, shouldn't start iterating at head
@@ -603,8 +1455,9 @@ impl<T: Debug> CdlList<T> {
         let mid : usize = self.size/2;
 
         //get the node before insertion point
+        self.record_seek(if index <= mid { index - 1 } else { self.size - index });
         if index <= mid {
-            node_ref = Rc::clone(&self.head.as_ref().unwrap());
+            node_ref = Rc::clone(self.head.as_ref().unwrap());
 
             count = 0;
             while count < index-1 {
@@ -620,7 +1473,7 @@ impl<T: Debug> CdlList<T> {
             }
         } else {
             // traverse list in reverse
-            node_ref = Rc::clone(&self.tail.as_ref().unwrap());
+            node_ref = Rc::clone(self.tail.as_ref().unwrap());
             count = self.size-1;
 
             while count >= index {
@@ -659,6 +1512,9 @@ impl<T: Debug> CdlList<T> {
 
         // adjust size of the list
         self.size += 1;
+
+        drop(ref_n_mut);
+        Some(ref_n)
     }
 
     /// Removes an element from the specified position, adjusting the existing 
@@ -666,7 +1522,12 @@ impl<T: Debug> CdlList<T> {
     /// from 0, so `remove_at(0, T)` removes the first element from the list, 
     /// `remove_at(3, T)` removes the fourth element from the list, etc.  The 
     /// removed element is returned to the user if one exists (i.e. the list is 
-    /// not empty).
+    /// not empty).  Like [`CdlList::insert_at()`], the list is walked from 
+    /// whichever end is closer.
+    /// 
+    /// Returns `None` if `index` is `size()` or more, which is every index when 
+    /// the list is empty.  Use [`CdlList::try_remove_at()`] to find out which 
+    /// index was rejected.
     /// 
     /// ```rust
     /// # use cdl_list_rs::cdl_list::CdlList;
@@ -685,15 +1546,40 @@ impl<T: Debug> CdlList<T> {
     /// assert_eq!(list.size(), 1);
     /// ```
     pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        self.try_remove_at(index).ok()
+    }
+
+    /// Like [`CdlList::remove_at()`], but returns an [`IndexOutOfBounds`] 
+    /// describing the rejected index if it is `size()` or more, rather than 
+    /// `None`.  The list is unchanged in that case.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
+    /// let mut list = CdlList::from([1, 2, 3]);
+    /// 
+    /// assert_eq!(list.try_remove_at(1), Ok(2));
+    /// assert_eq!(list.try_remove_at(2), Err(IndexOutOfBounds { index: 2, size: 2 }));
+    /// 
+    /// let mut empty : CdlList<u32> = CdlList::new();
+    /// assert_eq!(empty.try_remove_at(0), Err(IndexOutOfBounds { index: 0, size: 0 }));
+    /// ```
+    pub fn try_remove_at(&mut self, index: usize) -> Result<T, IndexOutOfBounds> {
+        if index >= self.size {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+
+        let val = self.remove(index).unwrap();
+        self.notify(ListEvent::RemovedAt { index, value: &val });
+        Ok(val)
+    }
+
+    // Does the work of remove_at.  index must be in range.
+    fn remove(&mut self, index: usize) -> Option<T> {
         if index == 0 {
-            return self.pop_front();
+            return self.pop(true);
         }
         if index == self.size()-1 {
-            return self.pop_back();
-        }
-        if index >= self.size() {
-            //Should probably throw an error
-            return None;
+            return self.pop(false);
         }
 
         // Starting point is based on where removal point is
@@ -703,8 +1589,9 @@ impl<T: Debug> CdlList<T> {
         let mid : usize = self.size/2;
 
         //get the node before removal point
+        self.record_seek(if index <= mid { index - 1 } else { self.size - index });
         if index <= mid {
-            node_ref = Rc::clone(&self.head.as_ref().unwrap());
+            node_ref = Rc::clone(self.head.as_ref().unwrap());
 
             count = 0;
             while count < index-1 {
@@ -720,7 +1607,7 @@ impl<T: Debug> CdlList<T> {
             }
         } else {
             // traverse list in reverse
-            node_ref = Rc::clone(&self.tail.as_ref().unwrap());
+            node_ref = Rc::clone(self.tail.as_ref().unwrap());
             count = self.size-1;
 
             while count >= index {
@@ -768,9 +1655,3315 @@ impl<T: Debug> CdlList<T> {
                 //should be able to access inner data now
                 let val = Rc::try_unwrap(sl).ok().unwrap().into_inner().data;
 
-                return Some(val);
+                Some(val)
             }, 
             _ => unreachable!("All intermediary nodes have strong links to next.")
         }
     }
-}
\ No newline at end of file
+
+    /// Optionally returns an [`ElemRef`] to the element at `index`, walking from 
+    /// whichever end of the list is closer.  Unlike [`CdlList::get_wrapping()`], 
+    /// an index past the tail doesn't wrap around, and `None` is returned instead.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from([1, 2, 3]);
+    /// 
+    /// assert_eq!(*list.get(1).unwrap().borrow(), 2);
+    /// assert!(list.get(3).is_none());
+    /// ```
+    pub fn get(&self, index: usize) -> Option<ElemRef<'_, T>> {
+        self.node_at(index).map(ElemRef::new)
+    }
+
+    /// Optionally returns an [`ElemMut`] to the element at `index`, walking from 
+    /// whichever end of the list is closer, or `None` if `index` is out of range. 
+    /// The `ElemMut` mutably borrows the list, so the list can't be read or 
+    /// changed until it is dropped.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 2, 3]);
+    /// 
+    /// *list.get_mut(1).unwrap().borrow_mut() *= 10;
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 20 <=> 3 <=> ...");
+    /// 
+    /// assert!(list.get_mut(3).is_none());
+    /// ```
+    /// 
+    /// Since the `ElemMut` borrows the list, trying to remove its element while it 
+    /// is still in use doesn't compile:
+    /// 
+    /// ```compile_fail
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 2, 3]);
+    /// 
+    /// let mut e = list.get_mut(1).unwrap();
+    /// list.remove_at(1);
+    /// *e.borrow_mut() = 4;
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<ElemMut<'_, T>> {
+        self.node_at(index).map(ElemMut::new)
+    }
+
+    /// Optionally returns an [`ElemRef`] to the element at `index`, counting 
+    /// backwards from the tail, so `get_from_back(0)` is the tail, `get_from_back(1)` 
+    /// is the element before it, etc.  The list is walked backwards along the prev 
+    /// links.  Returns `None` if `index` is out of range.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3); // list = ╔══> 1 <══> 2 <══> 3 <══╗
+    ///                    //        ╚═══════════════════════╝
+    /// 
+    /// assert_eq!(*list.get_from_back(0).unwrap().borrow(), 3);
+    /// assert_eq!(*list.get_from_back(2).unwrap().borrow(), 1);
+    /// assert!(list.get_from_back(3).is_none());
+    /// ```
+    pub fn get_from_back(&self, index: usize) -> Option<ElemRef<'_, T>> {
+        self.node_from_back(index).map(ElemRef::new)
+    }
+
+    /// Optionally returns an [`ElemRef`] to the element at `index` taken modulo 
+    /// `size()`, so indices past the tail wrap around to the head as many times as 
+    /// needed.  The list is then walked from whichever end is closer.  Returns 
+    /// `None` only if the list is empty.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// assert_eq!(*list.get_wrapping(7).unwrap().borrow(), 2);
+    /// assert_eq!(*list.get_wrapping(usize::MAX).unwrap().borrow(), 0);
+    /// ```
+    pub fn get_wrapping(&self, index: usize) -> Option<ElemRef<'_, T>> {
+        if self.is_empty() {
+            return None;
+        }
+        self.node_at(index % self.size).map(ElemRef::new)
+    }
+
+    /// Optionally returns an [`ElemRef`] to the element `offset` places after the 
+    /// element at `origin`, or before it if `offset` is negative, wrapping around 
+    /// the ring in either direction.  Like [`CdlList::get_wrapping()`], both 
+    /// `origin` and the result are taken modulo `size()`, and `None` is only 
+    /// returned for an empty list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// assert_eq!(*list.get_offset(3, 1).unwrap().borrow(), 4);
+    /// assert_eq!(*list.get_offset(1, -2).unwrap().borrow(), 4);
+    /// assert_eq!(*list.get_offset(0, -11).unwrap().borrow(), 4);
+    /// ```
+    pub fn get_offset(&self, origin: usize, offset: isize) -> Option<ElemRef<'_, T>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // reduce the offset to a distance forward around the ring
+        let size = self.size as i128;
+        let forward = (offset as i128).rem_euclid(size) as usize;
+        self.node_at((origin % self.size + forward) % self.size).map(ElemRef::new)
+    }
+
+    /// Optionally returns [`ElemRef`]s to the two elements on either side of the 
+    /// element at `index`, as `(prev, next)`.  Since the list is circular, the 
+    /// head's previous element is the tail and the tail's next element is the head, 
+    /// so every element has two neighbors.  In a list of one or two elements, the 
+    /// neighbors are the same element, which can be borrowed through both 
+    /// `ElemRef`s at once.  Returns `None` if `index` is out of range.
+    /// 
+    /// There is no `neighbors_mut`, because two mutable borrows of the same element 
+    /// can't coexist; [`CdlList::with_neighbors_mut()`] checks for this instead.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// let (prev, next) = list.neighbors(0).unwrap();
+    /// assert_eq!((*prev.borrow(), *next.borrow()), (3, 2));
+    /// ```
+    pub fn neighbors(&self, index: usize) -> Option<(ElemRef<'_, T>, ElemRef<'_, T>)> {
+        let node = self.node_at(index)?;
+        Some((ElemRef::new(Node::prev_of(&node)), ElemRef::new(Node::next_of(&node))))
+    }
+
+    /// Calls `f` with mutable references to the element before the one at `index`, 
+    /// the element itself, and the element after it, wrapping around the ring like 
+    /// [`CdlList::neighbors()`], and returns what `f` returns.
+    /// 
+    /// The three elements are only distinct when the list has at least three of 
+    /// them; otherwise the references would alias, so `f` isn't called and `None` 
+    /// is returned.  `None` is also returned if `index` is out of range.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// // move everything from the tail's neighbors onto the tail
+    /// list.with_neighbors_mut(2, |prev, cur, next| {
+    ///     *cur += *prev + *next;
+    ///     *prev = 0;
+    ///     *next = 0;
+    /// });
+    /// assert_eq!(list.to_string(), "... <=> 0 <=> 0 <=> 6 <=> ...");
+    /// 
+    /// list.pop_front();
+    /// assert!(list.with_neighbors_mut(0, |_, _, _| ()).is_none());
+    /// ```
+    pub fn with_neighbors_mut<R, F>(&mut self, index: usize, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T, &mut T, &mut T) -> R,
+    {
+        if self.size < 3 {
+            return None;
+        }
+
+        let node = self.node_at(index)?;
+        let prev = Node::prev_of(&node);
+        let next = Node::next_of(&node);
+
+        let mut prev_mut = prev.borrow_mut();
+        let mut node_mut = node.borrow_mut();
+        let mut next_mut = next.borrow_mut();
+        Some(f(&mut prev_mut.data, &mut node_mut.data, &mut next_mut.data))
+    }
+
+    /// Inserts an element so that it ends up at `index` counting backwards from 
+    /// the tail.  `insert_at_back(0, T)` makes `T` the new tail, 
+    /// `insert_at_back(1, T)` inserts `T` just before the tail, and 
+    /// `insert_at_back(list.size(), T)` makes `T` the new head.
+    /// 
+    /// Like [`CdlList::insert_at()`], returns an [`IndexOutOfBounds`] if `index` 
+    /// is greater than `size()`, in which case the list is unchanged and `T` is 
+    /// dropped.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(4); // list = ╔══> 1 <══> 2 <══> 4 <══╗
+    ///                    //        ╚═══════════════════════╝
+    /// 
+    /// // insert 3 just before the tail
+    /// list.insert_at_back(1, 3)?; // list = ╔══> 1 <══> 2 <══> 3 <══> 4 <══╗
+    ///                             //        ╚══════════════════════════════╝
+    /// 
+    /// assert_eq!(list.insert_at_back(5, 0), Err(IndexOutOfBounds { index: 5, size: 4 }));
+    /// 
+    /// assert_eq!(list.size(), 4);
+    /// assert_eq!(list.pop_back().unwrap(), 4);
+    /// assert_eq!(list.pop_back().unwrap(), 3);
+    /// # Ok::<(), IndexOutOfBounds>(())
+    /// ```
+    pub fn insert_at_back(&mut self, index: usize, val: T) -> Result<(), IndexOutOfBounds> {
+        if index > self.size {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+
+        if index == 0 {
+            self.push(val, false);
+
+            let tail = Rc::clone(self.tail.as_ref().unwrap());
+            let index = self.size - 1;
+            self.notify_node(&tail, |value| ListEvent::InsertedAt { index, value });
+            return Ok(());
+        }
+
+        // the new node goes just before the node currently at index-1
+        let after = self.node_from_back(index - 1).unwrap();
+        let prev = if Rc::ptr_eq(&after, self.head.as_ref().unwrap()) {
+            None
+        } else {
+            Some(Node::prev_of(&after))
+        };
+
+        let ref_n = Rc::new(RefCell::new(Node::new(val)));
+        self.link_node_after(prev.as_ref(), Rc::clone(&ref_n));
+
+        let index = self.size - 1 - index;
+        self.notify_node(&ref_n, |value| ListEvent::InsertedAt { index, value });
+        Ok(())
+    }
+
+    /// Removes the element at `index` counting backwards from the tail, so 
+    /// `remove_at_back(0)` removes the tail.  Like [`CdlList::remove_at()`], the 
+    /// removed element is returned if `index` is in range, and `None` otherwise. 
+    /// Use [`CdlList::try_remove_at_back()`] to find out which index was rejected.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// list.push_back(4);
+    /// 
+    /// // remove the 2nd-from-last element
+    /// assert_eq!(list.remove_at_back(1), Some(3));
+    /// assert_eq!(list.remove_at_back(3), None);
+    /// assert_eq!(list.size(), 3);
+    /// ```
+    pub fn remove_at_back(&mut self, index: usize) -> Option<T> {
+        self.try_remove_at_back(index).ok()
+    }
+
+    /// Like [`CdlList::remove_at_back()`], but returns an [`IndexOutOfBounds`] 
+    /// describing the rejected index if it is `size()` or more, rather than 
+    /// `None`.  The list is unchanged in that case.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
+    /// let mut list = CdlList::from([1, 2, 3]);
+    /// 
+    /// assert_eq!(list.try_remove_at_back(0), Ok(3));
+    /// assert_eq!(list.try_remove_at_back(2), Err(IndexOutOfBounds { index: 2, size: 2 }));
+    /// ```
+    pub fn try_remove_at_back(&mut self, index: usize) -> Result<T, IndexOutOfBounds> {
+        if index >= self.size {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+
+        let node = self.node_from_back(index).unwrap();
+        self.unlink_node(&node);
+
+        let val = Rc::try_unwrap(node).ok().unwrap().into_inner().data;
+        let index = self.size - index;
+        self.notify(ListEvent::RemovedAt { index, value: &val });
+        Ok(val)
+    }
+
+    /// Inserts `val` at `index` taken modulo `size() + 1`, for code that counts 
+    /// positions around the ring rather than along the list.  The extra position 
+    /// is the one after the tail, so indices `0` through `size()` behave exactly 
+    /// like [`CdlList::insert_at()`], `size() + 1` wraps around to the head again, 
+    /// and so on.  Inserting into an empty list always pushes the element.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// // with 3 elements, there are 4 places to insert, so 5 is the same as 1
+    /// list.insert_at_wrapping(5, 10);
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 10 <=> 2 <=> 3 <=> ...");
+    /// 
+    /// // with 4 elements, 4 is after the tail but 5 is the head
+    /// list.insert_at_wrapping(4, 20);
+    /// list.insert_at_wrapping(6, 30);
+    /// assert_eq!(list.to_string(), "... <=> 30 <=> 1 <=> 10 <=> 2 <=> 3 <=> 20 <=> ...");
+    /// ```
+    pub fn insert_at_wrapping(&mut self, index: usize, val: T) {
+        // size + 1 can't overflow, since the list can't hold usize::MAX nodes, 
+        // and the reduced index is never out of bounds
+        let _ = self.insert_at(index % (self.size + 1), val);
+    }
+
+    /// Removes and returns the element at `index` taken modulo `size()`, so 
+    /// indices past the tail wrap around to the head.  Unlike insertion, there 
+    /// are only `size()` elements to remove, so `size()` itself is the head.  
+    /// Returns `None` only if the list is empty.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// assert_eq!(list.remove_at_wrapping(3), Some(1));
+    /// assert_eq!(list.remove_at_wrapping(7), Some(3));
+    /// assert_eq!(list.remove_at_wrapping(usize::MAX), Some(2));
+    /// assert_eq!(list.remove_at_wrapping(0), None);
+    /// ```
+    pub fn remove_at_wrapping(&mut self, index: usize) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.remove_at(index % self.size)
+    }
+
+    /// Removes the elements at a batch of indices into the list as it is before 
+    /// the call, so no index needs adjusting for the removals before it, and 
+    /// returns them in order from head to tail.  `indices` can be in any order, 
+    /// and repeated indices are only removed once.
+    /// 
+    /// The indices are sorted and the elements removed during a single walk from 
+    /// the head, so removing k elements takes O(n + k log k) time.  Each removal is 
+    /// reported to the list's observer with the element's index at the time, in 
+    /// order from head to tail.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an [`IndexOutOfBounds`] for the first index that is `size()` or 
+    /// greater, without removing anything.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
+    /// let mut list = CdlList::from("abcdef");
+    /// 
+    /// assert_eq!(list.remove_many(&[4, 0, 2, 4]), Ok(vec!['a', 'c', 'e']));
+    /// assert_eq!(list.to_string_chars(), "bdf");
+    /// 
+    /// assert_eq!(list.remove_many(&[0, 3]), Err(IndexOutOfBounds { index: 3, size: 3 }));
+    /// assert_eq!(list.to_string_chars(), "bdf");
+    /// ```
+    pub fn remove_many(&mut self, indices: &[usize]) -> Result<Vec<T>, IndexOutOfBounds> {
+        if let Some(&index) = indices.iter().find(|&&index| index >= self.size) {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+
+        let mut sorted = indices.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        let mut removed = Vec::with_capacity(sorted.len());
+        let Some(&last) = sorted.last() else {
+            return Ok(removed);
+        };
+
+        // next is the original element at index passed
+        let mut next = Some(Rc::clone(self.head.as_ref().unwrap()));
+        let mut passed = 0;
+        for index in sorted {
+            let mut node = next.take().unwrap();
+            while passed < index {
+                node = Node::next_of(&node);
+                passed += 1;
+            }
+            if index < last {
+                next = Some(Node::next_of(&node));
+                passed += 1;
+            }
+
+            let at = index - removed.len();
+            self.unlink_node(&node);
+            self.notify_node(&node, |value| ListEvent::RemovedAt { index: at, value });
+            removed.push(Rc::try_unwrap(node).ok().unwrap().into_inner().data);
+        }
+
+        self.record_seek(last);
+        Ok(removed)
+    }
+
+    /// Returns the middle element as an [`ElemRef`], or `None` if the list is 
+    /// empty.  For an odd size this is the element with as many elements before it 
+    /// as after it; for an even size it is the last element of the front half, so 
+    /// in general the ⌈size/2⌉-th element counting from 1.
+    /// 
+    /// The middle is found with a slow and a fast pointer walking from the head, 
+    /// the fast one taking two steps for each of the slow one's until it reaches 
+    /// the tail, so `size()` isn't consulted.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// assert!(list.middle().is_none());
+    /// 
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// assert_eq!(*list.middle().unwrap().borrow(), 2);
+    /// 
+    /// list.push_back(5);
+    /// assert_eq!(*list.middle().unwrap().borrow(), 2);
+    /// ```
+    pub fn middle(&self) -> Option<ElemRef<'_, T>> {
+        self.middle_node().map(ElemRef::new)
+    }
+
+    /// Moves every element of `other` onto the back of this list, in order, leaving 
+    /// `other` empty.  This takes constant time: `other`'s ring is opened and 
+    /// linked in after the tail, and no node is moved or copied.  Handles to 
+    /// `other`'s elements stay valid, and now belong to this list.
+    /// 
+    /// If either list has an observer, it is told about each moved element, 
+    /// `other`'s as a removal from its front and this list's as a push to its back, 
+    /// which means walking the moved elements once.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut a = CdlList::from([1, 2]);
+    /// let mut b = CdlList::from([3, 4]);
+    /// 
+    /// let h = b.handle_at(0).unwrap();
+    /// a.append(&mut b);
+    /// assert_eq!(a.to_string(), "... <=> 1 <=> 2 <=> 3 <=> 4 <=> ...");
+    /// assert!(b.is_empty());
+    /// 
+    /// assert_eq!(a.handle_index(&h), Some(2));
+    /// assert!(b.peek_handle(&h).is_none());
+    /// ```
+    pub fn append(&mut self, other: &mut CdlList<T>) {
+        let Some(((head, tail), len)) = self.adopt_ring(other) else {
+            return;
+        };
+
+        self.link_chain_back(Rc::clone(&head), tail, len);
+        if self.observed() {
+            let mut node = head;
+            for _ in 0..len {
+                self.notify_node(&node, |value| ListEvent::Pushed { front: false, value });
+                node = Node::next_of(&node);
+            }
+        }
+    }
+
+    /// Moves every element of `other` into this list so that the first of them 
+    /// ends up at `index`, in order, leaving `other` empty.  Like 
+    /// [`CdlList::append()`], no node is moved or copied: the list is walked once 
+    /// to find `index`, and `other`'s ring is opened and linked in there.  Handles 
+    /// to `other`'s elements stay valid, and now belong to this list.
+    /// 
+    /// If either list has an observer, it is told about each moved element, 
+    /// `other`'s as a removal from its front and this list's as an insertion. 
+    /// Returns an [`IndexOutOfBounds`] if `index` is greater than `size()`, in 
+    /// which case neither list is changed.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
+    /// let mut list = CdlList::from([1, 4, 5]);
+    /// let mut other = CdlList::from([2, 3]);
+    /// 
+    /// list.splice_at(1, &mut other)?;
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 2 <=> 3 <=> 4 <=> 5 <=> ...");
+    /// assert!(other.is_empty());
+    /// 
+    /// assert_eq!(list.splice_at(6, &mut other), Err(IndexOutOfBounds { index: 6, size: 5 }));
+    /// # Ok::<(), IndexOutOfBounds>(())
+    /// ```
+    pub fn splice_at(&mut self, index: usize, other: &mut CdlList<T>) -> Result<(), IndexOutOfBounds> {
+        if index > self.size {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+
+        // find the node before the cut first, since the walk depends on the size
+        let before = if index == 0 || index == self.size { None } else { self.node_at(index - 1) };
+        let old_tail = self.tail.clone();
+        let Some(((head, tail), len)) = self.adopt_ring(other) else {
+            return Ok(());
+        };
+
+        match before {
+            Some(before) => {
+                // before isn't the tail, so its next link is strong
+                let after = Node::next_of(&before);
+                head.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&before)));
+                after.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&tail)));
+                tail.borrow_mut().next = Some(LinkType::StrongLink(after));
+                before.borrow_mut().next = Some(LinkType::StrongLink(Rc::clone(&head)));
+                self.size += len;
+            },
+            None => {
+                self.link_chain_back(Rc::clone(&head), Rc::clone(&tail), len);
+
+                // as in extend_front, moving the seam to just before the chain puts 
+                // it in front instead
+                if let (0, Some(old_tail)) = (index, old_tail) {
+                    let old_head = self.head.replace(Rc::clone(&head)).unwrap();
+                    tail.borrow_mut().next = Some(LinkType::StrongLink(old_head));
+                    old_tail.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(&head)));
+                    self.tail = Some(old_tail);
+                }
+            },
+        }
+
+        if self.observed() {
+            let mut node = head;
+            for i in 0..len {
+                self.notify_node(&node, |value| ListEvent::InsertedAt { index: index + i, value });
+                node = Node::next_of(&node);
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits the list at index `at`, returning a new list with the elements from 
+    /// `at` to the tail, in order, and keeping the elements before `at`.  Each list 
+    /// is its own ring afterwards.  `split_off(0)` moves every element, and 
+    /// `split_off(size())` returns an empty list.
+    /// 
+    /// Finding the cut walks from whichever end is closer, and the moved elements 
+    /// are walked once more to hand them over to the new list.  This list keeps its 
+    /// observer, which is told about each moved element as a removal.  Returns an 
+    /// [`IndexOutOfBounds`] if `at` is greater than `size()`, in which case the list 
+    /// is unchanged.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
+    /// let mut list = CdlList::from([1, 2, 3, 4, 5]);
+    /// 
+    /// let back = list.split_off(3)?;
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 2 <=> 3 <=> ...");
+    /// assert_eq!(back.to_string(), "... <=> 4 <=> 5 <=> ...");
+    /// 
+    /// assert_eq!(list.split_off(4).unwrap_err(), IndexOutOfBounds { index: 4, size: 3 });
+    /// # Ok::<(), IndexOutOfBounds>(())
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Result<CdlList<T>, IndexOutOfBounds> {
+        if at > self.size {
+            return Err(IndexOutOfBounds { index: at, size: self.size });
+        }
+
+        match self.node_at(at) {
+            Some(target) => Ok(self.split_off_node(target)),
+            None => Ok(CdlList::new()),
+        }
+    }
+
+    /// Splits the list into its front and back halves, with the middle element 
+    /// (see [`CdlList::middle()`]) ending the front half.  For an odd size, the 
+    /// front half gets the extra element.  An empty list splits into two empty 
+    /// lists, and a single element goes to the front.
+    /// 
+    /// The cut is found with the same slow and fast pointer walk as 
+    /// [`CdlList::middle()`], and the back half's elements are walked once more to 
+    /// hand them over to the new list.  The front half is this list, and keeps its 
+    /// observer.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// let (front, back) = list.split_half();
+    /// assert_eq!(front.to_string(), "... <=> 0 <=> 1 <=> 2 <=> ...");
+    /// assert_eq!(back.to_string(), "... <=> 3 <=> 4 <=> ...");
+    /// ```
+    pub fn split_half(mut self) -> (CdlList<T>, CdlList<T>) {
+        let back = match self.middle_node() {
+            Some(mid) if !Rc::ptr_eq(&mid, self.tail.as_ref().unwrap()) => {
+                self.split_off_node(Node::next_of(&mid))
+            },
+            _ => CdlList::new(),
+        };
+        (self, back)
+    }
+
+    /// Splits the list into `k` lists whose sizes differ by at most one, keeping 
+    /// the elements in order, so the first list starts at the head and the last 
+    /// ends at the tail.  When `size()` isn't a multiple of `k`, the lists at the 
+    /// front get the extra elements.  There are always exactly `k` lists, so if 
+    /// `k > size()`, each element gets a list of its own and the last 
+    /// `k - size()` lists are empty.
+    /// 
+    /// The list is cut in `k - 1` places by relinking nodes rather than moving 
+    /// elements, though every node is visited once to find the cuts and hand the 
+    /// nodes over to their new lists.  The first list is this one, and keeps its 
+    /// observer.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `k == 0`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let jobs = CdlList::from("abcdefghij");
+    /// let shards : Vec<String> = jobs.split_evenly(4)
+    ///     .iter()
+    ///     .map(|shard| shard.to_string_chars())
+    ///     .collect();
+    /// assert_eq!(shards, ["abc", "def", "gh", "ij"]);
+    /// ```
+    pub fn split_evenly(mut self, k: usize) -> Vec<CdlList<T>> {
+        assert!(k > 0, "cannot split a list into 0 lists");
+
+        let (base, extra) = (self.size / k, self.size % k);
+        // the first list is this one even if it's empty
+        let used = if base == 0 { extra.max(1) } else { k };
+
+        // the first node of each list after the first
+        let mut starts = Vec::with_capacity(used - 1);
+        if let Some(head) = &self.head {
+            let mut node = Rc::clone(head);
+            for i in 0..used - 1 {
+                let len = if i < extra { base + 1 } else { base };
+                for _ in 0..len {
+                    node = Node::next_of(&node);
+                }
+                starts.push(Rc::clone(&node));
+            }
+        }
+
+        // cut from the back so each split only hands over its own nodes
+        let mut lists : Vec<CdlList<T>> = Vec::with_capacity(k);
+        lists.extend((used..k).map(|_| CdlList::new()));
+        while let Some(start) = starts.pop() {
+            lists.push(self.split_off_node(start));
+        }
+        lists.push(self);
+
+        lists.reverse();
+        lists
+    }
+
+    /// Splits the list into at most `n` lists at the elements matching `pred`, like 
+    /// [`str::splitn()`].  The matching elements are separators, and are dropped. 
+    /// Once `n - 1` lists have been split off, the last list holds the rest of the 
+    /// elements, including any further separators.  `pred` is only called on 
+    /// elements before that point.
+    /// 
+    /// If `n` is 0, the result is empty.  Otherwise, there is always at least one 
+    /// list, even if this list is empty, and separators next to each other or at 
+    /// either end give empty lists.  Nodes are moved into the new lists rather than 
+    /// reallocated.  This list's observer is dropped.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let words = CdlList::from("Mary had a little lambda");
+    /// let parts : Vec<String> = words.splitn(3, |&c| c == ' ')
+    ///     .iter()
+    ///     .map(|part| part.to_string_chars())
+    ///     .collect();
+    /// assert_eq!(parts, ["Mary", "had", "a little lambda"]);
+    /// ```
+    pub fn splitn<P: FnMut(&T) -> bool>(mut self, n: usize, mut pred: P) -> Vec<CdlList<T>> {
+        self.observer = None;
+        let mut parts = Vec::new();
+        if n == 0 {
+            return parts;
+        }
+
+        let mut part = CdlList::new();
+        while parts.len() + 1 < n {
+            let Some(node) = self.pop_node(true) else {
+                break;
+            };
+
+            let is_separator = pred(&node.borrow().data);
+            if is_separator {
+                parts.push(std::mem::take(&mut part));
+            } else {
+                part.push_node(node, false);
+            }
+        }
+
+        // either the limit was reached just after a separator, leaving part empty 
+        // and the rest of the list intact, or the list ran out
+        parts.push(if self.is_empty() { part } else { self });
+        parts
+    }
+
+    /// Removes the elements in `range` and inserts the items of `replace_with` in 
+    /// their place, like [`Vec::splice()`].  The removed elements are returned as a 
+    /// new list in their original order.  The replacement may be shorter or longer 
+    /// than the range, or empty to perform a pure removal; likewise, an empty 
+    /// range performs a pure insertion.
+    /// 
+    /// The list is walked once to find the start of the range, after which each 
+    /// removal and insertion is O(1).
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// list.push_back(4); // list = ╔══> 1 <══> 2 <══> 3 <══> 4 <══╗
+    ///                    //        ╚══════════════════════════════╝
+    /// 
+    /// let mut removed = list.replace_range(1..3, vec![7, 8, 9]).unwrap();
+    ///                    // list = ╔══> 1 <══> 7 <══> 8 <══> 9 <══> 4 <══╗
+    ///                    //        ╚═════════════════════════════════════╝
+    /// 
+    /// assert_eq!(list.size(), 5);
+    /// assert_eq!(removed.pop_front(), Some(2));
+    /// assert_eq!(removed.pop_front(), Some(3));
+    /// ```
+    /// 
+    /// If the range is decreasing or extends past the end of the list, `None` 
+    /// is returned and the list is left untouched.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// 
+    /// assert!(list.replace_range(0..2, vec![5]).is_none());
+    /// assert_eq!(list.size(), 1);
+    /// ```
+    pub fn replace_range<R, I>(&mut self, range: R, replace_with: I) -> Option<CdlList<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let (start, end) = self.bounds_of(range)?;
+
+        // node just before the range, or None if the range starts at the head
+        let before = if start == 0 {
+            None
+        } else {
+            self.node_at(start - 1)
+        };
+
+        let mut removed : CdlList<T> = CdlList::new();
+        for _ in start..end {
+            let node = match &before {
+                Some(b) => Node::next_of(b),
+                None => Rc::clone(self.head.as_ref().unwrap()),
+            };
+
+            self.unlink_node(&node);
+            self.notify_node(&node, |value| ListEvent::RemovedAt { index: start, value });
+            removed.push_node(node, false);
+        }
+
+        let mut prev = before;
+        let mut inserted = 0;
+        for val in replace_with {
+            let ref_n = Rc::new(RefCell::new(Node::new(val)));
+            self.link_node_after(prev.as_ref(), Rc::clone(&ref_n));
+            self.notify_node(&ref_n, |value| ListEvent::InsertedAt { index: start + inserted, value });
+            prev = Some(ref_n);
+            inserted += 1;
+        }
+
+        self.trace_splice(start, removed.size(), inserted);
+        Some(removed)
+    }
+
+    /// Rotates the list `n` places to the left, so the element at index `n` becomes 
+    /// the new head, like [`std::collections::VecDeque::rotate_left()`].  `n` is 
+    /// taken modulo `size()`.
+    /// 
+    /// No elements are moved: the head and tail are re-seated around the ring, 
+    /// which only requires walking to the new head from whichever end is closer.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3); // list = ╔══> 1 <══> 2 <══> 3 <══╗
+    ///                    //        ╚═══════════════════════╝
+    /// 
+    /// list.rotate_left(1); // list = ╔══> 2 <══> 3 <══> 1 <══╗
+    ///                      //        ╚═══════════════════════╝
+    /// 
+    /// assert_eq!(*list.peek_front().unwrap(), 2);
+    /// assert_eq!(*list.peek_back().unwrap(), 1);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.size < 2 {
+            return;
+        }
+        let mid = n % self.size;
+        if mid == 0 {
+            return;
+        }
+
+        let new_head = self.node_at(mid).unwrap();
+        self.reseat_head(new_head, mid);
+    }
+
+    /// Rotates the list `n` places to the right, so the element at index 
+    /// `size() - n` becomes the new head, like 
+    /// [`std::collections::VecDeque::rotate_right()`].  `n` is taken modulo `size()`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// list.rotate_right(1); // list = ╔══> 3 <══> 1 <══> 2 <══╗
+    ///                       //        ╚═══════════════════════╝
+    /// 
+    /// assert_eq!(*list.peek_front().unwrap(), 3);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.size < 2 {
+            return;
+        }
+
+        self.rotate_left(self.size - n % self.size);
+    }
+
+    /// Rotates the list so that the first element, searching from the head, for 
+    /// which `pred` returns `true` becomes the new head, and returns `true`.  If no 
+    /// element matches, returns `false` and leaves the list untouched.
+    /// 
+    /// The list is walked once to find the match, and then only the head and tail 
+    /// move, as in [`CdlList::rotate_left()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut peers = CdlList::from(["alice", "bob", "carol"]);
+    /// 
+    /// assert!(peers.rotate_to(|p| p.starts_with('c')));
+    /// assert_eq!(*peers.peek_front().unwrap(), "carol");
+    /// assert_eq!(*peers.peek_back().unwrap(), "bob");
+    /// 
+    /// assert!(!peers.rotate_to(|p| p.is_empty()));
+    /// assert_eq!(*peers.peek_front().unwrap(), "carol");
+    /// ```
+    pub fn rotate_to<P: FnMut(&T) -> bool>(&mut self, pred: P) -> bool {
+        match self.find_node(pred) {
+            Some((0, _)) => true,
+            Some((mid, node)) => {
+                self.reseat_head(node, mid);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Removes every element for which `f` returns `false`, keeping the rest in 
+    /// order.  The list is walked once, and each removed node is unlinked where it 
+    /// is rather than by shifting the elements after it.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 1..=6 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// list.retain(|val| val % 2 == 0);
+    /// assert_eq!(list.to_string(), "... <=> 2 <=> 4 <=> 6 <=> ...");
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_with_index(|_, val| f(val));
+    }
+
+    /// Like [`CdlList::retain()`], but `f` is also given each element's index.
+    /// 
+    /// The index is the element's position **before** this call, not its position 
+    /// after the removals made so far.  That is, `f` sees the indices 
+    /// `0, 1, ..., size() - 1` in order no matter how many elements it rejects, 
+    /// so a predicate like `index % 3 != 2` removes every third element of the 
+    /// original list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..6 {
+    ///     list.push_back(i * 10);
+    /// }
+    /// 
+    /// // drop every third element
+    /// list.retain_with_index(|index, _| index % 3 != 2);
+    /// assert_eq!(list.to_string(), "... <=> 0 <=> 10 <=> 30 <=> 40 <=> ...");
+    /// ```
+    pub fn retain_with_index<F: FnMut(usize, &T) -> bool>(&mut self, mut f: F) {
+        self.retain_indexed(|index, val| f(index, val));
+    }
+
+    /// Like [`CdlList::retain()`], but `f` can change each element as well as 
+    /// decide whether to keep it, like [`Vec::retain_mut()`].  Changes to removed 
+    /// elements are dropped with them.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut timers = CdlList::from([3, 1, 2]);
+    /// 
+    /// // tick every timer, dropping those that run out
+    /// timers.retain_mut(|t| {
+    ///     *t -= 1;
+    ///     *t > 0
+    /// });
+    /// assert_eq!(timers.to_string(), "... <=> 2 <=> 1 <=> ...");
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        self.retain_indexed(|_, val| f(val));
+    }
+
+    // Does the work of the retain methods, giving f each element's index from 
+    // before any removals.
+    fn retain_indexed<F: FnMut(usize, &mut T) -> bool>(&mut self, mut f: F) {
+        if self.is_empty() {
+            return;
+        }
+
+        let size = self.size;
+        let mut removed = 0;
+        let mut node = Rc::clone(self.head.as_ref().unwrap());
+        for index in 0..size {
+            // find the next node before unlinking clears the links; the last 
+            // node has no next to visit
+            let next = if index + 1 < size { Some(Node::next_of(&node)) } else { None };
+
+            let keep = f(index, &mut node.borrow_mut().data);
+            if !keep {
+                self.unlink_node(&node);
+                let val = Rc::try_unwrap(node).ok().unwrap().into_inner().data;
+                self.notify(ListEvent::RemovedAt { index: index - removed, value: &val });
+                removed += 1;
+            }
+
+            match next {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+    }
+
+    /// Moves the elements in `src` so that they come just before the element that 
+    /// is at index `dst` **before the move**, or to the tail if `dst == size()`.  
+    /// The moved elements keep their order, as do the rest.  For example, to move 
+    /// the elements at 5 through 8 to just after the element at 1, use 
+    /// `move_range(5..9, 2)`.
+    /// 
+    /// Only links are changed; every element stays in its node, so 
+    /// [`NodeHandle`]s remain valid.  Moving a range onto itself (`dst == src.start` 
+    /// or `dst == src.end`), or moving an empty range, does nothing.  The list's 
+    /// observer sees each moved element as a removal followed by an insertion.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns a [`MoveRangeError`] and leaves the list untouched if `src` is 
+    /// reversed or out of bounds, if `dst > size()`, or if `dst` is strictly 
+    /// between `src.start` and `src.end`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, MoveRangeError};
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..6 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// list.move_range(3..5, 1).unwrap();
+    /// assert_eq!(list.to_string(), "... <=> 0 <=> 3 <=> 4 <=> 1 <=> 2 <=> 5 <=> ...");
+    /// 
+    /// assert_eq!(list.move_range(1..4, 2), Err(MoveRangeError::DestinationInSource));
+    /// ```
+    pub fn move_range(&mut self, src: Range<usize>, dst: usize) -> Result<(), MoveRangeError> {
+        let Range { start, end } = src;
+        if start > end || end > self.size {
+            return Err(MoveRangeError::SourceOutOfBounds);
+        }
+        if dst > self.size {
+            return Err(MoveRangeError::DestinationOutOfBounds);
+        }
+        if start < dst && dst < end {
+            return Err(MoveRangeError::DestinationInSource);
+        }
+        if start == end || dst == start || dst == end {
+            return Ok(());
+        }
+
+        // the node each moved node goes after, which is None for the head
+        let mut prev = if dst == 0 { None } else { self.node_at(dst - 1) };
+        let mut node = self.node_at(start).unwrap();
+        for i in 0..end - start {
+            let next = if i + 1 < end - start { Some(Node::next_of(&node)) } else { None };
+
+            // Moving backward, each node leaves from where the last one was and 
+            // lands after it.  Moving forward, each node leaves from start and 
+            // lands just before the element originally at dst.
+            let (from, to) = if dst < start { (start + i, dst + i) } else { (start, dst - 1) };
+
+            self.unlink_node(&node);
+            self.notify_node(&node, |value| ListEvent::RemovedAt { index: from, value });
+            self.link_node_after(prev.as_ref(), Rc::clone(&node));
+            self.notify_node(&node, |value| ListEvent::InsertedAt { index: to, value });
+
+            prev = Some(node);
+            match next {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces every element with the result of calling `f` on it, from head to 
+    /// tail.  Unlike mutating through a `&mut T`, `f` takes ownership of each 
+    /// element, so it can transform values that can't be updated in place.
+    /// 
+    /// Moving a value out of a node requires something to leave in its place, so 
+    /// each node is detached from its neighbours, consumed, and replaced by a new 
+    /// node holding `f`'s result.  The list's order never changes, but any 
+    /// [`NodeHandle`]s to its elements become stale.
+    /// 
+    /// If `f` panics, the element it was given is dropped and the list is left 
+    /// valid, holding the elements already mapped followed by the ones not yet 
+    /// reached.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<String> = CdlList::new();
+    /// list.push_back(String::from("a"));
+    /// list.push_back(String::from("b"));
+    /// 
+    /// // reuses each String's buffer
+    /// list.map_in_place(|mut s| { s.push('!'); s });
+    /// assert_eq!(list.to_string(), "... <=> \"a!\" <=> \"b!\" <=> ...");
+    /// ```
+    pub fn map_in_place<F: FnMut(T) -> T>(&mut self, mut f: F) {
+        if self.is_empty() {
+            return;
+        }
+
+        let size = self.size;
+        let mut prev : Option<Rc<RefCell<Node<T>>>> = None;
+        let mut node = Rc::clone(self.head.as_ref().unwrap());
+        for index in 0..size {
+            let next = if index + 1 < size { Some(Node::next_of(&node)) } else { None };
+
+            // once detached, this is the only reference to the node
+            self.unlink_node(&node);
+            let data = Rc::try_unwrap(node).ok().unwrap().into_inner().data;
+
+            let ref_n = Rc::new(RefCell::new(Node::new(f(data))));
+            self.link_node_after(prev.as_ref(), Rc::clone(&ref_n));
+            prev = Some(ref_n);
+
+            match next {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+    }
+
+    /// Returns a new list with one element for each element of this list, produced 
+    /// by calling `f` on the elements from head to tail, like [`Iterator::scan()`]. 
+    /// `f` is also passed a mutable reference to `state`, which starts as `init` 
+    /// and carries whatever `f` leaves in it on to the next call.
+    /// 
+    /// The new list is built as a detached chain of nodes in a single pass and 
+    /// linked in all at once.  An empty list gives an empty list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut words : CdlList<&str> = CdlList::new();
+    /// words.push_back("ring");
+    /// words.push_back("of");
+    /// words.push_back("words");
+    /// 
+    /// // the offset of each word in "ring of words"
+    /// let offsets = words.scan(0, |next, word| {
+    ///     let offset = *next;
+    ///     *next += word.len() + 1;
+    ///     offset
+    /// });
+    /// assert_eq!(offsets.to_string(), "... <=> 0 <=> 5 <=> 8 <=> ...");
+    /// ```
+    pub fn scan<S, U, F>(&self, init: S, mut f: F) -> CdlList<U>
+    where
+        F: FnMut(&mut S, &T) -> U,
+    {
+        let mut out = CdlList::new();
+        let mut state = init;
+        let mut chain = None;
+        self.walk(|val| Node::push_to_chain(&mut chain, f(&mut state, val), &out.owner));
+
+        if let Some((head, tail)) = chain {
+            out.link_chain_back(head, tail, self.size);
+        }
+        out
+    }
+
+    /// Calls `f` on each element from head to tail until it returns 
+    /// [`ControlFlow::Break`], and returns the break value, or `None` if `f` never 
+    /// breaks.  `f` isn't called again after it breaks.  This walks the list 
+    /// without allocating, and each element is only borrowed while `f` runs.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::ops::ControlFlow;
+    /// 
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 1..=5 {
+    ///     list.push_back(i * i);
+    /// }
+    /// 
+    /// // the first square over 5
+    /// let found = list.traverse(|&x| if x > 5 { ControlFlow::Break(x) } else { ControlFlow::Continue(()) });
+    /// assert_eq!(found, Some(9));
+    /// ```
+    pub fn traverse<B, F: FnMut(&T) -> ControlFlow<B>>(&self, f: F) -> Option<B> {
+        self.traverse_from(self.head.as_ref(), Node::next_of, f)
+    }
+
+    /// Like [`CdlList::traverse()`], but walks from tail to head following each 
+    /// element's previous link.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::ops::ControlFlow;
+    /// 
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 1..=5 {
+    ///     list.push_back(i * i);
+    /// }
+    /// 
+    /// // the last square under 10
+    /// let found = list.traverse_rev(|&x| if x < 10 { ControlFlow::Break(x) } else { ControlFlow::Continue(()) });
+    /// assert_eq!(found, Some(9));
+    /// ```
+    pub fn traverse_rev<B, F: FnMut(&T) -> ControlFlow<B>>(&self, f: F) -> Option<B> {
+        self.traverse_from(self.tail.as_ref(), Node::prev_of, f)
+    }
+
+    /// Returns the last element matching `pred` as an [`ElemRef`], or `None` if no 
+    /// element matches.  The search walks from the tail towards the head and stops 
+    /// at the first match, so `pred` is only called on the elements after it.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut log : CdlList<(u32, &str)> = CdlList::new();
+    /// log.push_back((1, "login"));
+    /// log.push_back((2, "save"));
+    /// log.push_back((3, "login"));
+    /// log.push_back((4, "save"));
+    /// 
+    /// let last_login = log.rfind(|&(_, event)| event == "login").unwrap();
+    /// assert_eq!(last_login.borrow().0, 3);
+    /// ```
+    pub fn rfind<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<ElemRef<'_, T>> {
+        self.rfind_node(pred).map(|(_, node)| ElemRef::new(node))
+    }
+
+    /// Returns the index, counting from the head, of the last element matching 
+    /// `pred`, or `None` if no element matches.  Like [`CdlList::rfind()`], this 
+    /// walks from the tail and stops at the first match.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from("abcabc");
+    /// assert_eq!(list.rposition(|&c| c == 'a'), Some(3));
+    /// assert_eq!(list.rposition(|&c| c == 'z'), None);
+    /// ```
+    pub fn rposition<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.rfind_node(pred).map(|(index, _)| index)
+    }
+
+    /// Moves the element at `index` to its place in a list that is otherwise sorted 
+    /// according to `compare`, and returns the element's new index.  This is meant 
+    /// for restoring order after changing a single element of a sorted list, and 
+    /// only walks as far as the element needs to move.  The other elements keep 
+    /// their relative order, and the moved element is placed after any equal 
+    /// elements it passes when moving towards the head, or before them when moving 
+    /// towards the tail.
+    /// 
+    /// Since this relinks the element's node, it is reported to the list's observer 
+    /// as a removal followed by an insertion.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index >= size()`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(9);
+    /// list.push_back(5);
+    /// list.push_back(1);
+    /// list.push_back(7); // sorted in descending order, except for the tail
+    /// 
+    /// assert_eq!(list.resort_by(3, |a, b| b.cmp(a)), 1);
+    /// assert_eq!(list.to_string(), "... <=> 9 <=> 7 <=> 5 <=> 1 <=> ...");
+    /// ```
+    pub fn resort_by<F>(&mut self, index: usize, mut compare: F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let node = match self.node_at(index) {
+            Some(node) => node,
+            None => panic!("resort index (is {}) should be < size (is {})", index, self.size),
+        };
+        let mut less = |a: &Rc<RefCell<Node<T>>>, b: &Rc<RefCell<Node<T>>>| {
+            compare(&a.borrow().data, &b.borrow().data) == Ordering::Less
+        };
+        let mut new_index = index;
+
+        // the node that will come before the moved node, or None for the head
+        let prev;
+        if index > 0 && less(&node, &Node::prev_of(&node)) {
+            // walk towards the head
+            let mut before = Node::prev_of(&node);
+            new_index -= 1;
+            while new_index > 0 {
+                let next_before = Node::prev_of(&before);
+                if !less(&node, &next_before) {
+                    break;
+                }
+                before = next_before;
+                new_index -= 1;
+            }
+            prev = if new_index == 0 { None } else { Some(Node::prev_of(&before)) };
+        } else if index + 1 < self.size && less(&Node::next_of(&node), &node) {
+            // walk towards the tail
+            let mut after = Node::next_of(&node);
+            new_index += 1;
+            while new_index + 1 < self.size {
+                let next_after = Node::next_of(&after);
+                if !less(&next_after, &node) {
+                    break;
+                }
+                after = next_after;
+                new_index += 1;
+            }
+            prev = Some(after);
+        } else {
+            return index;
+        }
+
+        self.unlink_node(&node);
+        self.notify_node(&node, |value| ListEvent::RemovedAt { index, value });
+        self.link_node_after(prev.as_ref(), Rc::clone(&node));
+        self.notify_node(&node, |value| ListEvent::InsertedAt { index: new_index, value });
+
+        new_index
+    }
+
+    /// Moves the element at `index` to its place in a list that is otherwise sorted 
+    /// by the key `f` extracts, and returns the element's new index.  See 
+    /// [`CdlList::resort_by()`].
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index >= size()`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<(u32, &str)> = CdlList::new();
+    /// list.push_back((1, "a"));
+    /// list.push_back((5, "b"));
+    /// list.push_back((9, "c"));
+    /// 
+    /// // "c" drops to the lowest priority
+    /// list.remove_at(2);
+    /// list.push_back((0, "c"));
+    /// 
+    /// assert_eq!(list.resort_by_key(2, |(priority, _)| *priority), 0);
+    /// assert_eq!(*list.peek_front().unwrap(), (0, "c"));
+    /// ```
+    pub fn resort_by_key<K, F>(&mut self, index: usize, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.resort_by(index, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Inserts `value` at its place in a list that is sorted according to 
+    /// `compare`, after any elements equal to it, and returns its index.  If the 
+    /// list isn't sorted, `value` is still inserted, but where is unspecified.
+    /// 
+    /// The place is searched for from the head and the tail at once, so this only 
+    /// walks as far as the new element's distance from whichever end is closer. 
+    /// The insertion is reported to the list's observer like 
+    /// [`CdlList::insert_at()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// // a queue sorted by descending priority
+    /// let mut queue = CdlList::from([(9, "page"), (5, "email"), (1, "backup")]);
+    /// 
+    /// let index = queue.insert_sorted_by((5, "chat"), |a, b| b.0.cmp(&a.0));
+    /// assert_eq!(index, 2);
+    /// assert_eq!(queue.get(2).unwrap().borrow().1, "chat");
+    /// ```
+    pub fn insert_sorted_by<F>(&mut self, value: T, mut compare: F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // the node the new one goes after, or None for the head
+        let (index, prev) = match (&self.head, &self.tail) {
+            (Some(head), Some(tail)) => {
+                let mut forward = Rc::clone(head);
+                let mut backward = Rc::clone(tail);
+                let mut steps = 0;
+                let found = loop {
+                    // the first element from the head that is greater than value...
+                    if compare(&value, &forward.borrow().data) == Ordering::Less {
+                        let prev = if steps == 0 { None } else { Some(Node::prev_of(&forward)) };
+                        break (steps, prev);
+                    }
+                    // ...is just after the first one from the tail that isn't
+                    if compare(&value, &backward.borrow().data) != Ordering::Less {
+                        break (self.size - steps, Some(backward));
+                    }
+
+                    // only an inconsistent compare gets this far
+                    steps += 1;
+                    if steps == self.size {
+                        break (self.size, Some(Rc::clone(tail)));
+                    }
+                    forward = Node::next_of(&forward);
+                    backward = Node::prev_of(&backward);
+                };
+                self.record_seek(steps);
+                found
+            },
+            _ => (0, None),
+        };
+
+        let ref_n = Rc::new(RefCell::new(Node::new(value)));
+        self.link_node_after(prev.as_ref(), Rc::clone(&ref_n));
+        self.notify_node(&ref_n, |value| ListEvent::InsertedAt { index, value });
+        index
+    }
+
+    /// Inserts `value` at its place in a list that is sorted by the key `f` 
+    /// extracts, after any elements with an equal key, and returns its index.  See 
+    /// [`CdlList::insert_sorted_by()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut words = CdlList::from(["a", "to", "the"]);
+    /// assert_eq!(words.insert_sorted_by_key("an", |w| w.len()), 2);
+    /// assert_eq!(words.to_string(), r#"... <=> "a" <=> "to" <=> "an" <=> "the" <=> ..."#);
+    /// ```
+    pub fn insert_sorted_by_key<K, F>(&mut self, value: T, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.insert_sorted_by(value, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Sorts the list according to `compare`, like [`slice::sort_by()`].  The sort 
+    /// is stable, so equal elements keep their order. 
+    /// 
+    /// This is a bottom-up merge sort on the nodes' links: the ring is opened into 
+    /// a chain, runs of 1, 2, 4, ... nodes are merged in place, and the ring is 
+    /// closed again at the end.  It takes O(n log n) time and no extra memory, and 
+    /// since no node is moved or reallocated, handles follow their elements.  No 
+    /// elements are added or removed, so the list's observer isn't told anything. 
+    /// If `compare` panics, the list keeps all of its elements, but in an 
+    /// unspecified order.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut words = CdlList::from(["pear", "fig", "apple", "kiwi"]);
+    /// words.sort_by(|a, b| a.len().cmp(&b.len()));
+    /// 
+    /// // "pear" and "kiwi" have the same length, and keep their order
+    /// assert_eq!(words.to_string(), r#"... <=> "fig" <=> "pear" <=> "kiwi" <=> "apple" <=> ..."#);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if self.size < 2 {
+            return;
+        }
+
+        // open the ring into a chain of strong links, emptying the list until the 
+        // sort is done, when dropping chains closes it again
+        let size = std::mem::take(&mut self.size);
+        let head = self.head.take();
+        self.tail.take().unwrap().borrow_mut().next = None;
+        let mut chains = OpenChains { list: self, size, merged: None, left: None, right: None, rest: head };
+
+        let mut width = 1;
+        while width < size {
+            while let Some(left) = chains.rest.take() {
+                chains.right = Node::cut_run(&left, width);
+                chains.rest = chains.right.as_ref().and_then(|right| Node::cut_run(right, width));
+                chains.left = Some(left);
+                Node::merge_runs(&mut chains.merged, &mut chains.left, &mut chains.right, &mut compare, &mut |_, _| {});
+            }
+            chains.rest = chains.merged.take().map(|(head, _)| head);
+            width *= 2;
+        }
+    }
+
+    /// Sorts the list by the key `f` extracts, like [`slice::sort_by_key()`].  See 
+    /// [`CdlList::sort_by()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut jobs = CdlList::from([(3, "c"), (1, "a"), (2, "b")]);
+    /// jobs.sort_by_key(|(priority, _)| *priority);
+    /// assert_eq!(*jobs.peek_front().unwrap(), (1, "a"));
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges `other` into this list, both sorted according to `compare`, so that 
+    /// the result is sorted too.  The merge is stable: equal elements keep their 
+    /// order within each list, and this list's come before `other`'s.  If either 
+    /// list isn't sorted, the result holds every element but in an unspecified 
+    /// order.
+    /// 
+    /// Like [`CdlList::sort_by()`], this relinks the existing nodes rather than 
+    /// moving elements, so it takes O(n + m) time and no extra memory, and handles 
+    /// to `other`'s elements now belong to this list.  If this list has an 
+    /// observer, each of `other`'s elements is reported to it as inserted at its 
+    /// final index, which means remembering them until the merge is done.  If 
+    /// `compare` panics, this list keeps the elements of both lists, in an 
+    /// unspecified order, and its observer isn't told about `other`'s.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut evens = CdlList::from([0, 2, 4, 6]);
+    /// let odds = CdlList::from([1, 3, 5]);
+    /// 
+    /// evens.merge_by(odds, |a: &u32, b| a.cmp(b));
+    /// assert_eq!(evens.to_string(), "... <=> 0 <=> 1 <=> 2 <=> 3 <=> 4 <=> 5 <=> 6 <=> ...");
+    /// ```
+    pub fn merge_by<F>(&mut self, mut other: CdlList<T>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let Some(((other_head, other_tail), other_len)) = self.adopt_ring(&mut other) else {
+            return;
+        };
+        other_tail.borrow_mut().next = None;
+
+        // open this list's ring too, emptying it until the merge is done, when 
+        // dropping chains closes it again
+        let size = std::mem::take(&mut self.size);
+        let head = self.head.take();
+        if let Some(tail) = self.tail.take() {
+            tail.borrow_mut().next = None;
+        }
+
+        let observing = self.observed();
+        let mut inserted = Vec::new();
+        let mut chains = OpenChains { list: self, size: size + other_len, merged: None, left: head, right: Some(other_head), rest: None };
+        Node::merge_runs(&mut chains.merged, &mut chains.left, &mut chains.right, &mut compare, &mut |index, node| {
+            if observing {
+                inserted.push((index, Rc::clone(node)));
+            }
+        });
+
+        drop(chains);
+        for (index, node) in inserted {
+            self.notify_node(&node, |value| ListEvent::InsertedAt { index, value });
+        }
+    }
+
+    /// Returns whether this list and `other` have the same size and `f` returns 
+    /// `true` for every pair of elements at the same index, like 
+    /// [`Iterator::eq_by()`].  The lists are walked together from their heads, 
+    /// stopping at the first pair `f` rejects, and lists of different sizes are 
+    /// unequal without calling `f` at all.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut a : CdlList<f64> = CdlList::new();
+    /// a.push_back(0.1 + 0.2);
+    /// a.push_back(1.0);
+    /// 
+    /// let mut b : CdlList<f64> = CdlList::new();
+    /// b.push_back(0.3);
+    /// b.push_back(1.0);
+    /// 
+    /// assert!(a.eq_by(&b, |x, y| (x - y).abs() < 1e-9));
+    /// ```
+    pub fn eq_by<U, F>(&self, other: &CdlList<U>, mut f: F) -> bool
+    where
+        F: FnMut(&T, &U) -> bool,
+    {
+        if self.size != other.size {
+            return false;
+        }
+        if self.is_empty() {
+            return true;
+        }
+
+        let mut a = Rc::clone(self.head.as_ref().unwrap());
+        let mut b = Rc::clone(other.head.as_ref().unwrap());
+        for i in 0..self.size {
+            if !f(&a.borrow().data, &b.borrow().data) {
+                return false;
+            }
+            if i + 1 < self.size {
+                a = Node::next_of(&a);
+                b = Node::next_of(&b);
+            }
+        }
+        true
+    }
+
+    /// Compares this list with `other` lexicographically using `f` to compare 
+    /// elements, like [`Iterator::cmp_by()`].  The lists are walked together from 
+    /// their heads and the first pair that isn't [`Ordering::Equal`] decides the 
+    /// result.  If one list runs out first, it is the lesser, so a list is 
+    /// always less than any longer list it is a prefix of.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::cmp::Ordering;
+    /// 
+    /// let mut a : CdlList<(u32, &str)> = CdlList::new();
+    /// a.push_back((1, "x"));
+    /// a.push_back((2, "y"));
+    /// 
+    /// let mut b : CdlList<(u32, &str)> = CdlList::new();
+    /// b.push_back((1, "z"));
+    /// b.push_back((3, "a"));
+    /// 
+    /// // only the numbers are compared
+    /// assert_eq!(a.cmp_by(&b, |x, y| x.0.cmp(&y.0)), Ordering::Less);
+    /// 
+    /// b.pop_back();
+    /// assert_eq!(a.cmp_by(&b, |x, y| x.0.cmp(&y.0)), Ordering::Greater);
+    /// ```
+    pub fn cmp_by<F>(&self, other: &CdlList<T>, mut f: F) -> Ordering
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let common = self.size.min(other.size);
+        if common > 0 {
+            let mut a = Rc::clone(self.head.as_ref().unwrap());
+            let mut b = Rc::clone(other.head.as_ref().unwrap());
+            for i in 0..common {
+                let ord = f(&a.borrow().data, &b.borrow().data);
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+                if i + 1 < common {
+                    a = Node::next_of(&a);
+                    b = Node::next_of(&b);
+                }
+            }
+        }
+
+        self.size.cmp(&other.size)
+    }
+
+    /// Registers a callback that is invoked after each successful push, pop, 
+    /// insertion, or removal, with a [`ListEvent`] describing the change and a 
+    /// reference to the affected element.  Operations that insert or remove 
+    /// several elements, like [`CdlList::replace_range()`], report one event per 
+    /// element, except [`CdlList::clear()`], which reports a single 
+    /// [`ListEvent::Cleared`].  Setting a new observer replaces the old one.
+    /// 
+    /// The observer must not try to modify the list it is observing.  The borrow 
+    /// checker already prevents this unless the list is shared through something 
+    /// like `Rc<RefCell<CdlList<T>>>`, in which case the `RefCell` will panic 
+    /// with a borrow error.  As an extra guard, the observer is removed from the 
+    /// list while it runs, so it is never invoked recursively.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, ListEvent};
+    /// use std::{cell::RefCell, rc::Rc};
+    /// 
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let log_clone = Rc::clone(&log);
+    /// 
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.set_observer(Box::new(move |event| {
+    ///     log_clone.borrow_mut().push(format!("{:?}", event));
+    /// }));
+    /// 
+    /// list.push_back(1);
+    /// list.insert_at(0, 2).unwrap();
+    /// list.pop_back();
+    /// 
+    /// assert_eq!(*log.borrow(), vec![
+    ///     "Pushed { front: false, value: 1 }",
+    ///     "InsertedAt { index: 0, value: 2 }",
+    ///     "Popped { front: false, value: 1 }",
+    /// ]);
+    /// ```
+    pub fn set_observer(&mut self, observer: Observer<T>) {
+        self.observer = Some(observer);
+    }
+
+    /// Removes and returns the list's observer, if one was set.  See 
+    /// [`CdlList::set_observer()`].
+    pub fn remove_observer(&mut self) -> Option<Observer<T>> {
+        self.observer.take()
+    }
+
+    // Reports a change to the observer, if there is one, and to tracing, if 
+    // enabled.  The observer is taken out of the list while it runs, so it can 
+    // never be invoked recursively.
+    fn notify(&mut self, event: ListEvent<&T>) {
+        self.trace_event(&event);
+        if let Some(mut observer) = self.observer.take() {
+            observer(event);
+            self.observer = Some(observer);
+        }
+    }
+
+    // Like notify, but for an event about the element inside node.
+    fn notify_node<F>(&mut self, node: &Rc<RefCell<Node<T>>>, event: F)
+    where
+        F: for<'a> FnOnce(&'a T) -> ListEvent<&'a T>,
+    {
+        if self.observed() {
+            let node_ref = node.borrow();
+            self.notify(event(&node_ref.data));
+        }
+    }
+
+    // Whether notify would reach anyone.  Operations that move whole chains of 
+    // nodes check this first, so they only walk the chain to report each 
+    // element when there is an observer or a tracing subscriber to see them.
+    fn observed(&self) -> bool {
+        self.observer.is_some() || self.trace_enabled()
+    }
+
+    /// Checks that the list's internal links are consistent, returning a 
+    /// description of the first problem found.  Walking forward from the head must 
+    /// reach the tail after exactly `size() - 1` strong links and return to the head 
+    /// through a single weak link, and every node's prev link must point back at the 
+    /// node before it.
+    /// 
+    /// This is intended for tests and debugging; a list built only through this 
+    /// crate's API should always be valid.  See also [`crate::assert_list_invariants!`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// assert!(list.validate().is_ok());
+    /// 
+    /// list.push_back(1);
+    /// list.push_front(2);
+    /// list.insert_at(1, 3).unwrap();
+    /// assert!(list.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let (head, tail) = match (&self.head, &self.tail) {
+            (None, None) if self.size == 0 => return Ok(()),
+            (Some(head), Some(tail)) if self.size > 0 => (head, tail),
+            _ => return Err(format!("head/tail pointers do not match size {}", self.size)),
+        };
+
+        let mut node = Rc::clone(head);
+        for i in 0..self.size {
+            let node_ref = node.borrow();
+            let is_tail = i == self.size - 1;
+
+            let next = match &node_ref.next {
+                Some(LinkType::StrongLink(sl)) if !is_tail => Rc::clone(sl),
+                Some(LinkType::WeakLink(wl)) if is_tail => match wl.upgrade() {
+                    Some(up) => up,
+                    None => return Err(String::from("tail->next points to a dropped node")),
+                },
+                Some(_) => return Err(format!("node {} has the wrong kind of next link", i)),
+                None => return Err(format!("node {} has no next link", i)),
+            };
+
+            let prev_of_next = match &next.borrow().prev {
+                Some(LinkType::WeakLink(wl)) => wl.upgrade(),
+                _ => return Err(format!("node {} does not have a weak prev link", (i + 1) % self.size)),
+            };
+            if !prev_of_next.is_some_and(|p| Rc::ptr_eq(&p, &node)) {
+                return Err(format!("node {}->next->prev does not point back to it", i));
+            }
+
+            if is_tail {
+                if !Rc::ptr_eq(&node, tail) {
+                    return Err(format!("node {} should be the tail", i));
+                }
+                if !Rc::ptr_eq(&next, head) {
+                    return Err(String::from("tail->next does not point to the head"));
+                }
+            } else if Rc::ptr_eq(&next, head) {
+                return Err(format!("list loops back to the head after {} nodes", i + 1));
+            }
+
+            drop(node_ref);
+            node = next;
+        }
+
+        Ok(())
+    }
+
+    // Converts a range into (start, end) indices, returning None if the range 
+    // is decreasing or extends past the end of the list.
+    fn bounds_of<R: RangeBounds<usize>>(&self, range: R) -> Option<(usize, usize)> {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e.checked_add(1)?,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.size,
+        };
+
+        if start > end || end > self.size {
+            return None;
+        }
+
+        Some((start, end))
+    }
+
+    // Calls f on each element from head to tail, borrowing one node at a time.
+    fn walk<F: FnMut(&T)>(&self, mut f: F) {
+        self.traverse(|val| {
+            f(val);
+            ControlFlow::<()>::Continue(())
+        });
+    }
+
+    // Calls f on size elements starting at start and stepping to each next one 
+    // with step, until f breaks.
+    fn traverse_from<B, S, F>(&self, start: Option<&Rc<RefCell<Node<T>>>>, step: S, mut f: F) -> Option<B>
+    where
+        S: Fn(&Rc<RefCell<Node<T>>>) -> Rc<RefCell<Node<T>>>,
+        F: FnMut(&T) -> ControlFlow<B>,
+    {
+        let mut node = Rc::clone(start?);
+        for i in 0..self.size {
+            if let ControlFlow::Break(b) = f(&node.borrow().data) {
+                return Some(b);
+            }
+            if i + 1 < self.size {
+                node = step(&node);
+            }
+        }
+        None
+    }
+
+    // Makes new_head, which is at index mid (not 0), the head of the list without 
+    // moving any nodes.
+    fn reseat_head(&mut self, new_head: Rc<RefCell<Node<T>>>, mid: usize) {
+        let new_tail = Node::prev_of(&new_head);
+        let old_head = Rc::clone(self.head.as_ref().unwrap());
+        let old_tail = self.tail.take().unwrap();
+
+        // close the old seam with a strong link, then open the new one, which 
+        // becomes the only weak next link
+        old_tail.borrow_mut().next = Some(LinkType::StrongLink(old_head));
+        new_tail.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(&new_head)));
+
+        self.head = Some(new_head);
+        self.tail = Some(new_tail);
+        self.trace_rotate(mid);
+    }
+
+    // Cuts the list just before target, which must belong to it, and returns a 
+    // new list from target to the old tail.  If target is the head, everything 
+    // moves.  The moved nodes are handed over to the new list and reported to this 
+    // list's observer as removals.
+    fn split_off_node(&mut self, target: Rc<RefCell<Node<T>>>) -> CdlList<T> {
+        let mut out : CdlList<T> = CdlList::new();
+
+        // hand the moved nodes over to the new list before relinking
+        let old_tail = Rc::clone(self.tail.as_ref().unwrap());
+        let moved = out.adopt_run(&target, &old_tail);
+
+        if Rc::ptr_eq(&target, self.head.as_ref().unwrap()) {
+            out.head = self.head.take();
+            out.tail = self.tail.take();
+        } else {
+            // close this list's circle just before the cut...
+            let before = Node::prev_of(&target);
+            let head = self.head.as_ref().unwrap();
+            before.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(head)));
+            head.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&before)));
+            self.tail = Some(before);
+
+            // ...and the new list's circle from the cut to the old tail
+            old_tail.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(&target)));
+            target.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&old_tail)));
+            out.head = Some(target);
+            out.tail = Some(old_tail);
+        }
+        out.size = moved.len();
+
+        let start = self.size - moved.len();
+        for node in &moved {
+            self.size -= 1;
+            self.notify_node(node, |value| ListEvent::RemovedAt { index: start, value });
+        }
+        out
+    }
+
+    // Cuts the list just before target, which must belong to it, and returns a 
+    // new list from the head to the node before target, which becomes the head. 
+    // If target is the head, nothing moves.  The moved nodes are handed over to 
+    // the new list and reported to this list's observer as removals from index 0.
+    fn split_before_node(&mut self, target: Rc<RefCell<Node<T>>>) -> CdlList<T> {
+        let mut out : CdlList<T> = CdlList::new();
+        if Rc::ptr_eq(&target, self.head.as_ref().unwrap()) {
+            return out;
+        }
+
+        let before = Node::prev_of(&target);
+        let old_head = self.head.take().unwrap();
+        let moved = out.adopt_run(&old_head, &before);
+
+        // close the new list's circle from the old head to the cut...
+        before.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(&old_head)));
+        old_head.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&before)));
+
+        // ...and this list's circle from the cut to the tail
+        let tail = self.tail.as_ref().unwrap();
+        tail.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(&target)));
+        target.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(tail)));
+        self.head = Some(target);
+
+        out.head = Some(old_head);
+        out.tail = Some(before);
+        out.size = moved.len();
+
+        for node in &moved {
+            self.size -= 1;
+            self.notify_node(node, |value| ListEvent::RemovedAt { index: 0, value });
+        }
+        out
+    }
+
+    // Makes the open chain starting at head, with size nodes, this list's ring, 
+    // restoring the prev links along the way.
+    fn close_chain(&mut self, head: Rc<RefCell<Node<T>>>, size: usize) {
+        let mut tail = Rc::clone(&head);
+        while let Some(next) = Node::chain_next(&tail) {
+            next.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&tail)));
+            tail = next;
+        }
+        tail.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(&head)));
+        head.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&tail)));
+
+        self.head = Some(head);
+        self.tail = Some(tail);
+        self.size = size;
+    }
+
+    // Empties other, returning its head and tail as a chain along with its size, 
+    // or None if it was already empty.  Its nodes, which are left linked to each other, are reported to 
+    // other's observer as removals and then belong to this list without being 
+    // visited.
+    fn adopt_ring(&self, other: &mut CdlList<T>) -> Option<(Chain<T>, usize)> {
+        let (Some(head), Some(tail)) = (other.head.take(), other.tail.take()) else {
+            return None;
+        };
+        let len = other.size;
+
+        if other.observed() {
+            let mut node = Rc::clone(&head);
+            for _ in 0..len {
+                let next = Node::next_of(&node);
+                other.size -= 1;
+                other.notify_node(&node, |value| ListEvent::RemovedAt { index: 0, value });
+                node = next;
+            }
+        }
+        other.size = 0;
+
+        *other.owner.forward.borrow_mut() = Some(Rc::clone(&self.owner));
+        other.owner = Rc::default();
+        Some(((head, tail), len))
+    }
+
+    // Hands the nodes from first to last, walking forwards, over to this list, 
+    // and returns them in order.  The nodes aren't relinked.
+    fn adopt_run(&self, first: &Rc<RefCell<Node<T>>>, last: &Rc<RefCell<Node<T>>>) -> Vec<Rc<RefCell<Node<T>>>> {
+        let mut run = vec![Rc::clone(first)];
+        while !Rc::ptr_eq(run.last().unwrap(), last) {
+            run.push(Node::next_of(run.last().unwrap()));
+        }
+        for node in &run {
+            node.borrow_mut().owner = Some(Rc::clone(&self.owner));
+        }
+        run
+    }
+
+    // Finds the middle node with a slow and a fast pointer.
+    fn middle_node(&self) -> Option<Rc<RefCell<Node<T>>>> {
+        let tail = self.tail.as_ref()?;
+        let mut slow = Rc::clone(self.head.as_ref().unwrap());
+        let mut fast = Rc::clone(&slow);
+        let mut steps = 0;
+        while !Rc::ptr_eq(&fast, tail) {
+            fast = Node::next_of(&fast);
+            if Rc::ptr_eq(&fast, tail) {
+                break;
+            }
+            fast = Node::next_of(&fast);
+            slow = Node::next_of(&slow);
+            steps += 1;
+        }
+
+        self.record_seek(steps);
+        Some(slow)
+    }
+
+    // Returns the index of node, which must belong to this list, by walking from 
+    // the head.
+    fn index_of_node(&self, target: &Rc<RefCell<Node<T>>>) -> usize {
+        let mut node = Rc::clone(self.head.as_ref().unwrap());
+        let mut index = 0;
+        while !Rc::ptr_eq(&node, target) {
+            node = Node::next_of(&node);
+            index += 1;
+        }
+
+        self.record_seek(index);
+        index
+    }
+
+    // Walks from the tail to the last node matching pred, returning it and its 
+    // index.
+    fn rfind_node<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<(usize, Rc<RefCell<Node<T>>>)> {
+        let mut node = Rc::clone(self.tail.as_ref()?);
+        for steps in 0..self.size {
+            if pred(&node.borrow().data) {
+                self.record_seek(steps);
+                return Some((self.size - 1 - steps, node));
+            }
+            if steps + 1 < self.size {
+                node = Node::prev_of(&node);
+            }
+        }
+
+        self.record_seek(self.size - 1);
+        None
+    }
+
+    // Like rfind_node, but walks from the head and returns the first match.
+    fn find_node<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<(usize, Rc<RefCell<Node<T>>>)> {
+        let mut node = Rc::clone(self.head.as_ref()?);
+        for index in 0..self.size {
+            if pred(&node.borrow().data) {
+                self.record_seek(index);
+                return Some((index, node));
+            }
+            if index + 1 < self.size {
+                node = Node::next_of(&node);
+            }
+        }
+
+        self.record_seek(self.size - 1);
+        None
+    }
+
+    // Returns every node, from head to tail.
+    fn nodes(&self) -> Vec<Rc<RefCell<Node<T>>>> {
+        let mut nodes = Vec::with_capacity(self.size);
+        if let Some(head) = &self.head {
+            let mut node = Rc::clone(head);
+            for i in 0..self.size {
+                let next = if i + 1 < self.size { Some(Node::next_of(&node)) } else { None };
+                nodes.push(node);
+                match next {
+                    Some(next) => node = next,
+                    None => break,
+                }
+            }
+        }
+        nodes
+    }
+
+    // Returns the node at index, walking from whichever end is closer.
+    fn node_at(&self, index: usize) -> Option<Rc<RefCell<Node<T>>>> {
+        if index >= self.size {
+            return None;
+        }
+
+        self.record_seek(if index <= self.size / 2 { index } else { self.size - 1 - index });
+        if index <= self.size / 2 {
+            let mut node = Rc::clone(self.head.as_ref().unwrap());
+            for _ in 0..index {
+                node = Node::next_of(&node);
+            }
+            Some(node)
+        } else {
+            // traverse list in reverse
+            let mut node = Rc::clone(self.tail.as_ref().unwrap());
+            for _ in index..self.size - 1 {
+                node = Node::prev_of(&node);
+            }
+            Some(node)
+        }
+    }
+
+    // Returns the node at index counting backwards from the tail.
+    fn node_from_back(&self, index: usize) -> Option<Rc<RefCell<Node<T>>>> {
+        if index >= self.size {
+            return None;
+        }
+
+        self.record_seek(index);
+        let mut node = Rc::clone(self.tail.as_ref().unwrap());
+        for _ in 0..index {
+            node = Node::prev_of(&node);
+        }
+        Some(node)
+    }
+
+    // Detaches a node that belongs to this list, fixing the links around it.  
+    // The caller keeps its own reference to the node, whose links are cleared.
+    fn unlink_node(&mut self, node: &Rc<RefCell<Node<T>>>) {
+        if Rc::ptr_eq(node, self.head.as_ref().unwrap()) {
+            self.pop_node(true);
+            return;
+        }
+        if Rc::ptr_eq(node, self.tail.as_ref().unwrap()) {
+            self.pop_node(false);
+            return;
+        }
+
+        // node is in the middle, so node->prev->next and node->next are strong
+        let prev = Node::prev_of(node);
+        let next = Node::next_of(node);
+
+        {
+            let mut node_mut = node.borrow_mut();
+            node_mut.next = None;
+            node_mut.prev = None;
+        }
+
+        prev.borrow_mut().next = Some(LinkType::StrongLink(Rc::clone(&next)));
+        next.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&prev)));
+
+        self.size -= 1;
+    }
+
+    // Links a detached node in after prev, or at the head if prev is None.
+    fn link_node_after(&mut self, prev: Option<&Rc<RefCell<Node<T>>>>, ref_n: Rc<RefCell<Node<T>>>) {
+        let prev = match prev {
+            Some(p) if !Rc::ptr_eq(p, self.tail.as_ref().unwrap()) => p,
+            Some(_) => {
+                self.push_node(ref_n, false);
+                return;
+            },
+            None => {
+                self.push_node(ref_n, true);
+                return;
+            }
+        };
+
+        // prev is not the tail, so prev->next is a strong link
+        let next = Node::next_of(prev);
+
+        {
+            let mut ref_n_mut = ref_n.borrow_mut();
+            ref_n_mut.next = Some(LinkType::StrongLink(Rc::clone(&next)));
+            ref_n_mut.prev = Some(LinkType::WeakLink(Rc::downgrade(prev)));
+            ref_n_mut.owner = Some(Rc::clone(&self.owner));
+        }
+
+        next.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&ref_n)));
+        prev.borrow_mut().next = Some(LinkType::StrongLink(ref_n));
+
+        self.size += 1;
+    }
+
+    // Links a chain of len detached nodes in after the tail.  The chain runs from 
+    // head to tail through strong next links, its nodes already belong to this 
+    // list, and its ends are left open for this to close.
+    fn link_chain_back(&mut self, head: Rc<RefCell<Node<T>>>, tail: Rc<RefCell<Node<T>>>, len: usize) {
+        if let Some(old_tail) = self.tail.take() {
+            head.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&old_tail)));
+            old_tail.borrow_mut().next = Some(LinkType::StrongLink(head));
+        } else {
+            self.head = Some(head);
+        }
+
+        let list_head = self.head.as_ref().unwrap();
+        tail.borrow_mut().next = Some(LinkType::WeakLink(Rc::downgrade(list_head)));
+        list_head.borrow_mut().prev = Some(LinkType::WeakLink(Rc::downgrade(&tail)));
+        self.tail = Some(tail);
+
+        self.size += len;
+    }
+
+    // Pushes every value onto the back by building them into a chain first and 
+    // linking it in at once, then reports each one as pushed.  Returns how many 
+    // values were pushed.
+    fn push_back_all<I: IntoIterator<Item = T>>(&mut self, vals: I) -> usize {
+        let mut chain = None;
+        let mut len = 0;
+        for val in vals {
+            Node::push_to_chain(&mut chain, val, &self.owner);
+            len += 1;
+        }
+
+        let Some((head, tail)) = chain else {
+            return 0;
+        };
+        self.link_chain_back(Rc::clone(&head), tail, len);
+
+        let mut node = head;
+        for i in 0..len {
+            self.record_push();
+            self.notify_node(&node, |value| ListEvent::Pushed { front: false, value });
+            if i + 1 < len {
+                node = Node::next_of(&node);
+            }
+        }
+        len
+    }
+}
+
+/// Without the `instrumentation` feature, recording an operation does nothing 
+/// and there is no way to read the counts back:
+/// 
+/// ```compile_fail
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let list : CdlList<u32> = CdlList::new();
+/// list.stats();
+/// ```
+#[cfg(not(feature = "instrumentation"))]
+impl<T> CdlList<T> {
+    fn record_push(&self) {}
+
+    fn record_pop(&self) {}
+
+    fn record_seek(&self, _nodes: usize) {}
+}
+
+// Without the tracing feature, tracing a mutation does nothing.
+#[cfg(not(feature = "tracing"))]
+impl<T> CdlList<T> {
+    fn trace_event(&self, _event: &ListEvent<&T>) {}
+
+    fn trace_enabled(&self) -> bool {
+        false
+    }
+
+    fn trace_splice(&self, _start: usize, _removed: usize, _inserted: usize) {}
+
+    fn trace_rotate(&self, _mid: usize) {}
+}
+
+impl<T: Clone> CdlList<T> {
+    /// Grows or shrinks the list to exactly `len` elements, like [`Vec::resize()`]. 
+    /// Growing pushes clones of `value` to the back, moving `value` itself in 
+    /// last, and reports each as pushed.  Shrinking pops elements from the back 
+    /// until `len` are left, reporting each as popped, and drops `value`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from(["a", "b"]);
+    /// 
+    /// list.resize(4, "z");
+    /// assert_eq!(list.to_string(), r#"... <=> "a" <=> "b" <=> "z" <=> "z" <=> ..."#);
+    /// 
+    /// list.resize(1, "z");
+    /// assert_eq!(list.to_string(), r#"... <=> "a" <=> ..."#);
+    /// ```
+    pub fn resize(&mut self, len: usize, value: T) {
+        if len > self.size {
+            self.push_back_all(std::iter::repeat(value).take(len - self.size));
+        } else {
+            while self.size > len {
+                self.pop_back();
+            }
+        }
+    }
+
+    /// Returns copies of the first `n` elements reached by walking forward from the 
+    /// head, wrapping around to the head after the tail as many times as needed.  
+    /// When `n > size()`, elements appear more than once.  An empty list returns an 
+    /// empty `Vec` for any `n`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// assert_eq!(list.cycle_collect(2), vec![1, 2]);
+    /// assert_eq!(list.cycle_collect(7), vec![1, 2, 3, 1, 2, 3, 1]);
+    /// ```
+    pub fn cycle_collect(&self, n: usize) -> Vec<T> {
+        self.cycle_collect_from(0, n)
+    }
+
+    /// Like [`CdlList::cycle_collect()`], but starts walking from the element at 
+    /// `start` rather than the head.  Since the walk wraps around anyway, `start` 
+    /// may be past the end of the list and is taken modulo `size()`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// assert_eq!(list.cycle_collect_from(2, 4), vec![3, 1, 2, 3]);
+    /// assert_eq!(list.cycle_collect_from(4, 2), vec![2, 3]);
+    /// ```
+    pub fn cycle_collect_from(&self, start: usize, n: usize) -> Vec<T> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let mut out = Vec::with_capacity(n);
+        let mut node = self.node_at(start % self.size).unwrap();
+        for i in 0..n {
+            out.push(node.borrow().data.clone());
+            if i + 1 < n {
+                node = Node::next_of(&node);
+            }
+        }
+        out
+    }
+
+    /// Returns a new list holding `n` copies of this one back to back, like 
+    /// [`slice::repeat()`].  `n == 0` gives an empty list.
+    /// 
+    /// Each copy is cloned into a detached chain of nodes that is linked onto the 
+    /// end of the new list in one step, so every element is cloned exactly once 
+    /// per copy and none of them are pushed individually.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// 
+    /// let big = list.repeat(3);
+    /// assert_eq!(big.size(), 6);
+    /// assert_eq!(big.to_string(), "... <=> 1 <=> 2 <=> 1 <=> 2 <=> 1 <=> 2 <=> ...");
+    /// ```
+    pub fn repeat(&self, n: usize) -> CdlList<T> {
+        let mut out = CdlList::new();
+        for _ in 0..n {
+            match self.clone_chain(&out.owner) {
+                Some((head, tail)) => out.link_chain_back(head, tail, self.size),
+                None => break,
+            }
+        }
+        out
+    }
+
+    /// Inserts a batch of elements, each at an index into the list as it was 
+    /// before the call, so no index needs adjusting for the insertions before it. 
+    /// Each value goes just before the element originally at its index, or at the 
+    /// tail if its index is `size()`.  Values with the same index end up in the 
+    /// order they appear in `entries`.  The values are cloned into the list.
+    /// 
+    /// The entries are sorted by index and spliced in during a single walk from 
+    /// the head, so inserting k elements takes O(n + k log k) time.  Each one is 
+    /// reported to the list's observer with its index after insertion, in order 
+    /// from head to tail.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an [`IndexOutOfBounds`] for the first entry whose index is greater 
+    /// than `size()`, without inserting anything.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, IndexOutOfBounds};
+    /// let mut list = CdlList::from("ace");
+    /// 
+    /// list.insert_many(&[(3, 'f'), (1, 'b'), (2, 'd'), (0, '_')]).unwrap();
+    /// assert_eq!(list.to_string_chars(), "_abcdef");
+    /// 
+    /// assert_eq!(list.insert_many(&[(0, 'x'), (8, 'y')]), Err(IndexOutOfBounds { index: 8, size: 7 }));
+    /// assert_eq!(list.to_string_chars(), "_abcdef");
+    /// ```
+    pub fn insert_many(&mut self, entries: &[(usize, T)]) -> Result<(), IndexOutOfBounds> {
+        if let Some(&(index, _)) = entries.iter().find(|(index, _)| *index > self.size) {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+
+        // a stable sort keeps entries with the same index in the order given
+        let mut sorted : Vec<&(usize, T)> = entries.iter().collect();
+        sorted.sort_by_key(|(index, _)| *index);
+
+        // prev is the node the next insertion goes after, and passed is how many of 
+        // the original elements come before it
+        let mut prev : Option<Rc<RefCell<Node<T>>>> = None;
+        let mut passed = 0;
+        for (inserted, (index, val)) in sorted.into_iter().enumerate() {
+            while passed < *index {
+                prev = Some(match &prev {
+                    Some(node) => Node::next_of(node),
+                    None => Rc::clone(self.head.as_ref().unwrap()),
+                });
+                passed += 1;
+            }
+
+            let ref_n = Rc::new(RefCell::new(Node::new(val.clone())));
+            self.link_node_after(prev.as_ref(), Rc::clone(&ref_n));
+            self.notify_node(&ref_n, |value| ListEvent::InsertedAt { index: index + inserted, value });
+            prev = Some(ref_n);
+        }
+
+        self.record_seek(passed);
+        Ok(())
+    }
+
+    // Clones every element into a new chain of nodes belonging to owner, returning 
+    // its head and tail, or None if the list is empty.
+    fn clone_chain(&self, owner: &Rc<Owner>) -> Option<Chain<T>> {
+        let mut chain = None;
+        self.walk(|val| Node::push_to_chain(&mut chain, val.clone(), owner));
+        chain
+    }
+}
+
+impl<T: PartialEq> CdlList<T> {
+    /// Returns whether any element is equal to `value`.  The list is walked from 
+    /// the head, stopping at the first match.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from([3, 1, 4]);
+    /// assert!(list.contains(&1));
+    /// assert!(!list.contains(&5));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.find_node(|val| val == value).is_some()
+    }
+
+    /// Returns the index, counting from the head, of the first element equal to 
+    /// `value`, or `None` if no element matches.  See [`CdlList::rposition()`] to 
+    /// search from the tail instead.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from("abcabc");
+    /// assert_eq!(list.position_of(&'c'), Some(2));
+    /// assert_eq!(list.position_of(&'z'), None);
+    /// ```
+    pub fn position_of(&self, value: &T) -> Option<usize> {
+        self.find_node(|val| val == value).map(|(index, _)| index)
+    }
+
+    /// Removes and returns the first element equal to `value`, searching from the 
+    /// head, or returns `None` if no element matches.  The matching node is 
+    /// unlinked where it is found, so the cost is just the search, and the other 
+    /// elements keep their order.  The removal is reported to the list's observer 
+    /// like [`CdlList::remove_at()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut recent = CdlList::from(["a.txt", "b.txt", "c.txt"]);
+    /// 
+    /// // move b.txt to the front
+    /// let file = recent.remove_value(&"b.txt").unwrap();
+    /// recent.push_front(file);
+    /// assert_eq!(recent.to_string(), "... <=> \"b.txt\" <=> \"a.txt\" <=> \"c.txt\" <=> ...");
+    /// 
+    /// assert_eq!(recent.remove_value(&"d.txt"), None);
+    /// ```
+    pub fn remove_value(&mut self, value: &T) -> Option<T> {
+        let (index, node) = self.find_node(|val| val == value)?;
+        self.unlink_node(&node);
+
+        let val = Rc::try_unwrap(node).ok().unwrap().into_inner().data;
+        self.notify(ListEvent::RemovedAt { index, value: &val });
+        Some(val)
+    }
+
+    /// Removes and returns the first element equal to `value`, searching from the 
+    /// head, by moving the tail into its place.  Returns `None` if no element 
+    /// matches.
+    /// 
+    /// This doesn't preserve order: after removing the element at index `i`, the 
+    /// old tail is at `i` instead, unless the match was the tail itself.  In 
+    /// exchange, no elements after the match need to shift, so the cost is the 
+    /// search plus a constant amount of relinking.  Nodes are moved rather than 
+    /// their elements, so [`NodeHandle`]s to the old tail stay valid.  The list's 
+    /// observer sees the match removed and the tail removed and then inserted at 
+    /// the match's index.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut pool = CdlList::from("abcde");
+    /// 
+    /// assert_eq!(pool.swap_remove_value(&'b'), Some('b'));
+    /// assert_eq!(pool.to_string_chars(), "aecd");
+    /// 
+    /// assert_eq!(pool.swap_remove_value(&'d'), Some('d'));
+    /// assert_eq!(pool.to_string_chars(), "aec");
+    /// 
+    /// assert_eq!(pool.swap_remove_value(&'z'), None);
+    /// ```
+    pub fn swap_remove_value(&mut self, value: &T) -> Option<T> {
+        let (index, node) = self.find_node(|val| val == value)?;
+        let tail = Rc::clone(self.tail.as_ref().unwrap());
+        let before = if index == 0 { None } else { Some(Node::prev_of(&node)) };
+        self.unlink_node(&node);
+        self.notify_node(&node, |value| ListEvent::RemovedAt { index, value });
+
+        if !Rc::ptr_eq(&node, &tail) {
+            let last = self.size - 1;
+            self.unlink_node(&tail);
+            self.notify_node(&tail, |value| ListEvent::RemovedAt { index: last, value });
+            self.link_node_after(before.as_ref(), Rc::clone(&tail));
+            self.notify_node(&tail, |value| ListEvent::InsertedAt { index, value });
+        }
+
+        drop(tail);
+        Some(Rc::try_unwrap(node).ok().unwrap().into_inner().data)
+    }
+
+    /// Replaces the first run of elements equal to `needle`, searching from the 
+    /// head, with the elements of `replacement`, which may be longer or shorter. 
+    /// Returns whether a match was found; if not, `replacement` isn't used.
+    /// 
+    /// A match has to lie between the head and the tail, so a run that would only 
+    /// appear by wrapping from the tail around to the head isn't found, just as 
+    /// [`CdlList::replace_range()`] never wraps.  An empty `needle` never matches. 
+    /// The list is walked once from the head, comparing each element a bounded 
+    /// number of times, and the match is replaced by relinking nodes, as with 
+    /// `replace_range`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from("one two two");
+    /// 
+    /// assert!(list.replace_sequence(&[' ', 't', 'w', 'o'], " three".chars()));
+    /// assert_eq!(list.to_string_chars(), "one three two");
+    /// 
+    /// assert!(!list.replace_sequence(&['x'], "y".chars()));
+    /// ```
+    pub fn replace_sequence<I>(&mut self, needle: &[T], replacement: I) -> bool
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut replacement = Some(replacement);
+        self.splice_sequences(needle, 1, || replacement.take().into_iter().flatten()) == 1
+    }
+
+    /// Replaces every run of elements equal to `needle` with a copy of 
+    /// `replacement`, returning how many were replaced.  Like 
+    /// [`CdlList::replace_sequence()`], matches don't wrap around from the tail to 
+    /// the head.  They are found from head to tail without overlapping, and only 
+    /// in the original elements, never in the replacements.  Each match is 
+    /// replaced as soon as it's found, so the whole list is walked only once.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from("aaaa-aa");
+    /// 
+    /// assert_eq!(list.replace_all_sequences(&['a', 'a'], &['b']), 3);
+    /// assert_eq!(list.to_string_chars(), "bb-b");
+    /// ```
+    pub fn replace_all_sequences(&mut self, needle: &[T], replacement: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        self.splice_sequences(needle, usize::MAX, || replacement.iter().cloned())
+    }
+
+    /// Returns whether this list holds the same elements as `other` in the same 
+    /// order, or in the reverse order.  Lists of different sizes are never equal. 
+    /// The reversed comparison walks `other` backwards from its tail in step with 
+    /// this list, so nothing is allocated or reversed.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let ring = CdlList::from("abcd");
+    /// assert!(ring.eq_ignoring_direction(&CdlList::from("abcd")));
+    /// assert!(ring.eq_ignoring_direction(&CdlList::from("dcba")));
+    /// 
+    /// // a rotation of the reversal isn't enough
+    /// assert!(!ring.eq_ignoring_direction(&CdlList::from("cbad")));
+    /// ```
+    pub fn eq_ignoring_direction(&self, other: &CdlList<T>) -> bool {
+        self.eq_by(other, |a, b| a == b) || self.eq_reversed(other)
+    }
+
+    /// Returns whether this list and `other` are the same ring, read from any 
+    /// starting element in either direction: that is, whether `other` or its 
+    /// reversal is a rotation of this list.  Lists of different sizes are never 
+    /// equal, and two empty lists are.
+    /// 
+    /// This searches for `other`, and then its reversal, in this list's elements 
+    /// followed by themselves again, so it takes O(n) time and space.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let ring = CdlList::from("abcd");
+    /// assert!(ring.eq_cyclic_undirected(&CdlList::from("cdab")));
+    /// assert!(ring.eq_cyclic_undirected(&CdlList::from("cbad")));
+    /// assert!(!ring.eq_cyclic_undirected(&CdlList::from("acbd")));
+    /// ```
+    pub fn eq_cyclic_undirected(&self, other: &CdlList<T>) -> bool {
+        if self.size != other.size {
+            return false;
+        }
+
+        let (a_nodes, b_nodes) = (self.nodes(), other.nodes());
+        let a_refs : Vec<Ref<'_, T>> = a_nodes.iter().map(|node| Ref::map(node.borrow(), |node| &node.data)).collect();
+        let b_refs : Vec<Ref<'_, T>> = b_nodes.iter().map(|node| Ref::map(node.borrow(), |node| &node.data)).collect();
+        let a : Vec<&T> = a_refs.iter().map(|val| &**val).collect();
+        let mut b : Vec<&T> = b_refs.iter().map(|val| &**val).collect();
+
+        if is_rotation(&a, &b) {
+            return true;
+        }
+        b.reverse();
+        is_rotation(&a, &b)
+    }
+
+    // Compares this list from head to tail against other from tail to head.
+    fn eq_reversed(&self, other: &CdlList<T>) -> bool {
+        if self.size != other.size {
+            return false;
+        }
+        if self.is_empty() {
+            return true;
+        }
+
+        let mut a = Rc::clone(self.head.as_ref().unwrap());
+        let mut b = Rc::clone(other.tail.as_ref().unwrap());
+        for i in 0..self.size {
+            if a.borrow().data != b.borrow().data {
+                return false;
+            }
+            if i + 1 < self.size {
+                a = Node::next_of(&a);
+                b = Node::prev_of(&b);
+            }
+        }
+        true
+    }
+
+    // Walks the list once from the head, finding up to limit non-overlapping 
+    // runs equal to needle with Knuth-Morris-Pratt, and replaces each with the 
+    // elements replacement returns as soon as its last node is reached.  The walk 
+    // carries on from the node after the match, so replacements are never 
+    // searched.  Returns how many runs were replaced.
+    fn splice_sequences<I, F>(&mut self, needle: &[T], limit: usize, mut replacement: F) -> usize
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut() -> I,
+    {
+        if needle.is_empty() || needle.len() > self.size {
+            return 0;
+        }
+
+        let fallback = kmp_fallback(needle);
+        let (mut replaced, mut matched, mut index) = (0, 0, 0);
+        let mut remaining = self.size;
+        let mut node = self.head.clone();
+        while let Some(cur) = node.take() {
+            remaining -= 1;
+            if remaining > 0 {
+                node = Some(Node::next_of(&cur));
+            }
+
+            {
+                let val = &cur.borrow().data;
+                while matched > 0 && *val != needle[matched] {
+                    matched = fallback[matched - 1];
+                }
+                if *val == needle[matched] {
+                    matched += 1;
+                }
+            }
+            index += 1;
+            if matched < needle.len() {
+                continue;
+            }
+
+            // the match ends at cur, so the node before it is needle.len() back
+            let start = index - needle.len();
+            let before = if start == 0 {
+                None
+            } else {
+                let mut before = cur;
+                for _ in 0..needle.len() {
+                    before = Node::prev_of(&before);
+                }
+                Some(before)
+            };
+
+            for _ in 0..needle.len() {
+                let node = match &before {
+                    Some(b) => Node::next_of(b),
+                    None => Rc::clone(self.head.as_ref().unwrap()),
+                };
+                self.unlink_node(&node);
+                self.notify_node(&node, |value| ListEvent::RemovedAt { index: start, value });
+            }
+
+            let mut prev = before;
+            let mut inserted = 0;
+            for val in replacement() {
+                let ref_n = Rc::new(RefCell::new(Node::new(val)));
+                self.link_node_after(prev.as_ref(), Rc::clone(&ref_n));
+                self.notify_node(&ref_n, |value| ListEvent::InsertedAt { index: start + inserted, value });
+                prev = Some(ref_n);
+                inserted += 1;
+            }
+            self.trace_splice(start, needle.len(), inserted);
+
+            index = start + inserted;
+            matched = 0;
+            replaced += 1;
+            if replaced == limit {
+                break;
+            }
+        }
+        replaced
+    }
+}
+
+// Returns the Knuth-Morris-Pratt table for pattern: fallback[i] is the length 
+// of the longest proper prefix of pattern[..=i] that is also a suffix of it.
+fn kmp_fallback<T: PartialEq>(pattern: &[T]) -> Vec<usize> {
+    let mut fallback = vec![0; pattern.len()];
+    let mut len = 0;
+    for i in 1..pattern.len() {
+        while len > 0 && pattern[i] != pattern[len] {
+            len = fallback[len - 1];
+        }
+        if pattern[i] == pattern[len] {
+            len += 1;
+        }
+        fallback[i] = len;
+    }
+    fallback
+}
+
+// Returns whether pattern, which is as long as text, is a rotation of it, by 
+// searching for pattern in text followed by itself with Knuth-Morris-Pratt.
+fn is_rotation<T: PartialEq>(text: &[&T], pattern: &[&T]) -> bool {
+    let n = pattern.len();
+    if n == 0 {
+        return true;
+    }
+
+    let fallback = kmp_fallback(pattern);
+    let mut matched = 0;
+    for i in 0..2 * n - 1 {
+        let val = text[i % n];
+        while matched > 0 && val != pattern[matched] {
+            matched = fallback[matched - 1];
+        }
+        if val == pattern[matched] {
+            matched += 1;
+            if matched == n {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+impl<T: Clone + Add<Output = T>> CdlList<T> {
+    /// Returns a new list of the running totals of this list's elements, so the 
+    /// element at index `i` is the sum of the elements at `0..=i`.  The new list 
+    /// has the same size as this one, and the tail holds the sum of every element.
+    /// 
+    /// Overflow behaves exactly like the element type's `+`: for primitive integers, 
+    /// this panics in debug builds and wraps in release builds.  Use a list of 
+    /// [`std::num::Wrapping`] to always wrap.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut durations : CdlList<u32> = CdlList::new();
+    /// durations.push_back(5);
+    /// durations.push_back(10);
+    /// durations.push_back(15);
+    /// 
+    /// let totals = durations.prefix_sums();
+    /// assert_eq!(totals.to_string(), "... <=> 5 <=> 15 <=> 30 <=> ...");
+    /// ```
+    pub fn prefix_sums(&self) -> CdlList<T> {
+        self.scan(None, |total: &mut Option<T>, val| {
+            let sum = match total.take() {
+                Some(total) => total + val.clone(),
+                None => val.clone(),
+            };
+            total.insert(sum).clone()
+        })
+    }
+}
+
+impl<T: Clone + Sub<Output = T>> CdlList<T> {
+    /// Returns a new list of the differences between each element and the one 
+    /// before it, going around the ring: the element at index `i` is 
+    /// `self[i] - self[i - 1]`, and the head's predecessor is the tail, so the new 
+    /// head is `head - tail`.  The new list has the same size as this one, and a 
+    /// single element is subtracted from itself.
+    /// 
+    /// Underflow behaves exactly like the element type's `-`, so for a ring of 
+    /// unsigned values, the wrap difference will usually underflow.  Use a list of 
+    /// [`std::num::Wrapping`] to always wrap, or 
+    /// [`CdlList::adjacent_differences_linear()`] to leave the wrap difference out.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// // minutes past the hour at which a bus arrives, every hour
+    /// let mut arrivals : CdlList<i32> = CdlList::new();
+    /// arrivals.push_back(5);
+    /// arrivals.push_back(20);
+    /// arrivals.push_back(50);
+    /// 
+    /// let gaps = arrivals.adjacent_differences();
+    /// assert_eq!(gaps.to_string(), "... <=> -45 <=> 15 <=> 30 <=> ...");
+    /// ```
+    pub fn adjacent_differences(&self) -> CdlList<T> {
+        match self.tail.as_ref() {
+            Some(tail) => {
+                let tail_val = tail.borrow().data.clone();
+                self.differences(Some(tail_val))
+            },
+            None => CdlList::new(),
+        }
+    }
+
+    /// Like [`CdlList::adjacent_differences()`], but without the difference 
+    /// between the head and the tail, like adjacent differences over a slice.  The 
+    /// new list has one element fewer than this one, or none if this one is empty, 
+    /// and the element at index `i` is `self[i + 1] - self[i]`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut timestamps : CdlList<u64> = CdlList::new();
+    /// timestamps.push_back(100);
+    /// timestamps.push_back(130);
+    /// timestamps.push_back(200);
+    /// 
+    /// let gaps = timestamps.adjacent_differences_linear();
+    /// assert_eq!(gaps.to_string(), "... <=> 30 <=> 70 <=> ...");
+    /// ```
+    pub fn adjacent_differences_linear(&self) -> CdlList<T> {
+        self.differences(None)
+    }
+
+    // Subtracts from each element the one before it, using prev as the head's 
+    // predecessor, or leaving the head out if prev is None.
+    fn differences(&self, mut prev: Option<T>) -> CdlList<T> {
+        let mut out = CdlList::new();
+        let mut chain = None;
+        let mut len = 0;
+        self.walk(|val| {
+            if let Some(prev) = prev.replace(val.clone()) {
+                Node::push_to_chain(&mut chain, val.clone() - prev, &out.owner);
+                len += 1;
+            }
+        });
+
+        if let Some((head, tail)) = chain {
+            out.link_chain_back(head, tail, len);
+        }
+        out
+    }
+}
+
+impl<T: Ord> CdlList<T> {
+    /// Returns whether the list is sorted in ascending order from head to tail.  An 
+    /// empty list is sorted.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert!(list.is_sorted());
+    /// 
+    /// list.push_back(0);
+    /// assert!(!list.is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+
+        let mut node = Rc::clone(self.head.as_ref().unwrap());
+        for _ in 1..self.size {
+            let next = Node::next_of(&node);
+            if node.borrow().data > next.borrow().data {
+                return false;
+            }
+            node = next;
+        }
+        true
+    }
+
+    /// Sorts the list in ascending order, like [`slice::sort()`].  The sort is 
+    /// stable.  See [`CdlList::sort_by()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([5, 1, 4, 2, 3]);
+    /// list.sort();
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 2 <=> 3 <=> 4 <=> 5 <=> ...");
+    /// ```
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+
+    /// Merges `other` into this list, both sorted in ascending order, so that the 
+    /// result is sorted too.  The merge is stable, and takes O(n + m) time without 
+    /// reallocating any nodes.  See [`CdlList::merge_by()`], and 
+    /// [`CdlList::merge_all()`] for merging more than two lists.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut a = CdlList::from([1, 4, 9]);
+    /// a.merge(CdlList::from([2, 4, 10]));
+    /// assert_eq!(a.to_string(), "... <=> 1 <=> 2 <=> 4 <=> 4 <=> 9 <=> 10 <=> ...");
+    /// ```
+    pub fn merge(&mut self, other: CdlList<T>) {
+        self.merge_by(other, T::cmp);
+    }
+
+    /// Inserts `value` at its place in a list that is sorted in ascending order, 
+    /// after any elements equal to it, and returns its index.  See 
+    /// [`CdlList::insert_sorted_by()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([10, 20, 30, 40]);
+    /// 
+    /// assert_eq!(list.insert_sorted(35), 3);
+    /// assert_eq!(list.insert_sorted(5), 0);
+    /// assert_eq!(list.to_string(), "... <=> 5 <=> 10 <=> 20 <=> 30 <=> 35 <=> 40 <=> ...");
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> usize {
+        self.insert_sorted_by(value, T::cmp)
+    }
+
+    /// Moves the element at `index` to its place in a list that is otherwise sorted, 
+    /// and returns the element's new index.  See [`CdlList::resort_by_key()`].
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index >= size()`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(3);
+    /// list.push_back(5);
+    /// list.push_back(7);
+    /// 
+    /// // bump the 3 up to a 6
+    /// list.remove_at(1);
+    /// list.insert_at(1, 6).unwrap();
+    /// 
+    /// assert_eq!(list.resort_at(1), 2);
+    /// assert!(list.is_sorted());
+    /// ```
+    pub fn resort_at(&mut self, index: usize) -> usize {
+        self.resort_by(index, |a, b| a.cmp(b))
+    }
+
+    /// Merges lists that are each sorted in ascending order into one sorted list. 
+    /// The merge is stable: equal elements keep their order within each list, and 
+    /// elements from earlier lists come before equal elements from later ones.
+    /// 
+    /// This is a k-way merge, which keeps the head of each list in a binary heap 
+    /// and repeatedly moves the smallest one to the output.  Nodes are moved rather 
+    /// than reallocated and no elements are cloned, so merging lists with n 
+    /// elements in total takes O(n log k) time.  If the lists aren't sorted, the 
+    /// result holds every element but in an unspecified order.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let shards = vec![
+    ///     CdlList::from([1, 4, 7]),
+    ///     CdlList::from([2, 5]),
+    ///     CdlList::from([0, 3, 6, 8]),
+    /// ];
+    /// 
+    /// let merged = CdlList::merge_all(shards);
+    /// assert_eq!(merged.to_string(), "... <=> 0 <=> 1 <=> 2 <=> 3 <=> 4 <=> 5 <=> 6 <=> 7 <=> 8 <=> ...");
+    /// ```
+    pub fn merge_all(mut lists: Vec<CdlList<T>>) -> CdlList<T> {
+        if lists.len() < 2 {
+            return lists.pop().unwrap_or_default();
+        }
+
+        let mut heap : BinaryHeap<MergeHead<T>> = lists.iter().enumerate()
+            .filter_map(|(source, list)| {
+                list.head.clone().map(|node| MergeHead { node, source })
+            })
+            .collect();
+
+        let mut out = CdlList::new();
+        while let Some(MergeHead { node, source }) = heap.pop() {
+            drop(node);
+            let list = &mut lists[source];
+            out.push_node(list.pop_node(true).unwrap(), false);
+            if let Some(node) = list.head.clone() {
+                heap.push(MergeHead { node, source });
+            }
+        }
+        out
+    }
+}
+
+// The head of one of the lists passed to merge_all, and which list it came 
+// from.  BinaryHeap is a max-heap, so these are ordered in reverse to pop the 
+// smallest element first, and the earliest list among equal elements.
+struct MergeHead<T> {
+    node: Rc<RefCell<Node<T>>>,
+    source: usize
+}
+
+impl<T: Ord> Ord for MergeHead<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.node.borrow().data.cmp(&self.node.borrow().data)
+            .then(other.source.cmp(&self.source))
+    }
+}
+
+impl<T: Ord> PartialOrd for MergeHead<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> PartialEq for MergeHead<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for MergeHead<T> {}
+
+impl<T: Ord + Clone> CdlList<T> {
+    /// Returns a copy of the element that would be at index `k` if the list were 
+    /// sorted in ascending order, or `None` if `k` is out of range.  The list itself 
+    /// is left untouched.
+    /// 
+    /// Rather than sorting, this clones each element once into a scratch list and 
+    /// runs a quickselect over it, moving whole nodes between partitions instead of 
+    /// copying values again.  This takes expected O(n) time.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(7);
+    /// list.push_back(2);
+    /// list.push_back(9);
+    /// list.push_back(4); // list = ╔══> 7 <══> 2 <══> 9 <══> 4 <══╗
+    ///                    //        ╚══════════════════════════════╝
+    /// 
+    /// assert_eq!(list.select_nth(0), Some(2));
+    /// assert_eq!(list.select_nth(2), Some(7));
+    /// assert_eq!(list.select_nth(4), None);
+    /// 
+    /// // the list is unchanged
+    /// assert_eq!(*list.peek_front().unwrap(), 7);
+    /// ```
+    pub fn select_nth(&self, k: usize) -> Option<T> {
+        if k >= self.size() {
+            return None;
+        }
+
+        Some(self.clone().quickselect(k))
+    }
+
+    /// Returns a copy of the median element of the list, or `None` if the list is 
+    /// empty.  For lists with an even number of elements, the lower of the two 
+    /// middle elements is returned.  See [`CdlList::select_nth()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// assert_eq!(list.median(), None);
+    /// 
+    /// list.push_back(5);
+    /// list.push_back(1);
+    /// list.push_back(3);
+    /// assert_eq!(list.median(), Some(3));
+    /// 
+    /// list.push_back(8);
+    /// assert_eq!(list.median(), Some(3));
+    /// ```
+    pub fn median(&self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.select_nth((self.size() - 1) / 2)
+    }
+
+    // Consumes the list, returning the k-th smallest element.  Each round 
+    // partitions the remaining nodes around a pivot into three lists and 
+    // keeps only the one containing k, so no values are copied or reallocated.
+    fn quickselect(mut self, mut k: usize) -> T {
+        // cheap xorshift for pivot positions; deterministic but not 
+        // tied to the order of the input
+        let mut seed = (self.size() as u64) ^ 0x9E37_79B9_7F4A_7C15;
+
+        loop {
+            if self.size() == 1 {
+                return self.pop_front().unwrap();
+            }
+
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+
+            // the pivot compares equal to itself, so it lands in equal
+            let pivot = self.node_at((seed % self.size() as u64) as usize).unwrap();
+
+            let mut less : CdlList<T> = CdlList::new();
+            let mut equal : CdlList<T> = CdlList::new();
+            let mut greater : CdlList<T> = CdlList::new();
+
+            while let Some(node) = self.pop_node(true) {
+                let ordering = node.borrow().data.cmp(&pivot.borrow().data);
+                match ordering {
+                    std::cmp::Ordering::Less => less.push_node(node, false),
+                    std::cmp::Ordering::Equal => equal.push_node(node, false),
+                    std::cmp::Ordering::Greater => greater.push_node(node, false),
+                }
+            }
+
+            // release our extra strong reference so the pivot can be popped
+            drop(pivot);
+
+            if k < less.size() {
+                self = less;
+            } else if k < less.size() + equal.size() {
+                return equal.pop_front().unwrap();
+            } else {
+                k -= less.size() + equal.size();
+                self = greater;
+            }
+        }
+    }
+}
+
+impl<T, E> CdlList<Result<T, E>> {
+    /// Turns a list of results into a list of the `Ok` values, in the same order, 
+    /// or returns the first `Err` from the head if there is one, like collecting 
+    /// an iterator of `Result`s.  Once an `Err` is found, it is returned and every 
+    /// element after it is dropped without being looked at.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut parsed : CdlList<Result<u32, String>> = CdlList::new();
+    /// parsed.push_back(Ok(1));
+    /// parsed.push_back(Ok(2));
+    /// assert_eq!(parsed.collect_results().unwrap().to_string(), "... <=> 1 <=> 2 <=> ...");
+    /// 
+    /// let mut parsed : CdlList<Result<u32, String>> = CdlList::new();
+    /// parsed.push_back(Ok(1));
+    /// parsed.push_back(Err(String::from("bad input")));
+    /// parsed.push_back(Err(String::from("worse input")));
+    /// assert_eq!(parsed.collect_results().unwrap_err(), "bad input");
+    /// ```
+    pub fn collect_results(mut self) -> Result<CdlList<T>, E> {
+        let mut out = CdlList::new();
+        while let Some(result) = self.pop(true) {
+            out.push_back(result?);
+        }
+        Ok(out)
+    }
+}
+
+impl<T> CdlList<Option<T>> {
+    /// Turns a list of options into a list of the `Some` values, in the same 
+    /// order, or returns `None` if any element is `None`, like collecting an 
+    /// iterator of `Option`s.  Like [`CdlList::collect_results()`], the elements 
+    /// after the first `None` are dropped without being looked at.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<Option<char>> = CdlList::new();
+    /// list.push_back(Some('a'));
+    /// list.push_back(Some('b'));
+    /// assert_eq!(list.collect_options().unwrap().to_string_chars(), "ab");
+    /// 
+    /// let mut list : CdlList<Option<char>> = CdlList::new();
+    /// list.push_back(Some('a'));
+    /// list.push_back(None);
+    /// assert!(list.collect_options().is_none());
+    /// ```
+    pub fn collect_options(mut self) -> Option<CdlList<T>> {
+        let mut out = CdlList::new();
+        while let Some(option) = self.pop(true) {
+            out.push_back(option?);
+        }
+        Some(out)
+    }
+}
+
+impl<T> FromIterator<T> for CdlList<T> {
+    /// Builds a list of the iterator's items, in order, so the first item becomes 
+    /// the head.  The items are linked into a chain as they arrive and the chain 
+    /// becomes the list in one step.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list : CdlList<u32> = (1..=5).collect();
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 2 <=> 3 <=> 4 <=> 5 <=> ...");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = CdlList::new();
+        list.push_back_all(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for CdlList<T> {
+    /// Appends the iterator's items to the back of the list, in order.  The items 
+    /// are linked into a chain first and spliced on after the tail in one step, 
+    /// then each is reported to the list's observer as pushed to the back.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = (1..=3).collect();
+    /// list.extend(vec![4, 5, 6]);
+    /// assert_eq!(list.size(), 6);
+    /// assert_eq!(*list.peek_back().unwrap(), 6);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.push_back_all(iter);
+    }
+}
+
+impl<T> From<Vec<T>> for CdlList<T> {
+    /// Builds a list of the vector's elements, in order, moving each one into the 
+    /// list.
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for CdlList<T> {
+    /// Builds a list of the array's elements, in order, moving each one into the 
+    /// list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from([3, 1, 4]);
+    /// assert_eq!(*list.peek_front().unwrap(), 3);
+    /// ```
+    fn from(arr: [T; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T> From<LinkedList<T>> for CdlList<T> {
+    /// Builds a list of the linked list's elements, in order, moving each one into 
+    /// the new list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::collections::LinkedList;
+    /// 
+    /// let list = CdlList::from(LinkedList::from([1, 2, 3]));
+    /// assert_eq!(list, CdlList::from([1, 2, 3]));
+    /// ```
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for CdlList<T> {
+    /// Builds a list of the deque's elements, from front to back, moving each one 
+    /// into the new list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::collections::VecDeque;
+    /// 
+    /// let mut deque = VecDeque::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(CdlList::from(deque), CdlList::from([1, 2, 3]));
+    /// ```
+    fn from(deque: VecDeque<T>) -> Self {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T> From<CdlList<T>> for LinkedList<T> {
+    /// Moves the list's elements, from head to tail, into a new [`LinkedList`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::collections::LinkedList;
+    /// 
+    /// let list : LinkedList<u32> = CdlList::from([1, 2, 3]).into();
+    /// assert_eq!(list, LinkedList::from([1, 2, 3]));
+    /// ```
+    fn from(list: CdlList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<CdlList<T>> for VecDeque<T> {
+    /// Moves the list's elements, from head to tail, into a new [`VecDeque`] 
+    /// with the head at the front.  The deque is allocated once, with room for 
+    /// exactly `size()` elements.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::collections::VecDeque;
+    /// 
+    /// let mut list = CdlList::from([1, 2, 3]);
+    /// list.rotate_left(1);
+    /// let deque : VecDeque<u32> = list.into();
+    /// assert_eq!(deque, [2, 3, 1]);
+    /// ```
+    fn from(list: CdlList<T>) -> Self {
+        let mut deque = VecDeque::with_capacity(list.size());
+        deque.extend(list);
+        deque
+    }
+}
+
+impl From<&str> for CdlList<char> {
+    /// Builds a list of the string's characters (Unicode scalar values, not bytes), 
+    /// in order.  The empty string gives an empty list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from("héllo");
+    /// assert_eq!(list.size(), 5);
+    /// assert_eq!(list.pop_front(), Some('h'));
+    /// assert_eq!(list.pop_front(), Some('é'));
+    /// ```
+    fn from(s: &str) -> Self {
+        s.chars().collect()
+    }
+}
+
+impl CdlList<char> {
+    /// Collects the list's characters, from head to tail, into a `String`.  This is 
+    /// the inverse of `CdlList::from(&str)`.
+    /// 
+    /// Since the list is circular, rotating it and reading it back is a simple way 
+    /// to check whether one string is a rotation of another:
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// fn is_rotation(s1: &str, s2: &str) -> bool {
+    ///     let mut list = CdlList::from(s1);
+    ///     if list.size() != s2.chars().count() {
+    ///         return false;
+    ///     }
+    /// 
+    ///     for _ in 0..list.size().max(1) {
+    ///         if list.to_string_chars() == s2 {
+    ///             return true;
+    ///         }
+    ///         let c = list.pop_front().unwrap();
+    ///         list.push_back(c);
+    ///     }
+    ///     false
+    /// }
+    /// 
+    /// assert!(is_rotation("waterbottle", "erbottlewat"));
+    /// assert!(!is_rotation("waterbottle", "erbottlewta"));
+    /// assert!(is_rotation("", ""));
+    /// ```
+    pub fn to_string_chars(&self) -> String {
+        let mut s = String::new();
+        self.walk(|c| s.push(*c));
+        s
+    }
+}
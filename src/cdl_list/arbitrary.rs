@@ -0,0 +1,29 @@
+//! Support for generating lists with the [`arbitrary`] crate, enabled with the 
+//! `arbitrary` feature.  This is mainly useful for structure-aware fuzzing with 
+//! tools like `cargo-fuzz`.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::CdlList;
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for CdlList<T> {
+    /// Draws a length bounded by the remaining input, then that many elements, 
+    /// which are linked into the list in one step.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<T>()?;
+        (0..len).map(|_| T::arbitrary(u)).collect()
+    }
+
+    /// Uses up all remaining input, drawing elements until it runs out and 
+    /// linking them into the list in one step.
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    /// A list may be empty, and has no upper bound on its size, just like the 
+    /// standard library's collections.
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
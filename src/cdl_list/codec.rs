@@ -0,0 +1,190 @@
+//! A compact binary format for a [`CdlList`], for sending lists over a socket or 
+//! storing them without pulling in `serde`.  A list is written as its size, as a 
+//! little-endian `u64`, followed by each element from head to tail in the format 
+//! of its [`Encode`] implementation.
+//! 
+//! Elements are encoded straight into the writer as the list is walked, so no 
+//! intermediate buffer is needed however long the list is.  For the types 
+//! implemented here, the bytes are the same as `bincode`'s default layout, so 
+//! with the `serde` feature a list written by one can be read by the other.
+
+use std::{io::{self, Read, Write}, ops::ControlFlow};
+
+use super::CdlList;
+
+/// A type that can be written in the binary format used by 
+/// [`CdlList::write_to()`].
+/// 
+/// Integers are written as little-endian bytes of their own width, with `usize` 
+/// and `isize` widened to 64 bits.  Strings and byte vectors are written as their 
+/// length, as a little-endian `u64`, followed by their bytes.
+pub trait Encode {
+    /// Writes `self` to `w`.
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()>;
+}
+
+/// A type that can be read from the binary format used by 
+/// [`CdlList::read_from()`], as written by its [`Encode`] implementation.
+pub trait Decode: Sized {
+    /// Reads a value from `r`, failing if `r` ends before the value is complete 
+    /// or the bytes don't form a valid value.
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self>;
+}
+
+macro_rules! impl_codec_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl Encode for $int {
+                fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+                    w.write_all(&self.to_le_bytes())
+                }
+            }
+
+            impl Decode for $int {
+                fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+                    let mut bytes = [0; std::mem::size_of::<$int>()];
+                    r.read_exact(&mut bytes)?;
+                    Ok(<$int>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_codec_for_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Encode for usize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u64).encode(w)
+    }
+}
+
+impl Decode for usize {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        usize::try_from(u64::decode(r)?).map_err(|_| invalid_data("usize out of range"))
+    }
+}
+
+impl Encode for isize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as i64).encode(w)
+    }
+}
+
+impl Decode for isize {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        isize::try_from(i64::decode(r)?).map_err(|_| invalid_data("isize out of range"))
+    }
+}
+
+impl Encode for Vec<u8> {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (self.len() as u64).encode(w)?;
+        w.write_all(self)
+    }
+}
+
+impl Decode for Vec<u8> {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let len = u64::decode(r)?;
+
+        // the length comes from the input, so read what is actually there 
+        // rather than allocating it all up front
+        let mut bytes = Vec::new();
+        r.take(len).read_to_end(&mut bytes)?;
+        if (bytes.len() as u64) < len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(bytes)
+    }
+}
+
+impl Encode for String {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (self.len() as u64).encode(w)?;
+        w.write_all(self.as_bytes())
+    }
+}
+
+impl Decode for String {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        String::from_utf8(Vec::decode(r)?).map_err(|_| invalid_data("string is not valid UTF-8"))
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl<T: Encode> CdlList<T> {
+    /// Writes the list's size followed by each of its elements, from head to tail, 
+    /// to `w`.  See the [`Encode`] trait for how elements are written.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the first error from `w` or from encoding an element.  Anything 
+    /// written before the error is left in `w`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u16> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(0x0302);
+    /// 
+    /// let mut bytes = Vec::new();
+    /// list.write_to(&mut bytes).unwrap();
+    /// assert_eq!(bytes, [2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 3]);
+    /// ```
+    pub fn write_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (self.size as u64).encode(w)?;
+
+        let failed = self.traverse(|val| match val.encode(w) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(err) => ControlFlow::Break(err),
+        });
+
+        match failed {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Decode> CdlList<T> {
+    /// Reads a list written by [`CdlList::write_to()`] from `r`.  Exactly the bytes 
+    /// making up the list are consumed, so more data can follow it in `r`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error of kind [`io::ErrorKind::UnexpectedEof`] if `r` ends 
+    /// before the whole list has been read, or the error from `r` or from decoding 
+    /// an element if one occurs.  No partially read list is ever returned.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::io::{Cursor, ErrorKind};
+    /// 
+    /// let mut list : CdlList<String> = CdlList::new();
+    /// list.push_back(String::from("ring"));
+    /// list.push_back(String::from("buffer"));
+    /// 
+    /// let mut bytes = Vec::new();
+    /// list.write_to(&mut bytes).unwrap();
+    /// 
+    /// let copy : CdlList<String> = CdlList::read_from(&mut Cursor::new(&bytes)).unwrap();
+    /// assert_eq!(copy.to_string(), list.to_string());
+    /// 
+    /// bytes.pop();
+    /// let err = CdlList::<String>::read_from(&mut Cursor::new(&bytes)).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    /// ```
+    pub fn read_from<R: Read>(r: &mut R) -> io::Result<CdlList<T>> {
+        let size = u64::decode(r)?;
+
+        let mut list = CdlList::new();
+        for _ in 0..size {
+            list.push_back(T::decode(r)?);
+        }
+        Ok(list)
+    }
+}
@@ -0,0 +1,443 @@
+//! Cursors over a [`CdlList`].  A cursor remembers a position in the list, so 
+//! neighbouring elements can be visited or changed without walking from the head 
+//! each time.  Since the list is circular, moving past the tail wraps around to 
+//! the head and vice versa; the only position without an element is the one a 
+//! cursor has over an empty list.
+
+use std::{cell::{Ref, RefCell, RefMut}, fmt::Debug, rc::Rc};
+
+use super::{CdlList, ListEvent, Node};
+
+/// A read-only cursor into a [`CdlList`], created by [`CdlList::cursor_front()`] or 
+/// [`CdlList::cursor_back()`].
+/// 
+/// A cursor only shares the list, so any number of cursors can walk the same list 
+/// independently.  Rather than holding a borrow of its current element, a cursor 
+/// keeps the element's node alive and borrows it only for as long as the `Ref` 
+/// returned by [`Cursor::current()`] lives.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list : CdlList<u32> = CdlList::new();
+/// list.push_back(1);
+/// list.push_back(2);
+/// list.push_back(3);
+/// 
+/// let mut slow = list.cursor_front();
+/// let mut fast = list.cursor_front();
+/// slow.move_next();
+/// fast.move_next();
+/// fast.move_next();
+/// 
+/// assert_eq!(*slow.current().unwrap(), 2);
+/// assert_eq!(*fast.current().unwrap(), 3);
+/// ```
+/// 
+/// The list stays borrowed until the cursor is dropped, even if it isn't used 
+/// again, since it holds on to its current element:
+/// 
+/// ```compile_fail
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list = CdlList::from([1, 2, 3]);
+/// 
+/// let cursor = list.cursor_back();
+/// assert_eq!(cursor.index(), Some(2));
+/// list.pop_back();
+/// ```
+pub struct Cursor<'a, T> {
+    list: &'a CdlList<T>,
+    current: Option<Rc<RefCell<Node<T>>>>,
+    index: usize
+}
+
+impl<T> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        Cursor { list: self.list, current: self.current.clone(), index: self.index }
+    }
+}
+
+impl<T> Debug for Cursor<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Cursor").field(&self.index()).finish()
+    }
+}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for Cursor<'_, T> {
+    fn drop(&mut self) {}
+}
+
+/// A cursor that can modify the [`CdlList`] it points into, created by 
+/// [`CdlList::cursor_front_mut()`] or [`CdlList::cursor_back_mut()`].  The cursor 
+/// mutably borrows the list, so the list can only be changed through the cursor 
+/// until it is dropped.
+/// 
+/// ```compile_fail
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list = CdlList::from([1, 2, 3]);
+/// 
+/// let mut cursor = list.cursor_front_mut();
+/// cursor.move_next();
+/// assert_eq!(list.size(), 3);
+/// ```
+pub struct CursorMut<'a, T> {
+    list: &'a mut CdlList<T>,
+    current: Option<Rc<RefCell<Node<T>>>>,
+    index: usize
+}
+
+impl<T> Debug for CursorMut<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorMut").field(&self.index()).finish()
+    }
+}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for CursorMut<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<T> CdlList<T> {
+    /// Returns a read-only cursor pointing at the head of the list.  If the list is 
+    /// empty, the cursor has no current element.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor { list: self, current: self.head.clone(), index: 0 }
+    }
+
+    /// Returns a read-only cursor pointing at the tail of the list.  If the list is 
+    /// empty, the cursor has no current element.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor { list: self, current: self.tail.clone(), index: self.size.saturating_sub(1) }
+    }
+
+    /// Returns a cursor pointing at the head of the list, which can move around 
+    /// the list and modify it.  If the list is empty, the cursor has no current 
+    /// element.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// 
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(*cursor.current().unwrap(), 1);
+    /// 
+    /// cursor.move_next();
+    /// *cursor.current().unwrap() *= 10;
+    /// drop(cursor);
+    /// 
+    /// assert_eq!(*list.peek_back().unwrap(), 20);
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.head.clone();
+        CursorMut { list: self, current, index: 0 }
+    }
+
+    /// Returns a cursor pointing at the tail of the list, which can move around 
+    /// the list and modify it.  If the list is empty, the cursor has no current 
+    /// element.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// 
+    /// let mut cursor = list.cursor_back_mut();
+    /// assert_eq!(cursor.index(), Some(1));
+    /// 
+    /// // the head comes right after the tail
+    /// cursor.move_next();
+    /// assert_eq!(*cursor.current().unwrap(), 1);
+    /// ```
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.tail.clone();
+        let index = self.size.saturating_sub(1);
+        CursorMut { list: self, current, index }
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns the index of the cursor's current element, counting from the head, 
+    /// or `None` if the list is empty.
+    pub fn index(&self) -> Option<usize> {
+        self.current.as_ref().map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element, wrapping around from the tail to the 
+    /// head.  Does nothing if the list is empty.
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current.take() {
+            self.current = Some(Node::next_of(&node));
+            self.index = (self.index + 1) % self.list.size;
+        }
+    }
+
+    /// Moves the cursor to the previous element, wrapping around from the head to 
+    /// the tail.  Does nothing if the list is empty.
+    pub fn move_prev(&mut self) {
+        if let Some(node) = self.current.take() {
+            self.current = Some(Node::prev_of(&node));
+            self.index = (self.index + self.list.size - 1) % self.list.size;
+        }
+    }
+
+    /// Returns a shared borrow of the element under the cursor, or `None` if the 
+    /// list is empty.  Borrows from different cursors may be held at the same 
+    /// time, even over the same element.
+    pub fn current(&self) -> Option<Ref<'_, T>> {
+        self.current.as_ref()
+            .map(|node| Ref::map(node.borrow(), |node| &node.data))
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns the index of the cursor's current element, counting from the head, 
+    /// or `None` if the list is empty.
+    pub fn index(&self) -> Option<usize> {
+        self.current.as_ref().map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element, wrapping around from the tail to the 
+    /// head.  Does nothing if the list is empty.
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current.take() {
+            self.current = Some(Node::next_of(&node));
+            self.index = (self.index + 1) % self.list.size;
+        }
+    }
+
+    /// Moves the cursor to the previous element, wrapping around from the head to 
+    /// the tail.  Does nothing if the list is empty.
+    pub fn move_prev(&mut self) {
+        if let Some(node) = self.current.take() {
+            self.current = Some(Node::prev_of(&node));
+            self.index = (self.index + self.list.size - 1) % self.list.size;
+        }
+    }
+
+    /// Returns a mutable borrow of the element under the cursor, or `None` if the 
+    /// list is empty.  The borrow must be dropped before the cursor is used again.
+    pub fn current(&mut self) -> Option<RefMut<'_, T>> {
+        self.current.as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.data))
+    }
+
+    /// Replaces the element under the cursor with `val`, returning the old 
+    /// element, or `None` if the list is empty (in which case `val` is dropped). 
+    /// The cursor stays where it is and the list's links are untouched.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<String> = CdlList::new();
+    /// list.push_back(String::from("a"));
+    /// list.push_back(String::from("b"));
+    /// 
+    /// let mut cursor = list.cursor_back_mut();
+    /// assert_eq!(cursor.replace_current(String::from("c")), Some(String::from("b")));
+    /// assert_eq!(cursor.index(), Some(1));
+    /// drop(cursor);
+    /// 
+    /// assert_eq!(list.to_string(), "... <=> \"a\" <=> \"c\" <=> ...");
+    /// ```
+    pub fn replace_current(&mut self, val: T) -> Option<T> {
+        self.current().map(|mut cur| std::mem::replace(&mut *cur, val))
+    }
+
+    /// Inserts `val` just after the element under the cursor, without walking the 
+    /// list.  The cursor stays on its current element.  Inserting after the tail 
+    /// makes `val` the new tail, and inserting into an empty list pushes `val` and 
+    /// moves the cursor onto it.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 3]);
+    /// 
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_after(2);
+    /// assert_eq!(*cursor.current().unwrap(), 1);
+    /// 
+    /// cursor.move_prev();
+    /// cursor.insert_after(4);
+    /// drop(cursor);
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 2 <=> 3 <=> 4 <=> ...");
+    /// ```
+    pub fn insert_after(&mut self, val: T) {
+        let ref_n = Rc::new(RefCell::new(Node::new(val)));
+        let index = match &self.current {
+            Some(cur) => {
+                self.list.link_node_after(Some(cur), Rc::clone(&ref_n));
+                self.index + 1
+            },
+            None => {
+                self.list.link_node_after(None, Rc::clone(&ref_n));
+                self.current = Some(Rc::clone(&ref_n));
+                self.index = 0;
+                0
+            }
+        };
+        self.list.notify_node(&ref_n, |value| ListEvent::InsertedAt { index, value });
+    }
+
+    /// Inserts `val` just before the element under the cursor, without walking 
+    /// the list.  The cursor stays on its current element, whose index goes up by 
+    /// one.  Inserting before the head makes `val` the new head, and inserting into 
+    /// an empty list pushes `val` and moves the cursor onto it.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([2, 3]);
+    /// 
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_before(1);
+    /// assert_eq!(cursor.index(), Some(1));
+    /// drop(cursor);
+    /// 
+    /// assert_eq!(*list.peek_front().unwrap(), 1);
+    /// ```
+    pub fn insert_before(&mut self, val: T) {
+        let ref_n = Rc::new(RefCell::new(Node::new(val)));
+        let index = match &self.current {
+            Some(cur) => {
+                let prev = if self.index == 0 { None } else { Some(Node::prev_of(cur)) };
+                self.list.link_node_after(prev.as_ref(), Rc::clone(&ref_n));
+                self.index += 1;
+                self.index - 1
+            },
+            None => {
+                self.list.link_node_after(None, Rc::clone(&ref_n));
+                self.current = Some(Rc::clone(&ref_n));
+                self.index = 0;
+                0
+            }
+        };
+        self.list.notify_node(&ref_n, |value| ListEvent::InsertedAt { index, value });
+    }
+
+    /// Removes the element under the cursor and returns it, or returns `None` if 
+    /// the list is empty.  The cursor moves on to the next element, wrapping 
+    /// around to the head if the tail was removed.  Removing the last element 
+    /// leaves the cursor with no current element, as if it had been created over an 
+    /// empty list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 2, 3]);
+    /// 
+    /// let mut cursor = list.cursor_back_mut();
+    /// assert_eq!(cursor.remove_current(), Some(3));
+    /// assert_eq!(*cursor.current().unwrap(), 1);
+    /// 
+    /// assert_eq!(cursor.remove_current(), Some(1));
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// assert_eq!(cursor.remove_current(), None);
+    /// assert_eq!(cursor.index(), None);
+    /// drop(cursor);
+    /// 
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        let index = self.index;
+        if self.list.size > 1 {
+            self.current = Some(Node::next_of(&node));
+        }
+
+        self.list.unlink_node(&node);
+        if self.index == self.list.size {
+            self.index = 0;
+        }
+
+        let val = Rc::try_unwrap(node).ok().unwrap().into_inner().data;
+        self.list.notify(ListEvent::RemovedAt { index, value: &val });
+        Some(val)
+    }
+
+    /// Detaches every element before the one under the cursor and returns them as 
+    /// a new list, in order.  The current element becomes the head, so the cursor 
+    /// stays on it at index 0.  Nothing is detached if the cursor is on the head 
+    /// or the list is empty.
+    /// 
+    /// Unlike the cursors of `std`'s `LinkedList`, there is no "ghost" position 
+    /// between the tail and the head, so the current element always stays in this 
+    /// list.  The detached elements are walked once to hand them over to the new 
+    /// list, and this list's observer is told about each as a removal from index 0.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 2, 3, 4]);
+    /// 
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// cursor.move_next();
+    /// let front = cursor.split_before();
+    /// assert_eq!(cursor.index(), Some(0));
+    /// drop(cursor);
+    /// 
+    /// assert_eq!(front.to_string(), "... <=> 1 <=> 2 <=> ...");
+    /// assert_eq!(list.to_string(), "... <=> 3 <=> 4 <=> ...");
+    /// ```
+    pub fn split_before(&mut self) -> CdlList<T> {
+        match &self.current {
+            Some(cur) => {
+                let front = self.list.split_before_node(Rc::clone(cur));
+                self.index = 0;
+                front
+            },
+            None => CdlList::new(),
+        }
+    }
+
+    /// Detaches every element after the one under the cursor and returns them as a 
+    /// new list, in order.  The current element becomes the tail, and the cursor's 
+    /// index doesn't change.  Nothing is detached if the cursor is on the tail or 
+    /// the list is empty.
+    /// 
+    /// The detached elements are walked once to hand them over to the new list, 
+    /// and this list's observer is told about each as a removal.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 2, 3, 4]);
+    /// 
+    /// let mut cursor = list.cursor_front_mut();
+    /// let back = cursor.split_after();
+    /// assert_eq!(cursor.index(), Some(0));
+    /// drop(cursor);
+    /// 
+    /// assert_eq!(back.to_string(), "... <=> 2 <=> 3 <=> 4 <=> ...");
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> ...");
+    /// ```
+    pub fn split_after(&mut self) -> CdlList<T> {
+        match &self.current {
+            Some(cur) if self.index + 1 < self.list.size => {
+                self.list.split_off_node(Node::next_of(cur))
+            },
+            _ => CdlList::new(),
+        }
+    }
+}
+
+impl<'a, T: Default> CursorMut<'a, T> {
+    /// Takes the element under the cursor, leaving `T::default()` in its place, or 
+    /// returns `None` if the list is empty.  Like 
+    /// [`CursorMut::replace_current()`], this doesn't move the cursor or change the 
+    /// list's links.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<Vec<u32>> = CdlList::new();
+    /// list.push_back(vec![1, 2]);
+    /// 
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.take_current(), Some(vec![1, 2]));
+    /// assert_eq!(cursor.take_current(), Some(vec![]));
+    /// drop(cursor);
+    /// 
+    /// assert_eq!(list.size(), 1);
+    /// ```
+    pub fn take_current(&mut self) -> Option<T> {
+        self.current().map(|mut cur| std::mem::take(&mut *cur))
+    }
+}
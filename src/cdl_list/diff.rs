@@ -0,0 +1,277 @@
+//! Longest common subsequences of two [`CdlList`]s, and the edit scripts built 
+//! on them for sending only the changes to a list to another copy of it. 
+//! [`CdlList::diff()`] computes a script from a longest common subsequence of the 
+//! two lists, and [`CdlList::apply()`] carries it out.
+
+use std::{cell::{Ref, RefCell}, fmt::Debug, iter, rc::Rc};
+
+use super::{CdlList, Node};
+
+/// A single change to a list, as produced by [`CdlList::diff()`].  Indices refer 
+/// to the list as it is when the edit is applied, after all earlier edits in the 
+/// same script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<T> {
+    /// Insert `value` so that it ends up at `index`
+    Insert {
+        /// Where the new element goes
+        index: usize,
+        /// The new element
+        value: T
+    },
+    /// Remove the element at `index`
+    Remove {
+        /// The element to remove
+        index: usize
+    },
+    /// Replace the element at `index` with `value`
+    Replace {
+        /// The element to replace
+        index: usize,
+        /// The new element
+        value: T
+    },
+}
+
+// One step of the alignment between the two lists.
+enum Step {
+    Keep,
+    Remove,
+    Insert(usize),
+}
+
+impl<T: PartialEq + Clone> CdlList<T> {
+    /// Returns a script of edits that turns this list into `other`, so that 
+    /// `self.apply(&self.diff(other))` leaves the two lists equal.  The edits are 
+    /// ordered from head to tail, and each index accounts for the edits before it.
+    /// 
+    /// The script keeps a longest common subsequence of the two lists in place and 
+    /// edits the elements around it.  Within each run of differing elements, 
+    /// removals and insertions are paired up into replacements where possible. 
+    /// Finding the subsequence takes O(n·m) time and space for lists of sizes n 
+    /// and m, so this is meant for lists of modest size.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, Edit};
+    /// let a = CdlList::from("kitten");
+    /// let b = CdlList::from("sitting");
+    /// 
+    /// assert_eq!(a.diff(&b), vec![
+    ///     Edit::Replace { index: 0, value: 's' },
+    ///     Edit::Replace { index: 4, value: 'i' },
+    ///     Edit::Insert { index: 6, value: 'g' },
+    /// ]);
+    /// ```
+    pub fn diff(&self, other: &CdlList<T>) -> Vec<Edit<T>> {
+        let (a_nodes, b_nodes) = (self.nodes(), other.nodes());
+        let a = borrow_all(&a_nodes);
+        let b = borrow_all(&b_nodes);
+        let table = suffix_lcs_table(&a, &b);
+
+        // walk the table from the front, preferring to remove before inserting
+        let (mut i, mut j) = (0, 0);
+        let mut steps = Vec::new();
+        while i < a.len() || j < b.len() {
+            if i < a.len() && j < b.len() && *a[i] == *b[j] {
+                steps.push(Step::Keep);
+                i += 1;
+                j += 1;
+            } else if j == b.len() || (i < a.len() && table[i + 1][j] >= table[i][j + 1]) {
+                steps.push(Step::Remove);
+                i += 1;
+            } else {
+                steps.push(Step::Insert(j));
+                j += 1;
+            }
+        }
+
+        let mut edits = Vec::new();
+        let mut index = 0;
+        let mut removals = 0;
+        let mut insertions = Vec::new();
+        for step in steps.into_iter().chain(iter::once(Step::Keep)) {
+            match step {
+                Step::Remove => removals += 1,
+                Step::Insert(j) => insertions.push(j),
+                Step::Keep => {
+                    // flush the run of differences before this element
+                    let replaced = removals.min(insertions.len());
+                    for &j in &insertions[..replaced] {
+                        edits.push(Edit::Replace { index, value: b[j].clone() });
+                        index += 1;
+                    }
+                    for _ in replaced..removals {
+                        edits.push(Edit::Remove { index });
+                    }
+                    for &j in &insertions[replaced..] {
+                        edits.push(Edit::Insert { index, value: b[j].clone() });
+                        index += 1;
+                    }
+
+                    removals = 0;
+                    insertions.clear();
+                    index += 1;
+                },
+            }
+        }
+        edits
+    }
+
+    /// Carries out a script of edits, in order, such as one produced by 
+    /// [`CdlList::diff()`].  Insertions and removals are reported to the list's 
+    /// observer like [`CdlList::insert_at()`] and [`CdlList::remove_at()`], and a 
+    /// replacement is reported as a removal followed by an insertion.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if an edit's index is out of range for the list at that point in the 
+    /// script.  The edits before it will already have been applied.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut replica = CdlList::from("abcde");
+    /// let primary = CdlList::from("xbcyd");
+    /// 
+    /// let script = replica.diff(&primary);
+    /// replica.apply(&script);
+    /// assert_eq!(replica.to_string_chars(), "xbcyd");
+    /// ```
+    pub fn apply(&mut self, edits: &[Edit<T>]) {
+        for edit in edits {
+            match edit {
+                Edit::Insert { index, value } => {
+                    assert!(*index <= self.size, "insertion index {} out of range for size {}", index, self.size);
+                    let _ = self.insert_at(*index, value.clone());
+                },
+                Edit::Remove { index } => {
+                    assert!(*index < self.size, "removal index {} out of range for size {}", index, self.size);
+                    self.remove_at(*index);
+                },
+                Edit::Replace { index, value } => {
+                    assert!(*index < self.size, "replacement index {} out of range for size {}", index, self.size);
+                    self.replace_range(*index..*index + 1, iter::once(value.clone()));
+                },
+            }
+        }
+    }
+}
+
+impl<T: PartialEq> CdlList<T> {
+    /// Returns the length of a longest common subsequence of this list and 
+    /// `other`: the most elements that appear in both lists in the same order, 
+    /// though not necessarily next to each other.  This takes O(n·m) time for 
+    /// lists of sizes n and m, but only O(min(n, m)) space.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let a = CdlList::from("ABCBDAB");
+    /// let b = CdlList::from("BDCABA");
+    /// assert_eq!(a.lcs_len(&b), 4);
+    /// ```
+    pub fn lcs_len(&self, other: &CdlList<T>) -> usize {
+        let (a_nodes, b_nodes) = (self.nodes(), other.nodes());
+        let a = borrow_all(&a_nodes);
+        let b = borrow_all(&b_nodes);
+
+        // keep one row of the table, as long as the shorter list
+        let (rows, cols) = if a.len() >= b.len() { (a.len(), b.len()) } else { (b.len(), a.len()) };
+        let equal = |row: usize, col: usize| if a.len() >= b.len() {
+            *a[row] == *b[col]
+        } else {
+            *a[col] == *b[row]
+        };
+
+        let mut prev = vec![0; cols + 1];
+        let mut cur = vec![0; cols + 1];
+        for row in 0..rows {
+            for col in 0..cols {
+                cur[col + 1] = if equal(row, col) {
+                    prev[col] + 1
+                } else {
+                    prev[col + 1].max(cur[col])
+                };
+            }
+            std::mem::swap(&mut prev, &mut cur);
+        }
+        prev[cols]
+    }
+
+    /// Returns whether every element of this list appears in `other` in the same 
+    /// order, though not necessarily next to each other.  The empty list is a 
+    /// subsequence of every list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let word = CdlList::from("ace");
+    /// assert!(word.is_subsequence_of(&CdlList::from("abcde")));
+    /// assert!(!word.is_subsequence_of(&CdlList::from("aec")));
+    /// ```
+    pub fn is_subsequence_of(&self, other: &CdlList<T>) -> bool {
+        let needles = self.nodes();
+        let mut found = 0;
+        other.walk(|val| {
+            if found < needles.len() && needles[found].borrow().data == *val {
+                found += 1;
+            }
+        });
+        found == needles.len()
+    }
+}
+
+impl<T: PartialEq + Clone> CdlList<T> {
+    /// Returns a new list holding a longest common subsequence of this list and 
+    /// `other`, as described in [`CdlList::lcs_len()`].  When there are several, 
+    /// the one returned is the same one [`CdlList::diff()`] keeps in place.  If 
+    /// either list is empty, so is the result.  This takes O(n·m) time and space.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let a = CdlList::from("ABCBDAB");
+    /// let b = CdlList::from("BDCABA");
+    /// assert_eq!(a.lcs(&b).to_string_chars(), "BDAB");
+    /// ```
+    pub fn lcs(&self, other: &CdlList<T>) -> CdlList<T> {
+        let (a_nodes, b_nodes) = (self.nodes(), other.nodes());
+        let a = borrow_all(&a_nodes);
+        let b = borrow_all(&b_nodes);
+        let table = suffix_lcs_table(&a, &b);
+
+        // follow the same path through the table as diff
+        let (mut i, mut j) = (0, 0);
+        let mut common = Vec::with_capacity(table[0][0]);
+        while i < a.len() && j < b.len() {
+            if *a[i] == *b[j] {
+                common.push(a[i].clone());
+                i += 1;
+                j += 1;
+            } else if table[i + 1][j] >= table[i][j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        let mut out = CdlList::new();
+        out.push_back_all(common);
+        out
+    }
+}
+
+fn borrow_all<T>(nodes: &[Rc<RefCell<Node<T>>>]) -> Vec<Ref<'_, T>> {
+    nodes.iter().map(|node| Ref::map(node.borrow(), |node| &node.data)).collect()
+}
+
+// table[i][j] is the length of a longest common subsequence of a[i..] and b[j..].
+fn suffix_lcs_table<T: PartialEq>(a: &[Ref<'_, T>], b: &[Ref<'_, T>]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            table[i][j] = if *a[i] == *b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+    table
+}
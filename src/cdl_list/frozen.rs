@@ -0,0 +1,110 @@
+//! Immutable snapshots of a [`CdlList`] that can be shared cheaply.  Freezing a 
+//! list moves it behind an `Rc`, so copies of the snapshot share its nodes, and 
+//! since nothing can change a frozen list, reading it never conflicts with a 
+//! mutable borrow.
+
+use std::{fmt::{self, Debug}, rc::Rc};
+
+use super::{CdlList, ElemRef, Iter};
+
+/// A read-only list created by [`CdlList::freeze()`].  Cloning a frozen list 
+/// only bumps a reference count, so it can be handed to any number of readers 
+/// without copying its elements.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let frozen = CdlList::from("abc").freeze();
+/// let shared = frozen.clone();
+/// 
+/// assert_eq!(shared.size(), 3);
+/// assert_eq!(*frozen.get(1).unwrap().borrow(), 'b');
+/// ```
+pub struct FrozenCdlList<T> {
+    list: Rc<CdlList<T>>
+}
+
+impl<T> Clone for FrozenCdlList<T> {
+    fn clone(&self) -> Self {
+        FrozenCdlList { list: Rc::clone(&self.list) }
+    }
+}
+
+impl<T> CdlList<T> {
+    /// Turns the list into a [`FrozenCdlList`], which can be cloned cheaply but 
+    /// not changed.  This takes constant time.
+    pub fn freeze(self) -> FrozenCdlList<T> {
+        FrozenCdlList { list: Rc::new(self) }
+    }
+}
+
+impl<T> FrozenCdlList<T> {
+    /// Returns the number of elements in the list.
+    pub fn size(&self) -> usize {
+        self.list.size()
+    }
+
+    /// Returns whether the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the element at `index`, or `None` if `index` is out of range.  This 
+    /// walks from whichever end of the list is closer.
+    pub fn get(&self, index: usize) -> Option<ElemRef<'_, T>> {
+        self.list.get(index)
+    }
+
+    /// Returns an iterator over the list's elements, from head to tail.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let frozen = CdlList::from("abc").freeze();
+    /// let upper : String = frozen.iter().map(|c| c.borrow().to_ascii_uppercase()).collect();
+    /// assert_eq!(upper, "ABC");
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Turns the frozen list back into a [`CdlList`] if this is its only copy, 
+    /// in constant time.  Otherwise, returns the frozen list unchanged.
+    pub fn try_thaw(self) -> Result<CdlList<T>, FrozenCdlList<T>> {
+        Rc::try_unwrap(self.list).map_err(|list| FrozenCdlList { list })
+    }
+}
+
+impl<T: Clone> FrozenCdlList<T> {
+    /// Turns the frozen list back into a [`CdlList`].  If this is the only copy of 
+    /// the frozen list, its nodes are reused and this takes constant time.  If 
+    /// there are other copies, they are left as they are and the elements are 
+    /// cloned into a new list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let frozen = CdlList::from("abc").freeze();
+    /// let shared = frozen.clone();
+    /// 
+    /// // shared, so this clones the elements
+    /// let mut list = frozen.thaw();
+    /// list.push_back('d');
+    /// assert_eq!(list.to_string_chars(), "abcd");
+    /// 
+    /// // the last copy, so this reuses the nodes
+    /// assert_eq!(shared.thaw().to_string_chars(), "abc");
+    /// ```
+    pub fn thaw(self) -> CdlList<T> {
+        self.try_thaw().unwrap_or_else(|frozen| CdlList::clone(&frozen.list))
+    }
+}
+
+impl<T: Debug> fmt::Display for FrozenCdlList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.list, f)
+    }
+}
+
+impl<T: Debug> Debug for FrozenCdlList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FrozenCdlList").field(&self.list.view(..).unwrap()).finish()
+    }
+}
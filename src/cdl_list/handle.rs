@@ -0,0 +1,255 @@
+//! Handles to individual nodes of a [`CdlList`].  A handle refers to a node 
+//! rather than an index, so it keeps pointing at the same element while other 
+//! elements are added or removed around it.
+
+use std::{cell::RefCell, fmt::{self, Debug}, rc::{Rc, Weak}};
+
+use super::{CdlList, ElemMut, ElemRef, Node, Owner};
+
+/// A reference to a single element of a [`CdlList`], created by 
+/// [`CdlList::handle_at()`].
+/// 
+/// A handle doesn't keep its element alive or borrow the list.  Once the element 
+/// is removed from the list, the handle is *stale* and methods taking it return 
+/// `None`.  Likewise, a handle is only accepted by the list its element currently 
+/// belongs to.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list : CdlList<u32> = CdlList::new();
+/// list.push_back(1);
+/// list.push_back(2);
+/// 
+/// let h = list.handle_at(1).unwrap();
+/// list.push_front(0);
+/// assert_eq!(*list.peek_handle(&h).unwrap().borrow(), 2);
+/// 
+/// list.pop_back();
+/// assert!(list.peek_handle(&h).is_none());
+/// ```
+pub struct NodeHandle<T> {
+    node: Weak<RefCell<Node<T>>>
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        NodeHandle { node: Weak::clone(&self.node) }
+    }
+}
+
+impl<T: Debug> Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.node.upgrade() {
+            Some(node) => f.debug_tuple("NodeHandle").field(&node.borrow().data).finish(),
+            None => f.write_str("NodeHandle(<stale>)"),
+        }
+    }
+}
+
+impl<T> CdlList<T> {
+    /// Returns a handle to the element at `index`, or `None` if `index` is out of 
+    /// range.
+    pub fn handle_at(&self, index: usize) -> Option<NodeHandle<T>> {
+        self.node_at(index).map(|node| NodeHandle { node: Rc::downgrade(&node) })
+    }
+
+    /// Returns a shared borrow of the element `h` refers to, or `None` if `h` is 
+    /// stale or belongs to another list.  Like [`CdlList::get_from_back()`], the 
+    /// element is returned as an [`ElemRef`], but finding it takes constant time.
+    pub fn peek_handle(&self, h: &NodeHandle<T>) -> Option<ElemRef<'_, T>> {
+        self.resolve(h).map(ElemRef::new)
+    }
+
+    /// Returns a mutable borrow of the element `h` refers to, or `None` if `h` is 
+    /// stale or belongs to another list.  This takes `&mut self` even though the 
+    /// element is found in constant time, so the list can't be read or changed 
+    /// while the [`ElemMut`] exists.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// 
+    /// let h = list.handle_at(0).unwrap();
+    /// *list.peek_handle_mut(&h).unwrap().borrow_mut() += 10;
+    /// 
+    /// assert_eq!(*list.peek_front().unwrap(), 11);
+    /// ```
+    pub fn peek_handle_mut(&mut self, h: &NodeHandle<T>) -> Option<ElemMut<'_, T>> {
+        self.resolve(h).map(ElemMut::new)
+    }
+
+    /// Returns the current index of the element `h` refers to, or `None` if `h` is 
+    /// stale or belongs to another list.  The index is found by walking from the 
+    /// head until the element's node is reached, so this takes O(n) time.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// 
+    /// let h = list.handle_at(2).unwrap();
+    /// list.remove_at(0);
+    /// assert_eq!(list.handle_index(&h), Some(1));
+    /// ```
+    pub fn handle_index(&self, h: &NodeHandle<T>) -> Option<usize> {
+        let target = self.resolve(h)?;
+        Some(self.index_of_node(&target))
+    }
+
+    /// Rotates the list so that the element `h` refers to becomes the head, like 
+    /// [`CdlList::rotate_left()`] by that element's index, and returns `true`.  If 
+    /// `h` is stale or belongs to another list, returns `false` and leaves the list 
+    /// untouched.
+    /// 
+    /// The element's index is found by walking out from the head in both 
+    /// directions at once, so this takes time proportional to the element's 
+    /// distance from the head going whichever way around the ring is shorter. 
+    /// Only the head and tail move; no elements are moved.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// let h = list.handle_at(3).unwrap();
+    /// assert!(list.rotate_head_to_handle(&h));
+    /// assert_eq!(list.to_string(), "... <=> 3 <=> 4 <=> 0 <=> 1 <=> 2 <=> ...");
+    /// ```
+    pub fn rotate_head_to_handle(&mut self, h: &NodeHandle<T>) -> bool {
+        let Some(target) = self.resolve(h) else {
+            return false;
+        };
+
+        let mut forward = Rc::clone(self.head.as_ref().unwrap());
+        let mut backward = Rc::clone(&forward);
+        let mut steps = 0;
+        let mid = loop {
+            if Rc::ptr_eq(&forward, &target) {
+                break steps;
+            }
+            if Rc::ptr_eq(&backward, &target) {
+                break self.size - steps;
+            }
+            steps += 1;
+            forward = Node::next_of(&forward);
+            backward = Node::prev_of(&backward);
+        };
+
+        self.record_seek(steps);
+        if mid != 0 {
+            self.reseat_head(target, mid);
+        }
+        true
+    }
+
+    /// Splits the list in two at the element `h` refers to, returning a new list 
+    /// that runs from that element to the old tail and leaving the elements before 
+    /// it in this list.  If `h` is the head, every element moves to the new list. 
+    /// Returns `None`, leaving the list untouched, if `h` is stale or belongs to 
+    /// another list.
+    /// 
+    /// No index lookup is needed to find where to cut, but the moved elements are 
+    /// walked once to hand them over to the new list, so this takes time 
+    /// proportional to the size of the returned list.  Handles to moved elements 
+    /// stay valid and are accepted by the new list from then on.  This list's 
+    /// observer sees each moved element as a removal.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// let h = list.handle_at(3).unwrap();
+    /// let back = list.split_at_handle(&h).unwrap();
+    /// assert_eq!(list.to_string(), "... <=> 0 <=> 1 <=> 2 <=> ...");
+    /// assert_eq!(back.to_string(), "... <=> 3 <=> 4 <=> ...");
+    /// 
+    /// assert!(list.peek_handle(&h).is_none());
+    /// assert_eq!(*back.peek_handle(&h).unwrap().borrow(), 3);
+    /// ```
+    pub fn split_at_handle(&mut self, h: &NodeHandle<T>) -> Option<CdlList<T>> {
+        let target = self.resolve(h)?;
+        Some(self.split_off_node(target))
+    }
+
+    /// Removes the span of elements running forward from the element `from` 
+    /// refers to up to the one `to` refers to, and returns them as a new list in 
+    /// that order.  The span follows next links, so it wraps past the tail to the 
+    /// head if `to` comes before `from`.  With `inclusive`, both ends are removed 
+    /// too; otherwise only the elements strictly between them are.  If `from` and 
+    /// `to` refer to the same element, that element alone is removed when 
+    /// `inclusive` is set, and nothing is removed otherwise.
+    /// 
+    /// Returns `None`, leaving the list untouched, if either handle is stale or 
+    /// belongs to another list.  The list's observer sees each removed element, 
+    /// as with [`CdlList::replace_range()`].  This walks the list from the head to 
+    /// find where the span starts, so it takes O(n) time.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..6 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// // from 4 forward to 1, wrapping past the tail
+    /// let from = list.handle_at(4).unwrap();
+    /// let to = list.handle_at(1).unwrap();
+    /// let removed = list.remove_between(&from, &to, true).unwrap();
+    /// 
+    /// assert_eq!(removed.to_string(), "... <=> 4 <=> 5 <=> 0 <=> 1 <=> ...");
+    /// assert_eq!(list.to_string(), "... <=> 2 <=> 3 <=> ...");
+    /// ```
+    pub fn remove_between(&mut self, from: &NodeHandle<T>, to: &NodeHandle<T>, inclusive: bool) -> Option<CdlList<T>> {
+        let from = self.resolve(from)?;
+        let to = self.resolve(to)?;
+
+        let (first, last) = if inclusive {
+            (from, to)
+        } else if Rc::ptr_eq(&from, &to) || Rc::ptr_eq(&Node::next_of(&from), &to) {
+            return Some(CdlList::new());
+        } else {
+            (Node::next_of(&from), Node::prev_of(&to))
+        };
+
+        let start = self.index_of_node(&first);
+        let mut len = 1;
+        let mut node = first;
+        while !Rc::ptr_eq(&node, &last) {
+            node = Node::next_of(&node);
+            len += 1;
+        }
+        self.record_seek(len);
+
+        let size = self.size;
+        if start + len <= size {
+            return self.replace_range(start..start + len, []);
+        }
+
+        // the span wraps, so take the part up to the tail, then the rest from the 
+        // head
+        let mut removed = self.replace_range(start..size, [])?;
+        let mut wrapped = self.replace_range(0..start + len - size, [])?;
+        while let Some(node) = wrapped.pop_node(true) {
+            removed.push_node(node, false);
+        }
+        Some(removed)
+    }
+
+    // Returns the node h refers to, if it is still alive and linked into this list.
+    pub(super) fn resolve(&self, h: &NodeHandle<T>) -> Option<Rc<RefCell<Node<T>>>> {
+        let node = h.node.upgrade()?;
+        let owned = node.borrow().owner.as_ref()
+            .is_some_and(|owner| Rc::ptr_eq(&Owner::root(owner), &self.owner));
+
+        if owned { Some(node) } else { None }
+    }
+}
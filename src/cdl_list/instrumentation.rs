@@ -0,0 +1,84 @@
+//! Operation counters for a [`CdlList`], enabled with the `instrumentation` 
+//! feature.  Every list keeps its own counters, which are plain `Cell<u64>`s, so 
+//! they can be updated by methods that only borrow the list.  Without the 
+//! feature, the counters and the methods in this module don't exist at all.
+
+use std::{cell::Cell, fmt::Debug};
+
+use super::CdlList;
+
+/// A snapshot of a list's operation counters, returned by [`CdlList::stats()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ListStats {
+    /// Number of elements pushed to the front or back of the list, including 
+    /// insertions at either end
+    pub pushes: u64,
+    /// Number of elements popped from the front or back of the list, including 
+    /// removals at either end
+    pub pops: u64,
+    /// Number of times the list was walked to find a position
+    pub seeks: u64,
+    /// Total number of links followed during seeks
+    pub nodes_traversed: u64,
+}
+
+// The live counters stored in each list.
+#[derive(Default)]
+pub(super) struct Counters {
+    pushes: Cell<u64>,
+    pops: Cell<u64>,
+    seeks: Cell<u64>,
+    nodes_traversed: Cell<u64>,
+}
+
+impl<T> CdlList<T> {
+    /// Returns the operations counted since the list was created or since the 
+    /// last call to [`CdlList::reset_stats()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::{CdlList, ListStats};
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..10 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// // index 3 is closer to the head, so the walk starts there
+    /// list.insert_at(3, 100).unwrap();
+    /// 
+    /// assert_eq!(list.stats(), ListStats { pushes: 10, pops: 0, seeks: 1, nodes_traversed: 2 });
+    /// ```
+    pub fn stats(&self) -> ListStats {
+        let counters = &self.counters;
+        ListStats {
+            pushes: counters.pushes.get(),
+            pops: counters.pops.get(),
+            seeks: counters.seeks.get(),
+            nodes_traversed: counters.nodes_traversed.get(),
+        }
+    }
+
+    /// Sets all of the list's operation counters back to zero.
+    pub fn reset_stats(&self) {
+        let counters = &self.counters;
+        counters.pushes.set(0);
+        counters.pops.set(0);
+        counters.seeks.set(0);
+        counters.nodes_traversed.set(0);
+    }
+
+    pub(super) fn record_push(&self) {
+        let pushes = &self.counters.pushes;
+        pushes.set(pushes.get() + 1);
+    }
+
+    pub(super) fn record_pop(&self) {
+        let pops = &self.counters.pops;
+        pops.set(pops.get() + 1);
+    }
+
+    pub(super) fn record_seek(&self, nodes: usize) {
+        let counters = &self.counters;
+        counters.seeks.set(counters.seeks.get() + 1);
+        counters.nodes_traversed.set(counters.nodes_traversed.get() + nodes as u64);
+    }
+}
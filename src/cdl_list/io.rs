@@ -0,0 +1,81 @@
+//! Lists used as streams: a `CdlList<u8>` is a byte queue that can be written 
+//! to as a [`std::io::Write`] sink and drained as a [`std::io::Read`] source, 
+//! and a `CdlList<char>` can be the target of [`write!`] through 
+//! [`std::fmt::Write`].
+
+use std::{fmt, io};
+
+use super::CdlList;
+
+impl io::Write for CdlList<u8> {
+    /// Pushes all of `buf` onto the back of the list in one step, returning 
+    /// `buf.len()`.  This never fails.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::io::Write;
+    /// 
+    /// let mut list : CdlList<u8> = CdlList::new();
+    /// write!(list, "{}-{}", 4, 2).unwrap();
+    /// 
+    /// assert_eq!(list.to_string(), "... <=> 52 <=> 45 <=> 50 <=> ...");
+    /// ```
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(self.push_back_all(buf.iter().copied()))
+    }
+
+    /// Does nothing, since written bytes go straight into the list.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl io::Read for CdlList<u8> {
+    /// Pops bytes from the front of the list into `buf` until either `buf` is full 
+    /// or the list is empty, returning how many were read.  Bytes come out in the 
+    /// order they were pushed to the back, and an empty list reads as the end of 
+    /// the stream, returning 0.  This never fails.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::io::{Read, Write};
+    /// 
+    /// let mut list : CdlList<u8> = CdlList::new();
+    /// list.write_all(b"hello").unwrap();
+    /// 
+    /// let mut buf = [0; 3];
+    /// assert_eq!(list.read(&mut buf).unwrap(), 3);
+    /// assert_eq!(&buf, b"hel");
+    /// 
+    /// let mut rest = String::new();
+    /// list.read_to_string(&mut rest).unwrap();
+    /// assert_eq!(rest, "lo");
+    /// assert!(list.is_empty());
+    /// ```
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.size());
+        for byte in &mut buf[..n] {
+            *byte = self.pop_front().unwrap();
+        }
+        Ok(n)
+    }
+}
+
+impl fmt::Write for CdlList<char> {
+    /// Pushes the characters of `s` onto the back of the list in one step.  This 
+    /// never fails.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// use std::fmt::Write;
+    /// 
+    /// let mut list = CdlList::from("x = ");
+    /// write!(list, "{:.1}", 2.25).unwrap();
+    /// 
+    /// assert_eq!(list.to_string_chars(), "x = 2.2");
+    /// ```
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_back_all(s.chars());
+        Ok(())
+    }
+}
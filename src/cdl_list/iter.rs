@@ -0,0 +1,675 @@
+//! Iterators over a [`CdlList`].  [`Iter`] walks the list without changing it, 
+//! lending out each element as an [`ElemRef`], [`IterMut`] does the same with 
+//! [`ElemMut`]s so the elements can be changed in place, and [`IntoIter`] 
+//! consumes the list and returns its elements by value.  All three visit each 
+//! element exactly once rather than going around the ring forever, and all three 
+//! can be run from either end.  [`Cycle`] is the exception: it follows the 
+//! ring around and around, and never stops unless the list is empty.  [`Drain`] 
+//! and [`ExtractIf`] remove elements from a list as they go, leaving the rest of 
+//! it linked up, and [`CdlList::drain_range()`] detaches a range of elements and 
+//! returns them through an [`IntoIter`].
+
+use std::{cell::RefCell, convert::Infallible, fmt::Debug, iter::{self, FusedIterator}, marker::PhantomData, ops::{ControlFlow, RangeBounds}, rc::Rc};
+
+use super::{CdlList, ElemMut, ElemRef, LinkType, ListEvent, Node, OpenChains};
+
+/// An iterator over the elements of a [`CdlList`], or of a range of one, created 
+/// by [`CdlList::iter()`] or [`ListView::iter()`](super::ListView::iter).  Each 
+/// element is returned as an [`ElemRef`], which borrows the element's data when 
+/// asked.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list : CdlList<u32> = CdlList::new();
+/// list.push_back(1);
+/// list.push_back(2);
+/// list.push_back(3);
+/// 
+/// let total : u32 = list.iter().map(|x| *x.borrow()).sum();
+/// assert_eq!(total, 6);
+/// 
+/// let backwards : Vec<u32> = list.iter().rev().map(|x| *x.borrow()).collect();
+/// assert_eq!(backwards, [3, 2, 1]);
+/// ```
+/// 
+/// The list stays borrowed until the iterator is dropped, even if it isn't used 
+/// again, since it holds on to the next element:
+/// 
+/// ```compile_fail
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list = CdlList::from([1, 2, 3]);
+/// 
+/// let mut it = list.iter();
+/// it.next();
+/// list.pop_front();
+/// list.pop_front();
+/// ```
+pub struct Iter<'a, T> {
+    front: Option<Rc<RefCell<Node<T>>>>,
+    back: Option<Rc<RefCell<Node<T>>>>,
+    remaining: usize,
+    _list: PhantomData<&'a CdlList<T>>
+}
+
+/// An iterator over the elements of a [`CdlList`] that allows changing them, 
+/// created by [`CdlList::iter_mut()`].  Each element is returned as an 
+/// [`ElemMut`], which mutably borrows the element's data when asked.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list = CdlList::from([1, 2, 3]);
+/// 
+/// for mut x in list.iter_mut().rev() {
+///     *x.borrow_mut() *= 10;
+/// }
+/// assert_eq!(list, CdlList::from([10, 20, 30]));
+/// ```
+pub struct IterMut<'a, T> {
+    front: Option<Rc<RefCell<Node<T>>>>,
+    back: Option<Rc<RefCell<Node<T>>>>,
+    remaining: usize,
+    _list: PhantomData<&'a mut CdlList<T>>
+}
+
+/// An endless iterator over the elements of a [`CdlList`], created by 
+/// [`CdlList::cycle_iter()`] or [`CdlList::cycle_iter_from()`].  After the tail 
+/// it wraps around to the head, so it only ever returns `None` for an empty list.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let workers = CdlList::from(["a", "b", "c"]);
+/// 
+/// let assigned : Vec<(u32, &str)> = (1..=5).zip(workers.cycle_iter().map(|w| *w.borrow())).collect();
+/// assert_eq!(assigned, [(1, "a"), (2, "b"), (3, "c"), (4, "a"), (5, "b")]);
+/// ```
+pub struct Cycle<'a, T> {
+    next: Option<Rc<RefCell<Node<T>>>>,
+    _list: PhantomData<&'a CdlList<T>>
+}
+
+/// An iterator that moves the elements out of a [`CdlList`], created by its 
+/// [`IntoIterator`] implementation.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let list = CdlList::from("abc");
+/// 
+/// let mut s = String::new();
+/// for c in list {
+///     s.push(c);
+/// }
+/// assert_eq!(s, "abc");
+/// ```
+pub struct IntoIter<T> {
+    list: CdlList<T>
+}
+
+/// An iterator that removes every element from a [`CdlList`] and returns it by 
+/// value, created by [`CdlList::drain()`].  Any elements that haven't been 
+/// returned when the iterator is dropped are removed then.
+pub struct Drain<'a, T> {
+    list: &'a mut CdlList<T>
+}
+
+/// An iterator that removes the elements of a [`CdlList`] matching a predicate 
+/// and returns them by value, created by [`CdlList::extract_if()`].  Elements 
+/// the iterator hasn't reached when it is dropped stay in the list.
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    list: &'a mut CdlList<T>,
+    pred: F,
+    next: Option<Rc<RefCell<Node<T>>>>,
+    index: usize,
+    removed: usize,
+    remaining: usize
+}
+
+impl<T> CdlList<T> {
+    /// Returns an iterator over the list's elements, from head to tail.  It 
+    /// yields exactly `size()` elements and then stops, and can also be run from 
+    /// the tail with [`Iterator::rev()`] or [`DoubleEndedIterator::next_back()`]. 
+    /// The list can't be changed while the iterator exists.
+    /// 
+    /// Since each element lives in a `RefCell`, the iterator yields [`ElemRef`]s 
+    /// rather than plain references.  Call [`ElemRef::borrow()`] to read one.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from("ring");
+    /// let upper : String = list.iter().map(|c| c.borrow().to_ascii_uppercase()).collect();
+    /// assert_eq!(upper, "RING");
+    /// 
+    /// let empty : CdlList<u32> = CdlList::new();
+    /// assert!(empty.iter().next().is_none());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.head.clone(), self.tail.clone(), self.size)
+    }
+
+    /// Returns an iterator over the list's elements that allows changing them, 
+    /// from head to tail or, with [`Iterator::rev()`], from tail to head.  Like 
+    /// [`CdlList::iter()`] it yields exactly `size()` elements, and the list's 
+    /// observer isn't told about any changes made through it.
+    /// 
+    /// Each element is yielded as an [`ElemMut`].  Call [`ElemMut::borrow_mut()`] 
+    /// to change one.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from("ring");
+    /// for mut c in list.iter_mut() {
+    ///     c.borrow_mut().make_ascii_uppercase();
+    /// }
+    /// assert_eq!(list.to_string_chars(), "RING");
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { front: self.head.clone(), back: self.tail.clone(), remaining: self.size, _list: PhantomData }
+    }
+
+    /// Returns an iterator that walks the list's elements forever, from head to 
+    /// tail and then around to the head again, for round-robin style use.  Like 
+    /// [`CdlList::iter()`] it yields [`ElemRef`]s, and the list can't be changed 
+    /// while the iterator exists.  It yields nothing if the list is empty, and 
+    /// otherwise never ends, so it's usually paired with [`Iterator::take()`] or 
+    /// [`Iterator::zip()`].
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from([1, 2, 3]);
+    /// let seven : Vec<u32> = list.cycle_iter().take(7).map(|x| *x.borrow()).collect();
+    /// assert_eq!(seven, [1, 2, 3, 1, 2, 3, 1]);
+    /// 
+    /// let empty : CdlList<u32> = CdlList::new();
+    /// assert!(empty.cycle_iter().next().is_none());
+    /// ```
+    pub fn cycle_iter(&self) -> Cycle<'_, T> {
+        Cycle { next: self.head.clone(), _list: PhantomData }
+    }
+
+    /// Like [`CdlList::cycle_iter()`], but starts from the element at `start` 
+    /// rather than the head.  Since the walk wraps around anyway, `start` may be 
+    /// past the end of the list and is taken modulo `size()`.  The starting 
+    /// element is found by walking from whichever end is closer.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let list = CdlList::from([1, 2, 3]);
+    /// let from_third : Vec<u32> = list.cycle_iter_from(2).take(4).map(|x| *x.borrow()).collect();
+    /// assert_eq!(from_third, [3, 1, 2, 3]);
+    /// 
+    /// let wrapped : Vec<u32> = list.cycle_iter_from(4).take(2).map(|x| *x.borrow()).collect();
+    /// assert_eq!(wrapped, [2, 3]);
+    /// ```
+    pub fn cycle_iter_from(&self, start: usize) -> Cycle<'_, T> {
+        let next = if self.is_empty() { None } else { self.node_at(start % self.size) };
+        Cycle { next, _list: PhantomData }
+    }
+
+    /// Returns an iterator that removes the list's elements from head to tail, or 
+    /// from tail to head with [`Iterator::rev()`], and returns them by value.  The 
+    /// elements are removed as they are returned, each reported to the list's 
+    /// observer as popped, and whatever is left when the iterator is dropped is 
+    /// removed too, so the list is always empty afterwards.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut jobs = CdlList::from([1, 2, 3, 4]);
+    /// 
+    /// let first_two : Vec<u32> = jobs.drain().take(2).collect();
+    /// assert_eq!(first_two, [1, 2]);
+    /// assert!(jobs.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { list: self }
+    }
+
+    /// Detaches the elements in `range` and returns an iterator that yields them by 
+    /// value, in order.  The rest of the list is linked back up straight away, so 
+    /// unlike [`CdlList::drain()`], the iterator doesn't borrow the list, and any 
+    /// elements it hasn't returned are dropped with it.  Each detached element is 
+    /// reported to the list's observer as a removal at the start of the range.
+    /// 
+    /// The list is walked once to find the start of the range, as in 
+    /// [`CdlList::replace_range()`].  If the range is decreasing or extends past the 
+    /// end of the list, `None` is returned and the list is left untouched.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list = CdlList::from([1, 2, 3, 4, 5]);
+    /// 
+    /// let middle : Vec<u32> = list.drain_range(1..4).unwrap().collect();
+    /// assert_eq!(middle, [2, 3, 4]);
+    /// assert_eq!(list.to_string(), "... <=> 1 <=> 5 <=> ...");
+    /// 
+    /// assert!(list.drain_range(..3).is_none());
+    /// ```
+    pub fn drain_range<R: RangeBounds<usize>>(&mut self, range: R) -> Option<IntoIter<T>> {
+        self.replace_range(range, iter::empty()).map(CdlList::into_iter)
+    }
+
+    /// Returns an iterator that walks the list once from head to tail, removing 
+    /// each element for which `pred` returns `true` and returning it by value.  The 
+    /// elements `pred` rejects stay where they are, and `pred` may change them. 
+    /// Like [`CdlList::retain()`], each removal just unlinks its node, and is 
+    /// reported to the list's observer with the element's index at the time.
+    /// 
+    /// The walk is lazy: `pred` is only called as the iterator is advanced, and if 
+    /// the iterator is dropped early, the elements it hasn't reached are kept.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut jobs = CdlList::from([(1, "done"), (2, "queued"), (3, "done"), (4, "queued")]);
+    /// 
+    /// let finished : Vec<u32> = jobs.extract_if(|job| job.1 == "done").map(|job| job.0).collect();
+    /// assert_eq!(finished, [1, 3]);
+    /// assert_eq!(jobs.size(), 2);
+    /// assert_eq!(jobs.peek_front().unwrap().0, 2);
+    /// ```
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F> {
+        let next = self.head.clone();
+        let remaining = self.size;
+        ExtractIf { list: self, pred, next, index: 0, removed: 0, remaining }
+    }
+}
+
+impl<'a, T> Iter<'a, T> {
+    // front and back are the first and last of len consecutive nodes.
+    pub(super) fn new(front: Option<Rc<RefCell<Node<T>>>>, back: Option<Rc<RefCell<Node<T>>>>, len: usize) -> Iter<'a, T> {
+        Iter { front, back, remaining: len, _list: PhantomData }
+    }
+}
+
+impl<'a, T> Iter<'a, T> {
+    // What try_fold would be, if overriding it didn't need the unstable Try 
+    // trait.  The current node is kept in a local and walked along the strong 
+    // next links, and only written back once f breaks or the elements run out, 
+    // so a partly walked iterator carries on from the right place.
+    fn try_walk<B, R, F>(&mut self, init: B, mut f: F) -> ControlFlow<R, B>
+    where
+        F: FnMut(B, ElemRef<'a, T>) -> ControlFlow<R, B>,
+    {
+        let mut acc = init;
+        let Some(mut node) = self.front.take() else {
+            return ControlFlow::Continue(acc);
+        };
+
+        while self.remaining > 0 {
+            self.remaining -= 1;
+            let next = if self.remaining > 0 { Some(Node::next_of(&node)) } else { None };
+            match f(acc, ElemRef::new(node)) {
+                ControlFlow::Continue(a) => acc = a,
+                ControlFlow::Break(r) => {
+                    self.front = next;
+                    return ControlFlow::Break(r);
+                }
+            }
+            match next {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter::new(self.front.clone(), self.back.clone(), self.remaining)
+    }
+}
+
+impl<T> Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Iter").field(&self.remaining).finish()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = ElemRef<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.front.take()?;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.front = Some(Node::next_of(&node));
+        }
+        Some(ElemRef::new(node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let ControlFlow::Continue(acc) = self.try_walk::<_, Infallible, _>(init, |acc, elem| ControlFlow::Continue(f(acc, elem)));
+        acc
+    }
+
+    fn for_each<F: FnMut(Self::Item)>(self, mut f: F) {
+        self.fold((), |(), elem| f(elem));
+    }
+
+    fn find<P>(&mut self, mut pred: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        match self.try_walk((), |(), elem| if pred(&elem) { ControlFlow::Break(elem) } else { ControlFlow::Continue(()) }) {
+            ControlFlow::Break(elem) => Some(elem),
+            ControlFlow::Continue(()) => None,
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.back.take()?;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.back = Some(Node::prev_of(&node));
+        }
+        Some(ElemRef::new(node))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for Iter<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<'a, T> IntoIterator for &'a CdlList<T> {
+    type Item = ElemRef<'a, T>;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Clone for Cycle<'_, T> {
+    fn clone(&self) -> Self {
+        Cycle { next: self.next.clone(), _list: PhantomData }
+    }
+}
+
+impl<T> Debug for Cycle<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cycle").finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> Iterator for Cycle<'a, T> {
+    type Item = ElemRef<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next.take()?;
+        self.next = Some(Node::next_of(&node));
+        Some(ElemRef::new(node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => (usize::MAX, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<T> FusedIterator for Cycle<'_, T> {}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for Cycle<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<T> Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IterMut").field(&self.remaining).finish()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = ElemMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.front.take()?;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.front = Some(Node::next_of(&node));
+        }
+        Some(ElemMut::new(node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn count(self) -> usize {
+        self.remaining
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.back.take()?;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.back = Some(Node::prev_of(&node));
+        }
+        Some(ElemMut::new(node))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for IterMut<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<'a, T> IntoIterator for &'a mut CdlList<T> {
+    type Item = ElemMut<'a, T>;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for CdlList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the list, returning an iterator that moves its elements out from 
+    /// head to tail, or from tail to head with [`Iterator::rev()`].  The list's 
+    /// observer isn't told about the removals.
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list.size()).finish()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop(true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size(), Some(self.list.size()))
+    }
+
+    fn count(self) -> usize {
+        self.list.size()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let ControlFlow::Continue(acc) = self.try_walk::<_, Infallible, _>(init, |acc, val| ControlFlow::Continue(f(acc, val)));
+        acc
+    }
+
+    fn for_each<F: FnMut(T)>(self, mut f: F) {
+        self.fold((), |(), val| f(val));
+    }
+
+    fn find<P>(&mut self, mut pred: P) -> Option<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        match self.try_walk((), |(), val| if pred(&val) { ControlFlow::Break(val) } else { ControlFlow::Continue(()) }) {
+            ControlFlow::Break(val) => Some(val),
+            ControlFlow::Continue(()) => None,
+        }
+    }
+}
+
+impl<T> IntoIter<T> {
+    // As for Iter, what try_fold would be.  The ring is opened into a chain 
+    // once, and each element is moved out of its node while walking the strong 
+    // next links, as free_nodes does.  What's left of the chain is held by an 
+    // OpenChains guard, which closes it back into the list if f breaks or 
+    // panics.
+    fn try_walk<B, R, F>(&mut self, init: B, mut f: F) -> ControlFlow<R, B>
+    where
+        F: FnMut(B, T) -> ControlFlow<R, B>,
+    {
+        let mut acc = init;
+        let Some(tail) = self.list.tail.take() else {
+            return ControlFlow::Continue(acc);
+        };
+        tail.borrow_mut().next = None;
+        drop(tail);
+
+        let size = std::mem::take(&mut self.list.size);
+        let rest = self.list.head.take();
+        let mut chains = OpenChains { list: &mut self.list, size, merged: None, left: None, right: None, rest };
+        while let Some(node) = chains.rest.take() {
+            chains.rest = match node.borrow_mut().next.take() {
+                Some(LinkType::StrongLink(next)) => Some(next),
+                _ => None,
+            };
+            chains.size -= 1;
+            acc = f(acc, Rc::try_unwrap(node).ok().unwrap().into_inner().data)?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop(false)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Debug for Drain<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Drain").field(&self.list.size()).finish()
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size(), Some(self.list.size()))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        while self.list.pop_front().is_some() {}
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Debug for ExtractIf<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ExtractIf").field(&self.remaining).finish()
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.remaining > 0 {
+            // find the next node before unlinking clears the links; the last 
+            // node has no next to visit
+            let node = self.next.take().unwrap();
+            self.remaining -= 1;
+            if self.remaining > 0 {
+                self.next = Some(Node::next_of(&node));
+            }
+
+            let index = self.index;
+            self.index += 1;
+            if (self.pred)(&mut node.borrow_mut().data) {
+                self.list.unlink_node(&node);
+                let val = Rc::try_unwrap(node).ok().unwrap().into_inner().data;
+                self.list.notify(ListEvent::RemovedAt { index: index - self.removed, value: &val });
+                self.removed += 1;
+                return Some(val);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> FusedIterator for ExtractIf<'_, T, F> {}
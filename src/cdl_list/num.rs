@@ -0,0 +1,97 @@
+//! Numeric aggregates over a [`CdlList`], enabled with the `num` feature.  These 
+//! are built on the traits from the [`num_traits`] crate, so they work for any 
+//! primitive integer or float type as well as user-defined numeric types.
+
+use num_traits::{CheckedAdd, One, ToPrimitive, Zero};
+
+use super::CdlList;
+
+impl<T: Clone + Zero> CdlList<T> {
+    /// Returns the sum of all elements in the list, or zero if the list is empty.
+    /// 
+    /// Overflow behaves exactly like the element type's `+`: for primitive integers, 
+    /// this panics in debug builds and wraps in release builds.  Use 
+    /// [`CdlList::checked_sum()`] to detect overflow instead.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// assert_eq!(list.sum(), 0);
+    /// 
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// assert_eq!(list.sum(), 6);
+    /// ```
+    pub fn sum(&self) -> T {
+        let mut total = T::zero();
+        self.walk(|val| total = total.clone() + val.clone());
+        total
+    }
+}
+
+impl<T: Clone + Zero + CheckedAdd> CdlList<T> {
+    /// Returns the sum of all elements in the list, or `None` if the sum overflows 
+    /// at any point.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<i32> = CdlList::new();
+    /// list.push_back(i32::MAX);
+    /// assert_eq!(list.checked_sum(), Some(i32::MAX));
+    /// 
+    /// list.push_back(1);
+    /// assert_eq!(list.checked_sum(), None);
+    /// ```
+    pub fn checked_sum(&self) -> Option<T> {
+        let mut total = Some(T::zero());
+        self.walk(|val| total = total.as_ref().and_then(|t| t.checked_add(val)));
+        total
+    }
+}
+
+impl<T: Clone + One> CdlList<T> {
+    /// Returns the product of all elements in the list, or one if the list is empty.  
+    /// Like [`CdlList::sum()`], overflow follows the element type's `*`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// assert_eq!(list.product(), 1);
+    /// 
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// list.push_back(4);
+    /// assert_eq!(list.product(), 24);
+    /// ```
+    pub fn product(&self) -> T {
+        let mut total = T::one();
+        self.walk(|val| total = total.clone() * val.clone());
+        total
+    }
+}
+
+impl<T: ToPrimitive> CdlList<T> {
+    /// Returns the arithmetic mean of the list as an `f64`, or `None` if the list is 
+    /// empty or an element cannot be represented as an `f64`.  The elements are 
+    /// accumulated as `f64`s, so this does not overflow for integer types.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// assert_eq!(list.mean(), None);
+    /// 
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.mean(), Some(1.5));
+    /// ```
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut total = Some(0.0);
+        self.walk(|val| total = total.and_then(|t| val.to_f64().map(|v| t + v)));
+        total.map(|t| t / self.size() as f64)
+    }
+}
@@ -0,0 +1,32 @@
+//! Support for generating lists in property tests with [`proptest`], enabled 
+//! with the `proptest` feature.
+
+use proptest::{collection::{self, SizeRange}, strategy::Strategy};
+
+use super::CdlList;
+
+/// Returns a [`Strategy`] that generates lists whose elements are drawn from 
+/// `element` and whose size is drawn from `size`, which can be a `usize` or any 
+/// range of them.
+/// 
+/// When a property fails, the list is shrunk first by removing elements and then 
+/// by shrinking the elements that remain, each step rebuilding the list from 
+/// scratch so every candidate is a well-formed ring.
+/// 
+/// ```rust
+/// use cdl_list_rs::cdl_list::{cdl_list_strategy, CdlList};
+/// use proptest::prelude::*;
+/// 
+/// // rotating a full turn changes nothing
+/// proptest!(|(list in cdl_list_strategy(any::<u8>(), 0..16))| {
+///     let mut rotated = list.clone();
+///     rotated.rotate_left(list.size());
+///     prop_assert_eq!(rotated, list);
+/// });
+/// ```
+pub fn cdl_list_strategy<S>(element: S, size: impl Into<SizeRange>) -> impl Strategy<Value = CdlList<S::Value>>
+where
+    S: Strategy,
+{
+    collection::vec(element, size).prop_map(CdlList::from)
+}
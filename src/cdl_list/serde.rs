@@ -0,0 +1,57 @@
+//! Support for serializing lists with [`serde`], enabled with the `serde` 
+//! feature.  A list is represented as a sequence of its elements from head to 
+//! tail, the same as a `Vec`, so the two are interchangeable in serialized data.
+//! 
+//! The length is given to the serializer up front and each element is written 
+//! as the list is walked, so binary formats that need the length first, like 
+//! `bincode`, can stream even a very large list without copying it into a `Vec`.
+
+use std::{fmt, marker::PhantomData, ops::ControlFlow};
+use serde::{
+    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+use super::CdlList;
+
+impl<T: Serialize> Serialize for CdlList<T> {
+    /// Serializes the elements as a sequence of length `size()`, from head to tail.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.size))?;
+        let failed = self.traverse(|val| match seq.serialize_element(val) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(err) => ControlFlow::Break(err),
+        });
+
+        match failed {
+            Some(err) => Err(err),
+            None => seq.end(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for CdlList<T> {
+    /// Deserializes a sequence, pushing each element to the back of the list as 
+    /// it arrives.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+struct ListVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ListVisitor<T> {
+    type Value = CdlList<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = CdlList::new();
+        while let Some(val) = seq.next_element()? {
+            list.push_back(val);
+        }
+        Ok(list)
+    }
+}
@@ -0,0 +1,95 @@
+//! Structured logging of a [`CdlList`]'s mutations, enabled with the `tracing` 
+//! feature.  Every event reported to an observer (see [`CdlList::set_observer()`]) 
+//! is also emitted as a `TRACE` level [`tracing`] event with the target 
+//! `cdl_list_rs`, carrying an `op` field naming the operation, the `front` flag 
+//! or `index` of the change, and the `size` of the list afterwards.  Splices 
+//! emit one more event summarizing the whole operation.  Without the feature, 
+//! none of this is compiled in.
+//! 
+//! Elements are left out of the events unless [`CdlList::set_trace_values()`] 
+//! is used to opt in, in which case they appear as a `value` field formatted 
+//! with `Debug`.  Opting in is the only part of the list that requires its 
+//! elements to be `Debug`.
+
+use std::fmt::{self, Debug};
+use tracing::{trace, Level};
+
+use super::{CdlList, ListEvent};
+
+// Formats an element for a trace event.  Stored in the list by 
+// set_trace_values, where T is known to be Debug.
+pub(super) type FormatValue<T> = fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result;
+
+// Lets a stored FormatValue be used as a Debug field value.
+struct Traced<'a, T>(&'a T, FormatValue<T>);
+
+impl<T> Debug for Traced<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+impl<T: Debug> CdlList<T> {
+    /// Sets whether the elements involved in each mutation are included in the 
+    /// list's `tracing` events.  This is off by default, since elements may be 
+    /// large or hold data that shouldn't end up in logs.
+    /// 
+    /// With the `tracing` feature, every push, pop, insertion, and removal is 
+    /// emitted as a `TRACE` level event with the target `cdl_list_rs`.  Each 
+    /// event has an `op` field (`"push"`, `"pop"`, `"insert_at"`, or 
+    /// `"remove_at"`), a `front` or `index` field locating the change, and the 
+    /// `size` of the list afterwards.  [`CdlList::replace_range()`] emits one 
+    /// event per element followed by a `"replace_range"` event with `start`, 
+    /// `removed`, `inserted`, and `size` fields.  Rotations emit a `"rotate_left"` 
+    /// event whose `mid` field is the index of the new head before rotating.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// list.set_trace_values(true);
+    /// list.push_back(1); // emits op="push" front=false size=1 value=1
+    /// ```
+    pub fn set_trace_values(&mut self, enabled: bool) {
+        self.trace_values = if enabled { Some(<T as Debug>::fmt) } else { None };
+    }
+}
+
+impl<T> CdlList<T> {
+    pub(super) fn trace_event(&self, event: &ListEvent<&T>) {
+        let size = self.size;
+        match (event, self.trace_values) {
+            (ListEvent::Pushed { front, .. }, None) =>
+                trace!(target: "cdl_list_rs", op = "push", front, size),
+            (ListEvent::Pushed { front, value }, Some(fmt)) =>
+                trace!(target: "cdl_list_rs", op = "push", front, size, value = ?Traced(*value, fmt)),
+            (ListEvent::Popped { front, .. }, None) =>
+                trace!(target: "cdl_list_rs", op = "pop", front, size),
+            (ListEvent::Popped { front, value }, Some(fmt)) =>
+                trace!(target: "cdl_list_rs", op = "pop", front, size, value = ?Traced(*value, fmt)),
+            (ListEvent::InsertedAt { index, .. }, None) =>
+                trace!(target: "cdl_list_rs", op = "insert_at", index, size),
+            (ListEvent::InsertedAt { index, value }, Some(fmt)) =>
+                trace!(target: "cdl_list_rs", op = "insert_at", index, size, value = ?Traced(*value, fmt)),
+            (ListEvent::RemovedAt { index, .. }, None) =>
+                trace!(target: "cdl_list_rs", op = "remove_at", index, size),
+            (ListEvent::RemovedAt { index, value }, Some(fmt)) =>
+                trace!(target: "cdl_list_rs", op = "remove_at", index, size, value = ?Traced(*value, fmt)),
+            (ListEvent::Cleared { len }, _) =>
+                trace!(target: "cdl_list_rs", op = "clear", len, size),
+        }
+    }
+
+    // Whether a subscriber would record this list's events.  Operations that 
+    // move whole chains check this before walking them to trace each element.
+    pub(super) fn trace_enabled(&self) -> bool {
+        tracing::enabled!(target: "cdl_list_rs", Level::TRACE)
+    }
+
+    pub(super) fn trace_splice(&self, start: usize, removed: usize, inserted: usize) {
+        trace!(target: "cdl_list_rs", op = "replace_range", start, removed, inserted, size = self.size);
+    }
+
+    pub(super) fn trace_rotate(&self, mid: usize) {
+        trace!(target: "cdl_list_rs", op = "rotate_left", mid, size = self.size);
+    }
+}
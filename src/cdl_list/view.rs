@@ -0,0 +1,123 @@
+//! Borrowed views of a contiguous range of a [`CdlList`].  A view remembers where 
+//! its range starts and how long it is, so it can be read, printed, and compared 
+//! without splitting the list or copying its elements.
+
+use std::{cell::RefCell, fmt::{self, Debug}, marker::PhantomData, ops::RangeBounds, rc::Rc};
+
+use super::{CdlList, ElemRef, Iter, Node};
+
+/// A read-only view of a range of a [`CdlList`], created by [`CdlList::view()`]. 
+/// The view borrows the list, so the list can't be changed until it is dropped.
+/// 
+/// ```rust
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let list = CdlList::from("a view");
+/// let view = list.view(2..).unwrap();
+/// 
+/// assert_eq!(view.len(), 4);
+/// assert_eq!(*view.get(0).unwrap().borrow(), 'v');
+/// assert!(view == &['v', 'i', 'e', 'w'][..]);
+/// ```
+/// 
+/// The list stays borrowed until the view is dropped, even if it isn't used 
+/// again, since it holds on to the ends of its range:
+/// 
+/// ```compile_fail
+/// # use cdl_list_rs::cdl_list::CdlList;
+/// let mut list = CdlList::from([1, 2, 3]);
+/// 
+/// let view = list.view(1..).unwrap();
+/// assert_eq!(view.len(), 2);
+/// list.pop_back();
+/// ```
+pub struct ListView<'a, T> {
+    start: Option<Rc<RefCell<Node<T>>>>,
+    end: Option<Rc<RefCell<Node<T>>>>,
+    len: usize,
+    _list: PhantomData<&'a CdlList<T>>
+}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for ListView<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<T> CdlList<T> {
+    /// Returns a view of the elements in `range`, or `None` if `range` is reversed 
+    /// or out of bounds.  Finding the start of the range walks the list from the 
+    /// head, but the view itself doesn't copy anything.  A view of the whole list 
+    /// prints and compares like the list itself.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::cdl_list::CdlList;
+    /// let mut list : CdlList<u32> = CdlList::new();
+    /// for i in 0..6 {
+    ///     list.push_back(i);
+    /// }
+    /// 
+    /// let view = list.view(1..4).unwrap();
+    /// assert_eq!(view.to_string(), "... <=> 1 <=> 2 <=> 3 <=> ...");
+    /// 
+    /// assert!(list.view(4..7).is_none());
+    /// ```
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> Option<ListView<'_, T>> {
+        let (start, end) = self.bounds_of(range)?;
+        let (start_node, end_node) = if start < end {
+            (self.node_at(start), self.node_at(end - 1))
+        } else {
+            (None, None)
+        };
+
+        Some(ListView { start: start_node, end: end_node, len: end - start, _list: PhantomData })
+    }
+}
+
+impl<'a, T> ListView<'a, T> {
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at `index` counting from the start of the view, or 
+    /// `None` if `index` is out of range.  This walks the view from its start.
+    pub fn get(&self, index: usize) -> Option<ElemRef<'a, T>> {
+        self.iter().nth(index)
+    }
+
+    /// Returns an iterator over the view's elements, in order.
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter::new(self.start.clone(), self.end.clone(), self.len)
+    }
+}
+
+impl<T: Debug> fmt::Display for ListView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "None");
+        }
+
+        write!(f, "... <=> ")?;
+        for elem in self.iter() {
+            write!(f, "{:?} <=> ", *elem.borrow())?;
+        }
+        write!(f, "...")
+    }
+}
+
+impl<T: Debug> Debug for ListView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elems : Vec<_> = self.iter().collect();
+        f.debug_list().entries(elems.iter().map(|elem| elem.borrow())).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for ListView<'_, T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.len == other.len() && self.iter().zip(other.iter()).all(|(elem, val)| *elem.borrow() == *val)
+    }
+}
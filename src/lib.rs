@@ -8,9 +8,11 @@
 //! Create a list using [`cdl_list::CdlList::new()`]: 
 //! 
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! use cdl_list_rs::cdl_list::CdlList;
 //! 
 //! let mut list : CdlList<u32> = CdlList::new();
+//! # }
 //! ```
 //! 
 //! The list must be mutable to add any elements to it.  Elements may be added to 
@@ -18,6 +20,7 @@
 //! [`cdl_list::CdlList::push_back()`].
 //! 
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! # use cdl_list_rs::cdl_list::CdlList;
 //! # let mut list : CdlList<u32> = CdlList::new();
 //! // Where A <══> B = A ⇄ B
@@ -29,25 +32,41 @@
 //! 
 //! list.push_front(3); // list = ╔══> 3 <══> 1 <══> 2 <══╗
 //!                     //        ╚═══════════════════════╝
+//! # }
+//! ```
+//! 
+//! To build a list from known elements in one step, use the [`cdl_list!`] macro, 
+//! which works like `vec!`:
+//! 
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use cdl_list_rs::cdl_list;
+//! 
+//! let list = cdl_list![3, 1, 2]; // list = ╔══> 3 <══> 1 <══> 2 <══╗
+//!                                //        ╚═══════════════════════╝
+//! assert_eq!(list.size(), 3);
+//! # }
 //! ```
 //! 
 //! Additionally, you may use [`cdl_list::CdlList::insert_at()`] 
 //! to insert an element into the list at a specific index.
 //! 
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! # use cdl_list_rs::cdl_list::CdlList;
 //! # let mut list : CdlList<u32> = CdlList::new();
 //! # list.push_front(1);
 //! # list.push_back(2);
 //! # list.push_front(3);
-//! list.insert_at(2, 4); // list = ╔══> 3 <══> 1 <══> 4 <══> 2 <══╗
-//!                       //        ╚══════════════════════════════╝
+//! list.insert_at(2, 4).unwrap(); // list = ╔══> 3 <══> 1 <══> 4 <══> 2 <══╗
+//!                                //        ╚══════════════════════════════╝
 //! 
 //! assert_eq!(list.size(), 4);
 //! assert_eq!(list.pop_back(), Some(2));
 //! assert_eq!(list.pop_back(), Some(4));
 //! assert_eq!(list.pop_back(), Some(1));
 //! assert_eq!(list.pop_back(), Some(3));
+//! # }
 //! ```
 //! 
 //! To see which item is at the head or tail of the list, use 
@@ -55,6 +74,7 @@
 //! creates a copy of the value and cannot modify the list's contents!
 //! 
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! # use cdl_list_rs::cdl_list::CdlList;
 //! # let mut list : CdlList<u32> = CdlList::new();
 //! list.push_front(1);
@@ -62,6 +82,7 @@
 //! list.push_front(3);
 //! let head_val = *list.peek_front().unwrap();        // head_val = 3
 //! let tail_val = list.peek_back().unwrap().clone();  // tail_val = 2
+//! # }
 //! ```
 //! 
 //! To remove an item from the list, you can currently use 
@@ -71,6 +92,7 @@
 //! peek, this returns `None` if the list is empty.
 //! 
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! # use cdl_list_rs::cdl_list::CdlList;
 //! # let mut list : CdlList<u32> = CdlList::new();
 //! # list.push_front(1);
@@ -90,12 +112,14 @@
 //!                              // list is empty
 //! 
 //! let empty = list.pop_back(); // empty = None
+//! # }
 //! ```
 //! 
 //! Additionally, you may use [`cdl_list::CdlList::remove_at()`] 
 //! to remove an element from the list at a specific index.
 //! 
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! # use cdl_list_rs::cdl_list::CdlList;
 //! # let mut list : CdlList<u32> = CdlList::new();
 //! list.push_front(1);
@@ -106,308 +130,5193 @@
 //! // Index: 0, 1, 2
 //! 
 //! assert_eq!(list.remove_at(1), Some(1));
+//! # }
+//! ```
+//! 
+//! ## Iterating
+//! 
+//! [`cdl_list::CdlList::iter()`] visits each element once, from head to tail, 
+//! without going around the ring forever.  Since elements live in `RefCell`s, it 
+//! yields [`cdl_list::ElemRef`]s, which are read with `borrow()`. 
+//! [`cdl_list::CdlList::iter_mut()`] yields [`cdl_list::ElemMut`]s instead, which 
+//! can also be changed with `borrow_mut()`.  Iterating over the list itself 
+//! consumes it and yields the elements by value.  All of them can also run 
+//! backwards with `rev()`.
+//! 
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! # use cdl_list_rs::cdl_list::CdlList;
+//! # let mut list : CdlList<u32> = CdlList::new();
+//! list.push_back(1);
+//! list.push_back(2);
+//! list.push_back(3);
+//! 
+//! let total : u32 = list.iter().map(|x| *x.borrow()).sum();
+//! assert_eq!(total, 6);
+//! 
+//! for mut x in list.iter_mut() {
+//!     *x.borrow_mut() *= 2;
+//! }
+//! 
+//! for x in list.into_iter().rev() {
+//!     println!("{}", x); // 6, 4, 2
+//! }
+//! # }
 //! ```
 //! 
+//! ## Fixed capacity
+//! 
+//! For targets without a heap, [`static_cdl_list::StaticCdlList`] offers the same 
+//! operations on a list of at most `N` elements stored inline.  Since it can fill 
+//! up, adding an element returns a `Result` that gives the element back on failure.
+//! 
+//! ```rust
+//! use cdl_list_rs::static_cdl_list::StaticCdlList;
+//! 
+//! let mut list : StaticCdlList<u32, 2> = StaticCdlList::new();
+//! list.push_back(1).unwrap();
+//! list.push_front(2).unwrap();
+//! assert_eq!(list.push_back(3), Err(3));
+//! 
+//! assert_eq!(list.pop_back(), Some(1));
+//! ```
+//! 
+//! ## Sharing between threads
+//! 
+//! A `CdlList` is built on `Rc` and `RefCell`, so it can't be sent to another thread. 
+//! [`sync_cdl_list::SyncCdlList`] has the same shape built on `Arc` and `RwLock`, and can 
+//! live behind an `Arc<Mutex<_>>` shared between threads.
+//! 
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use cdl_list_rs::sync_cdl_list::SyncCdlList;
+//! use std::thread;
+//! 
+//! let list : SyncCdlList<u32> = (1..=4).collect();
+//! let sum = thread::spawn(move || list.iter().map(|x| *x.read()).sum::<u32>());
+//! assert_eq!(sum.join().unwrap(), 10);
+//! # }
+//! ```
+//! 
+//! ## Optional features
+//! 
+//! The `std` feature is on by default and provides [`cdl_list`](mod@cdl_list) and 
+//! [`sync_cdl_list`].  Without it, the crate is `no_std` and only [`static_cdl_list`] 
+//! is available.
+//! 
+//! The following cargo features are off by default:
+//! 
+//! - `num`: numeric aggregates (`sum`, `product`, `checked_sum`, `mean`) built on 
+//!   [`num-traits`](https://crates.io/crates/num-traits). 
+//! - `arbitrary`: an `Arbitrary` implementation for structure-aware fuzzing with 
+//!   [`arbitrary`](https://crates.io/crates/arbitrary).  See `fuzz/` for an example target. 
+//! - `instrumentation`: per-list counters of pushes, pops, positional seeks, and nodes 
+//!   traversed, read with `stats()` and cleared with `reset_stats()`. 
+//! - `tracing`: `TRACE` level [`tracing`](https://crates.io/crates/tracing) events for every 
+//!   push, pop, insertion, removal, splice, and rotation.  Elements are only logged after 
+//!   opting in with `set_trace_values(true)`. 
+//! - `qcell`: [`token_cdl_list`], a list built on [`qcell`](https://crates.io/crates/qcell)'s 
+//!   `TLCell` whose borrows are checked at compile time against a token instead of 
+//!   by `RefCell` at runtime. 
+//! - `serde`: `Serialize` and `Deserialize` implementations for `CdlList` with 
+//!   [`serde`](https://crates.io/crates/serde), representing a list as a sequence of 
+//!   its elements from head to tail.  The elements are streamed, so binary formats 
+//!   like `bincode` don't need to buffer the list. 
+//! - `proptest`: `cdl_list_strategy()`, a [`proptest`](https://crates.io/crates/proptest) 
+//!   strategy for generating lists in property tests, which shrinks by removing 
+//!   elements.
+//! 
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "std")]
 pub mod cdl_list;
+pub mod static_cdl_list;
+#[cfg(feature = "qcell")]
+pub mod token_cdl_list;
+#[cfg(feature = "std")]
+pub mod sync_cdl_list;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub mod macros;
 
+// Behavioral tests shared by every list implementation.  Each instantiation 
+// provides a List<T> alias for the implementation under test, along with 
+// push_front, push_back and insert_at functions for the pushes and insertions 
+// that are expected to succeed.
 #[cfg(test)]
-mod tests {
-    use super::*;
-    use cdl_list::CdlList;
+macro_rules! backend_tests {
+    () => {
+        #[test]
+        fn test_cdl_empty() {
+            let list : List<u32> = List::new();
+            assert!(list.is_empty());
+        }
 
-    #[test]
-    fn test_cdl_empty() {
-        let list : CdlList<u32> = CdlList::new();
-        assert!(list.is_empty());
-    }
+        #[test]
+        fn test_one_push() {
+            let mut list : List<u32> = List::new();
+            push_front(&mut list, 3);
+            assert!(!list.is_empty());
 
-    #[test]
-    fn test_one_push() {
-        let mut list : CdlList<u32> = CdlList::new();
-        list.push_front(3);
-        assert!(!list.is_empty());
+            let mut list : List<u32> = List::new();
+            push_back(&mut list, 3);
+            assert!(!list.is_empty());
+        }
 
-        let mut list : CdlList<u32> = CdlList::new();
-        list.push_back(3);
-        assert!(!list.is_empty());
+        #[test]
+        fn test_empty_pop() {
+            let mut list : List<u32> = List::new();
+            assert_eq!(list.pop_front(), None);
+            assert_eq!(list.pop_back(), None);
+        }
+
+        #[test]
+        fn test_one_pop() {
+            let mut list : List<u32> = List::new();
+            push_front(&mut list, 42);
+            let val = list.pop_front();
+
+            assert_eq!(val, Some(42));
+            assert!(list.is_empty());
+        }
+
+        #[test]
+        fn test_pop_front() {
+            let mut list : List<u32> = List::new();
+            push_back(&mut list, 4);
+            push_back(&mut list, 8);
+            push_back(&mut list, 15);
+            push_back(&mut list, 16);
+            push_back(&mut list, 23);
+            push_back(&mut list, 42);
+
+            let val = list.pop_front();
+            assert_eq!(val, Some(4));
+            assert_eq!(list.size(), 5);
+
+            let val = list.pop_front();        
+            assert_eq!(val, Some(8));
+            assert_eq!(list.size(), 4);
+
+            let val = list.pop_front();        
+            assert_eq!(val, Some(15));
+            assert_eq!(list.size(), 3);
+
+            let val = list.pop_front();        
+            assert_eq!(val, Some(16));
+            assert_eq!(list.size(), 2);
+
+            let val = list.pop_front();        
+            assert_eq!(val, Some(23));
+            assert_eq!(list.size(), 1);
+
+            let val = list.pop_front();        
+            assert_eq!(val, Some(42));
+            assert!(list.is_empty());
+        }
+
+        #[test]
+        fn test_pop_back() {
+            let mut list : List<u32> = List::new();
+            push_front(&mut list, 4);
+            push_front(&mut list, 8);
+            push_front(&mut list, 15);
+            push_front(&mut list, 16);
+            push_front(&mut list, 23);
+            push_front(&mut list, 42);
+
+            let val = list.pop_back();        
+            assert_eq!(val, Some(4));
+            assert_eq!(list.size(), 5);
+
+            let val = list.pop_back();        
+            assert_eq!(val, Some(8));
+            assert_eq!(list.size(), 4);
+
+            let val = list.pop_back();        
+            assert_eq!(val, Some(15));
+            assert_eq!(list.size(), 3);
+
+            let val = list.pop_back();        
+            assert_eq!(val, Some(16));
+            assert_eq!(list.size(), 2);
+
+            let val = list.pop_back();        
+            assert_eq!(val, Some(23));
+            assert_eq!(list.size(), 1);
+
+            let val = list.pop_back();        
+            assert_eq!(val, Some(42));
+            assert_eq!(list.size(), 0);
+        }
+
+        #[test]
+        fn test_push_and_pop() {
+            // Alternate between pushing and popping from different ends of 
+            // the list to see if nodes are correctly linked after each call
+            let mut list : List<i32> = List::new();
+
+            push_front(&mut list, 1);
+            assert_eq!(list.pop_front(), Some(1));
+            assert!(list.pop_front().is_none());
+
+            push_back(&mut list, 2);
+            push_front(&mut list, 3);
+            push_back(&mut list, 4);
+
+            // List = 3 <=> 2 <=> 4
+            assert_eq!(list.pop_back(), Some(4));
+            assert_eq!(list.pop_front(), Some(3));
+
+            push_front(&mut list, 5);
+            push_back(&mut list, 6);
+            push_front(&mut list, 7);
+            push_back(&mut list, 8);
+            push_front(&mut list, 9);
+
+            // List = 9 <=> 7 <=> 5 <=> 2 <=> 6 <=> 8
+            assert_list_eq!(list, [9, 7, 5, 2, 6, 8]);
+            assert_list_invariants!(list);
+            assert_eq!(list.pop_back(), Some(8));
+            assert_eq!(list.pop_back(), Some(6));
+
+            assert_eq!(list.pop_front(), Some(9));
+            assert_eq!(list.pop_front(), Some(7));
+
+            assert_eq!(list.pop_back(), Some(2));
+            assert_eq!(list.pop_back(), Some(5));
+        }
+
+        #[test]
+        fn test_peek_empty() {
+            let list : List<i32> = List::new();
+
+            assert!(list.peek_front().is_none());
+            assert!(list.peek_back().is_none());
+        }
+
+        #[test]
+        fn test_peek_one() {
+            let mut list : List<i32> = List::new();
+            push_front(&mut list, 1);
+
+            let val = *list.peek_front().unwrap();
+            assert_eq!(val, 1);
+        }
+
+        #[test]
+        fn test_peek_front() {
+            let mut list : List<i32> = List::new();
+            push_front(&mut list, 3);
+            push_front(&mut list, 2);
+            push_front(&mut list, 1);
+
+            let val = *list.peek_front().unwrap();
+            assert_eq!(val, 1);
+        }
+
+        #[test]
+        fn test_peek_back() {
+            let mut list : List<i32> = List::new();
+            push_back(&mut list, 1);
+            push_back(&mut list, 2);
+            push_back(&mut list, 3);
+
+            let val = *list.peek_back().unwrap();
+            assert_eq!(val, 3);
+        }
+
+        #[test]
+        fn test_size() {
+            let mut list : List<u32> = List::new();
+            assert_eq!(list.size(), 0);
+
+            push_back(&mut list, 1);
+            assert_eq!(list.size(), 1);
+
+            push_back(&mut list, 2);
+            assert_eq!(list.size(), 2);
+
+            list.pop_back();
+            assert_eq!(list.size(), 1);
+        }
+
+        #[test]
+        fn test_insert_at() {
+            let mut list : List<u32> = List::new();
+            push_back(&mut list, 1);
+            push_back(&mut list, 2);
+            push_back(&mut list, 4);
+
+            insert_at(&mut list, 2, 3);
+
+            assert_eq!(list.size(), 4);
+            assert_eq!(list.pop_front().unwrap(), 1);
+            assert_eq!(list.pop_front().unwrap(), 2);
+            assert_eq!(list.pop_front().unwrap(), 3);
+            assert_eq!(list.pop_front().unwrap(), 4);
+
+            //test bounds
+            assert!(list.is_empty());
+            push_back(&mut list, 1);
+            push_back(&mut list, 2);
+            push_back(&mut list, 3);
+
+            insert_at(&mut list, 0, 0);
+            let len = list.size();
+            insert_at(&mut list, len, 4);
+
+            assert_eq!(list.pop_front().unwrap(), 0);
+            assert_eq!(list.pop_front().unwrap(), 1);
+            assert_eq!(list.pop_front().unwrap(), 2);
+            assert_eq!(list.pop_front().unwrap(), 3);
+            assert_eq!(list.pop_front().unwrap(), 4);
+            assert!(list.is_empty());
+
+            push_back(&mut list, 1);
+            assert_eq!(list.size(), 1);
+
+            // invalid additions
+            assert!(list.insert_at(list.size()+1, 2).is_err());
+            assert!(list.insert_at(list.size()+1, 3).is_err());
+            assert!(list.insert_at(list.size()+1, 4).is_err());
+            assert!(list.insert_at(list.size()+1, 5).is_err());
+
+            assert_eq!(list.size(), 1);
+            assert_eq!(list.pop_back(), Some(1));
+        }
+
+        #[test]
+        fn test_remove_at() {
+            let mut list : List<u32> = List::new();
+            assert!(list.remove_at(0).is_none());
+
+            push_back(&mut list, 1);
+            push_back(&mut list, 2);
+            push_back(&mut list, 3);
+            push_back(&mut list, 4);
+            push_back(&mut list, 5);
+            push_back(&mut list, 6);
+            push_back(&mut list, 7);
+            push_back(&mut list, 8);
+
+            assert_eq!(list.remove_at(list.size()-1), Some(8));
+            assert_eq!(list.remove_at(0), Some(1));
+            assert_eq!(list.remove_at(list.size()-1), Some(7));
+
+            // List: 2, 3, 4, 5, 6
+
+            assert_eq!(list.remove_at(2), Some(4));
+            assert_eq!(list.size(), 4);
+
+            // invalid removal
+            assert!(list.remove_at(list.size()).is_none());
+            assert!(list.remove_at(list.size()+1).is_none());
+        }
+
+        #[test]
+        fn test_insert_and_remove_at() {
+            let mut list : List<u32> = List::new();
+            assert!(list.remove_at(0).is_none());
+
+            //series of insertions and removals
+            push_back(&mut list, 1);
+            push_back(&mut list, 3);
+            push_back(&mut list, 5);
+            insert_at(&mut list, 0, 0);
+            insert_at(&mut list, 2, 2);
+            assert_eq!(list.remove_at(4), Some(5));
+            insert_at(&mut list, 4, 4);
+
+            //dummy insertions do not insert anything
+            assert!(list.insert_at(6, 6).is_err());
+            assert!(list.insert_at(7, 7).is_err());
+
+            assert_eq!(list.size(), 5);
+            assert_list_eq!(list, [0, 1, 2, 3, 4]);
+            assert_list_invariants!(list);
+
+            assert_eq!(list.remove_at(1), Some(1));
+            assert_eq!(list.remove_at(2), Some(3));
+            assert_eq!(list.remove_at(1), Some(2));
+            assert_eq!(list.remove_at(1), Some(4));
+            assert_eq!(list.remove_at(0), Some(0));
+
+            assert!(list.is_empty());
+        }
+    };
+}
+
+// Runs the shared tests against StaticCdlList.  Unlike the other backends, this 
+// also runs without the std feature, where the crate is built as no_std.
+#[cfg(test)]
+mod static_backend {
+    use crate::static_cdl_list::StaticCdlList;
+    #[cfg(feature = "std")]
+    use crate::{assert_list_eq, assert_list_invariants};
+
+    type List<T> = StaticCdlList<T, 16>;
+
+    // No test list comes close to the capacity, so every push succeeds.
+    fn push_front<T>(list: &mut List<T>, t: T) {
+        assert!(list.push_front(t).is_ok());
     }
 
-    #[test]
-    fn test_empty_pop() {
-        let mut list : CdlList<u32> = CdlList::new();
-        assert_eq!(list.pop_front(), None);
-        assert_eq!(list.pop_back(), None);
+    fn push_back<T>(list: &mut List<T>, t: T) {
+        assert!(list.push_back(t).is_ok());
     }
 
-    #[test]
-    fn test_one_pop() {
-        let mut list : CdlList<u32> = CdlList::new();
-        list.push_front(42);
-        let val = list.pop_front();
+    fn insert_at<T>(list: &mut List<T>, index: usize, val: T) {
+        assert!(list.insert_at(index, val).is_ok());
+    }
 
-        assert_eq!(val, Some(42));
-        assert!(list.is_empty());
+    // The assertion macros need std, so without it the elements are compared 
+    // one at a time instead.
+    #[cfg(not(feature = "std"))]
+    macro_rules! assert_list_eq {
+        ($list:expr, $expected:expr) => {{
+            let expected = $expected;
+            assert_eq!($list.size(), expected.len());
+            for (i, val) in expected.iter().enumerate() {
+                assert_eq!($list.get(i), Some(val), "index {}", i);
+            }
+        }};
     }
 
-    #[test]
-    fn test_pop_front() {
-        let mut list : CdlList<u32> = CdlList::new();
-        list.push_back(4);
-        list.push_back(8);
-        list.push_back(15);
-        list.push_back(16);
-        list.push_back(23);
-        list.push_back(42);
+    #[cfg(not(feature = "std"))]
+    macro_rules! assert_list_invariants {
+        ($list:expr) => {
+            assert_eq!($list.validate(), Ok(()));
+        };
+    }
 
-        let val = list.pop_front();
-        assert_eq!(val, Some(4));
-        assert_eq!(list.size(), 5);
+    backend_tests!();
+}
 
-        let val = list.pop_front();        
-        assert_eq!(val, Some(8));
-        assert_eq!(list.size(), 4);
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use cdl_list::CdlList;
+    use static_cdl_list::StaticCdlList;
+    use std::panic;
 
-        let val = list.pop_front();        
-        assert_eq!(val, Some(15));
-        assert_eq!(list.size(), 3);
+    mod cdl_list_backend {
+        use super::*;
 
-        let val = list.pop_front();        
-        assert_eq!(val, Some(16));
-        assert_eq!(list.size(), 2);
+        type List<T> = CdlList<T>;
 
-        let val = list.pop_front();        
-        assert_eq!(val, Some(23));
-        assert_eq!(list.size(), 1);
+        fn push_front<T>(list: &mut List<T>, t: T) {
+            list.push_front(t);
+        }
 
-        let val = list.pop_front();        
-        assert_eq!(val, Some(42));
-        assert!(list.is_empty());
+        fn push_back<T>(list: &mut List<T>, t: T) {
+            list.push_back(t);
+        }
+
+        fn insert_at<T>(list: &mut List<T>, index: usize, val: T) {
+            list.insert_at(index, val).unwrap();
+        }
+
+        backend_tests!();
     }
 
-    #[test]
-    fn test_pop_back() {
-        let mut list : CdlList<u32> = CdlList::new();
-        list.push_front(4);
-        list.push_front(8);
-        list.push_front(15);
-        list.push_front(16);
-        list.push_front(23);
-        list.push_front(42);
-
-        let val = list.pop_back();        
-        assert_eq!(val, Some(4));
-        assert_eq!(list.size(), 5);
+    // Runs the shared tests through a wrapper that supplies the token to every 
+    // call.  Each call creates the thread's only token for Marker and drops it 
+    // again, so any number of wrapped lists can be alive at once.
+    #[cfg(feature = "qcell")]
+    mod token_backend {
+        use super::*;
+        use crate::{cdl_list::IndexOutOfBounds, macros::ListElements, token_cdl_list::{TLCellOwner, TokenCdlList}};
 
-        let val = list.pop_back();        
-        assert_eq!(val, Some(8));
-        assert_eq!(list.size(), 4);
+        struct Marker;
 
-        let val = list.pop_back();        
-        assert_eq!(val, Some(15));
-        assert_eq!(list.size(), 3);
+        struct List<T> {
+            list: TokenCdlList<Marker, T>
+        }
 
-        let val = list.pop_back();        
-        assert_eq!(val, Some(16));
-        assert_eq!(list.size(), 2);
+        // Elements are copied out, since a borrow can't outlive its call's token.
+        struct Peeked<T>(T);
 
-        let val = list.pop_back();        
-        assert_eq!(val, Some(23));
-        assert_eq!(list.size(), 1);
+        impl<T> std::ops::Deref for Peeked<T> {
+            type Target = T;
 
-        let val = list.pop_back();        
-        assert_eq!(val, Some(42));
-        assert_eq!(list.size(), 0);
-    }
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
 
-    #[test]
-    fn test_push_and_pop() {
-        // Alternate between pushing and popping from different ends of 
-        // the list to see if nodes are correctly linked after each call
-        let mut list : CdlList<i32> = CdlList::new();
+        impl<T: Clone> List<T> {
+            fn new() -> Self {
+                List { list: TokenCdlList::new() }
+            }
 
-        list.push_front(1);
-        assert_eq!(list.pop_front(), Some(1));
-        assert!(list.pop_front().is_none());
+            fn is_empty(&self) -> bool {
+                self.list.is_empty()
+            }
 
-        list.push_back(2);
-        list.push_front(3);
-        list.push_back(4);
+            fn size(&self) -> usize {
+                self.list.size()
+            }
 
-        // List = 3 <=> 2 <=> 4
-        assert_eq!(list.pop_back(), Some(4));
-        assert_eq!(list.pop_front(), Some(3));
-        
-        list.push_front(5);
-        list.push_back(6);
-        list.push_front(7);
-        list.push_back(8);
-        list.push_front(9);
+            fn push_front(&mut self, t: T) {
+                self.list.push_front(t, &mut TLCellOwner::new());
+            }
 
-        // List = 9 <=> 7 <=> 5 <=> 2 <=> 6 <=> 8
-        assert_eq!(list.pop_back(), Some(8));
-        assert_eq!(list.pop_back(), Some(6));
+            fn push_back(&mut self, t: T) {
+                self.list.push_back(t, &mut TLCellOwner::new());
+            }
 
-        assert_eq!(list.pop_front(), Some(9));
-        assert_eq!(list.pop_front(), Some(7));
+            fn pop_front(&mut self) -> Option<T> {
+                self.list.pop_front(&mut TLCellOwner::new())
+            }
 
-        assert_eq!(list.pop_back(), Some(2));
-        assert_eq!(list.pop_back(), Some(5));
-    }
+            fn pop_back(&mut self) -> Option<T> {
+                self.list.pop_back(&mut TLCellOwner::new())
+            }
 
-    #[test]
-    fn test_peek_empty() {
-        let list : CdlList<i32> = CdlList::new();
+            fn peek_front(&self) -> Option<Peeked<T>> {
+                self.list.peek_front(&TLCellOwner::new()).cloned().map(Peeked)
+            }
 
-        assert!(list.peek_front().is_none());
-        assert!(list.peek_back().is_none());
-    }
+            fn peek_back(&self) -> Option<Peeked<T>> {
+                self.list.peek_back(&TLCellOwner::new()).cloned().map(Peeked)
+            }
 
-    #[test]
-    fn test_peek_one() {
-        let mut list : CdlList<i32> = CdlList::new();
-        list.push_front(1);
+            fn insert_at(&mut self, index: usize, val: T) -> Result<(), IndexOutOfBounds> {
+                self.list.insert_at(index, val, &mut TLCellOwner::new())
+            }
 
-        let val = *list.peek_front().unwrap();
-        assert_eq!(val, 1);
-    }
+            fn remove_at(&mut self, index: usize) -> Option<T> {
+                self.list.remove_at(index, &mut TLCellOwner::new())
+            }
 
-    #[test]
-    fn test_peek_front() {
-        let mut list : CdlList<i32> = CdlList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+            fn validate(&self) -> Result<(), String> {
+                self.list.validate(&TLCellOwner::new())
+            }
+        }
+
+        fn push_front<T: Clone>(list: &mut List<T>, t: T) {
+            list.push_front(t);
+        }
+
+        fn push_back<T: Clone>(list: &mut List<T>, t: T) {
+            list.push_back(t);
+        }
+
+        fn insert_at<T: Clone>(list: &mut List<T>, index: usize, val: T) {
+            list.insert_at(index, val).unwrap();
+        }
+
+        impl<T> ListElements<T> for List<T> {
+            fn with_elements(&self, f: &mut dyn FnMut(&[&T])) {
+                let owner = TLCellOwner::new();
+                let refs : Vec<&T> = self.list.iter(&owner).collect();
+                f(&refs);
+            }
+        }
+
+        backend_tests!();
+
+        #[test]
+        fn test_token_list() {
+            let mut token = TLCellOwner::<Marker>::new();
+            let mut list : TokenCdlList<Marker, String> = TokenCdlList::new();
+            assert!(list.get(0, &token).is_none());
+            assert!(list.peek_front_mut(&mut token).is_none());
+
+            for s in ["b", "c", "e"] {
+                list.push_back(String::from(s), &mut token);
+            }
+            list.push_front(String::from("a"), &mut token);
+            list.insert_at(3, String::from("d"), &mut token).unwrap();
+            assert_eq!(list.insert_at(6, String::from("f"), &mut token), Err(IndexOutOfBounds { index: 6, size: 5 }));
+            assert!(list.validate(&token).is_ok());
 
-        let val = *list.peek_front().unwrap();
-        assert_eq!(val, 1);
+            // plain references to different elements can be held together
+            let (first, last) = (list.get(0, &token).unwrap(), list.get(4, &token).unwrap());
+            assert_eq!((first.as_str(), last.as_str()), ("a", "e"));
+            assert!(list.get(5, &token).is_none());
+            assert_eq!(list.iter(&token).len(), 5);
+
+            list.peek_front_mut(&mut token).unwrap().push('!');
+            list.peek_back_mut(&mut token).unwrap().push('?');
+            let all : Vec<&str> = list.iter(&token).map(String::as_str).collect();
+            assert_eq!(all, ["a!", "b", "c", "d", "e?"]);
+
+            assert_eq!(list.remove_at(2, &mut token).as_deref(), Some("c"));
+            assert_eq!(list.pop_front(&mut token).as_deref(), Some("a!"));
+            assert_eq!(list.pop_back(&mut token).as_deref(), Some("e?"));
+            assert!(list.validate(&token).is_ok());
+            assert_eq!(format!("{:?}", list), "TokenCdlList { size: 2, .. }");
+
+            // one token serves every list with the same marker
+            let mut other : TokenCdlList<Marker, String> = TokenCdlList::new();
+            other.push_back(list.pop_back(&mut token).unwrap(), &mut token);
+            assert_eq!(other.peek_front(&token).map(String::as_str), Some("d"));
+            assert_eq!(list.peek_back(&token).map(String::as_str), Some("b"));
+        }
+
+        #[test]
+        fn test_token_list_drop() {
+            // dropping a long list doesn't recurse once per node
+            let mut token = TLCellOwner::<Marker>::new();
+            let mut list : TokenCdlList<Marker, u32> = TokenCdlList::new();
+            for i in 0..200_000 {
+                list.push_back(i, &mut token);
+            }
+            drop(list);
+
+            let rc = std::rc::Rc::new(());
+            let mut list : TokenCdlList<Marker, std::rc::Rc<()>> = TokenCdlList::new();
+            for _ in 0..3 {
+                list.push_front(std::rc::Rc::clone(&rc), &mut token);
+            }
+            list.remove_at(1, &mut token);
+            assert_eq!(std::rc::Rc::strong_count(&rc), 3);
+            drop(list);
+            assert_eq!(std::rc::Rc::strong_count(&rc), 1);
+        }
     }
 
-    #[test]
-    fn test_peek_back() {
-        let mut list : CdlList<i32> = CdlList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
+    mod sync_backend {
+        use super::*;
+        use crate::sync_cdl_list::SyncCdlList;
+        use std::{sync::{Arc, Mutex, RwLock}, thread};
+
+        type List<T> = SyncCdlList<T>;
+
+        fn push_front<T>(list: &mut List<T>, t: T) {
+            list.push_front(t);
+        }
+
+        fn push_back<T>(list: &mut List<T>, t: T) {
+            list.push_back(t);
+        }
+
+        fn insert_at<T>(list: &mut List<T>, index: usize, val: T) {
+            list.insert_at(index, val).unwrap();
+        }
+
+        backend_tests!();
+
+        #[test]
+        fn test_sync_list() {
+            let mut list : SyncCdlList<String> = ["b", "c", "e"].into_iter().map(String::from).collect();
+            list.push_front(String::from("a"));
+            list.insert_at(3, String::from("d")).unwrap();
+            assert!(list.insert_at(6, String::from("z")).is_err());
+            assert_list_eq!(list, ["a", "b", "c", "d", "e"]);
+            assert_list_invariants!(list);
+
+            list.peek_front_mut().unwrap().push('!');
+            list.get_mut(4).unwrap().write().push('?');
+            assert_eq!(*list.get(0).unwrap().read(), "a!");
+            assert!(list.get(5).is_none());
+            assert_eq!(format!("{:?}", list), r#"["a!", "b", "c", "d", "e?"]"#);
+
+            list.rotate_left(2);
+            assert_list_eq!(list, ["c", "d", "e?", "a!", "b"]);
+            list.rotate_right(7);
+            assert_list_eq!(list, ["a!", "b", "c", "d", "e?"]);
+            assert_list_invariants!(list);
+
+            let back : Vec<String> = list.into_iter().rev().collect();
+            assert_eq!(back, ["e?", "d", "c", "b", "a!"]);
+        }
+
+        #[test]
+        fn test_sync_list_across_threads() {
+            fn assert_send_sync<T: Send + Sync>() {}
+            assert_send_sync::<SyncCdlList<u32>>();
+
+            // several readers share one list
+            let list : SyncCdlList<u32> = (0..100).collect();
+            let sums : Vec<u32> = thread::scope(|s| {
+                let readers : Vec<_> = (0..4)
+                    .map(|_| s.spawn(|| list.iter().map(|x| *x.read()).sum()))
+                    .collect();
+                readers.into_iter().map(|r| r.join().unwrap()).collect()
+            });
+            assert_eq!(sums, [4950; 4]);
+
+            // writers take turns through a mutex
+            let shared = Arc::new(Mutex::new(SyncCdlList::new()));
+            let writers : Vec<_> = (0..4).map(|i| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    for j in 0..100 {
+                        let mut list = shared.lock().unwrap();
+                        if j % 2 == 0 {
+                            list.push_back(i * 100 + j);
+                        } else {
+                            list.push_front(i * 100 + j);
+                        }
+                    }
+                })
+            }).collect();
+            for writer in writers {
+                writer.join().unwrap();
+            }
+
+            let list = Arc::try_unwrap(shared).unwrap().into_inner().unwrap();
+            assert_list_invariants!(list);
+            let mut all : Vec<u32> = list.into_iter().collect();
+            all.sort();
+            assert_eq!(all, (0..400).collect::<Vec<_>>());
+
+            // a list can also be moved to another thread behind a RwLock
+            let shared = Arc::new(RwLock::new(SyncCdlList::from_iter(0..10u32)));
+            let reader = Arc::clone(&shared);
+            let total = thread::spawn(move || reader.read().unwrap().iter().map(|x| *x.read()).sum::<u32>());
+            shared.write().unwrap().push_back(10);
+            let total = total.join().unwrap();
+            assert!(total == 45 || total == 55);
+        }
 
-        let val = *list.peek_back().unwrap();
-        assert_eq!(val, 3);
+        #[test]
+        fn test_sync_list_drops_every_node() {
+            let rc = Arc::new(());
+            let mut list = SyncCdlList::new();
+            for _ in 0..4 {
+                list.push_back(Arc::clone(&rc));
+            }
+            list.remove_at(1);
+            list.rotate_left(1);
+            assert_eq!(Arc::strong_count(&rc), 4);
+            drop(list);
+            assert_eq!(Arc::strong_count(&rc), 1);
+
+            // long enough to overflow the stack if nodes dropped recursively
+            let list : SyncCdlList<u32> = (0..1_000_000).collect();
+            drop(list);
+        }
     }
 
     #[test]
-    fn test_size() {
+    fn test_select_nth() {
         let mut list : CdlList<u32> = CdlList::new();
-        assert_eq!(list.size(), 0);
-        
+        assert!(list.select_nth(0).is_none());
+        assert!(list.median().is_none());
+
+        list.push_back(3);
+        list.push_back(3);
         list.push_back(1);
-        assert_eq!(list.size(), 1);
-        
         list.push_back(2);
-        assert_eq!(list.size(), 2);
-        
-        list.pop_back();
-        assert_eq!(list.size(), 1);
+
+        assert_eq!(list.select_nth(0), Some(1));
+        assert_eq!(list.select_nth(1), Some(2));
+        assert_eq!(list.select_nth(2), Some(3));
+        assert_eq!(list.select_nth(3), Some(3));
+        assert!(list.select_nth(4).is_none());
+        assert_eq!(list.median(), Some(2));
+
+        // list is left as it was
+        assert_eq!(list.size(), 4);
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
     }
 
     #[test]
-    fn test_insert_at() {
-        let mut list : CdlList<u32> = CdlList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(4);
+    fn test_select_nth_random() {
+        // small LCG so the test is reproducible without extra dependencies
+        let mut state : u64 = 42;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as u32
+        };
 
-        list.insert_at(2, 3);
+        for size in 1..40 {
+            let mut list : CdlList<u32> = CdlList::new();
+            let mut values = Vec::new();
+            for _ in 0..size {
+                let v = next() % 20;
+                list.push_back(v);
+                values.push(v);
+            }
+            values.sort();
 
-        assert_eq!(list.size(), 4);
-        assert_eq!(list.pop_front().unwrap(), 1);
-        assert_eq!(list.pop_front().unwrap(), 2);
-        assert_eq!(list.pop_front().unwrap(), 3);
-        assert_eq!(list.pop_front().unwrap(), 4);
+            for (k, v) in values.iter().enumerate() {
+                assert_eq!(list.select_nth(k), Some(*v));
+            }
+            assert_eq!(list.median(), Some(values[(size - 1) / 2]));
+            assert!(list.select_nth(size).is_none());
+        }
+    }
 
-        //test bounds
-        assert!(list.is_empty());
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
+    // drains a list front to back so its contents can be compared
+    fn drain(list: &mut CdlList<u32>) -> Vec<u32> {
+        let mut v = Vec::new();
+        while let Some(x) = list.pop_front() {
+            v.push(x);
+        }
+        v
+    }
 
-        list.insert_at(0, 0);
-        list.insert_at(list.size(), 4);
+    #[test]
+    fn test_replace_range() {
+        let mut list : CdlList<u32> = CdlList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
 
-        assert_eq!(list.pop_front().unwrap(), 0);
-        assert_eq!(list.pop_front().unwrap(), 1);
-        assert_eq!(list.pop_front().unwrap(), 2);
-        assert_eq!(list.pop_front().unwrap(), 3);
-        assert_eq!(list.pop_front().unwrap(), 4);
-        assert!(list.is_empty());
+        // longer replacement
+        let removed = list.replace_range(2..4, vec![10, 11, 12]).unwrap();
+        assert_list_eq!(removed, [2, 3]);
+        assert_eq!(list.size(), 7);
 
-        list.push_back(1);
-        assert_eq!(list.size(), 1);
+        // shorter replacement touching the head
+        let removed = list.replace_range(..2, vec![20]).unwrap();
+        assert_list_eq!(removed, [0, 1]);
 
-        // invalid additions
-        list.insert_at(list.size()+1, 2);
-        list.insert_at(list.size()+1, 3);
-        list.insert_at(list.size()+1, 4);
-        list.insert_at(list.size()+1, 5);
+        // pure removal touching the tail
+        let removed = list.replace_range(4.., vec![]).unwrap();
+        assert_list_eq!(removed, [4, 5]);
 
-        assert_eq!(list.size(), 1);
-        assert_eq!(list.pop_back(), Some(1));
+        // pure insertion at the end
+        let mut removed = list.replace_range(4..4, vec![30, 31]).unwrap();
+        assert!(removed.is_empty());
+        assert_eq!(removed.pop_front(), None);
+
+        assert_eq!(list.size(), 6);
+        assert_list_eq!(list, [20, 10, 11, 12, 30, 31]);
+        assert_list_invariants!(list);
     }
 
     #[test]
-    fn test_remove_at() {
+    fn test_replace_range_whole_and_invalid() {
         let mut list : CdlList<u32> = CdlList::new();
-        assert!(list.remove_at(0).is_none());
+        assert!(list.replace_range(.., vec![1, 2]).unwrap().is_empty());
+        assert_eq!(list.size(), 2);
+
+        let removed = list.replace_range(0..=1, vec![]).unwrap();
+        assert!(list.is_empty());
+        assert_list_eq!(removed, [1, 2]);
 
         list.push_back(1);
         list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
-        list.push_back(5);
-        list.push_back(6);
-        list.push_back(7);
-        list.push_back(8);
 
-        assert_eq!(list.remove_at(list.size()-1), Some(8));
-        assert_eq!(list.remove_at(0), Some(1));
-        assert_eq!(list.remove_at(list.size()-1), Some(7));
+        // out of bounds or decreasing ranges leave the list untouched
+        assert!(list.replace_range(1..3, vec![9]).is_none());
+        assert!(list.replace_range(3..3, vec![9]).is_none());
+        #[allow(clippy::reversed_empty_ranges)]
+        let bad = list.replace_range(2..1, vec![9]);
+        assert!(bad.is_none());
 
-        // List: 2, 3, 4, 5, 6
+        assert_list_eq!(list, [1, 2]);
+        assert_list_invariants!(list);
 
-        assert_eq!(list.remove_at(2), Some(4));
-        assert_eq!(list.size(), 4);
+        list.push_front(4);
+        list.push_back(5);
+        assert_list_eq!(list, [4, 1, 2, 5]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_get_from_back() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert!(list.get_from_back(0).is_none());
 
-        // invalid removal
-        assert!(list.remove_at(list.size()).is_none());
-        assert!(list.remove_at(list.size()+1).is_none());
+        for i in 1..=5 {
+            list.push_back(i);
+        }
+
+        assert_eq!(*list.get_from_back(0).unwrap().borrow(), *list.peek_back().unwrap());
+        assert_eq!(*list.get_from_back(4).unwrap().borrow(), *list.peek_front().unwrap());
+        for i in 0..5 {
+            assert_eq!(*list.get_from_back(i).unwrap().borrow(), 5 - i as u32);
+        }
+        assert!(list.get_from_back(5).is_none());
     }
 
     #[test]
-    fn test_insert_and_remove_at() {
+    fn test_insert_and_remove_at_back() {
+        use cdl_list::IndexOutOfBounds;
+
         let mut list : CdlList<u32> = CdlList::new();
-        assert!(list.remove_at(0).is_none());
+        assert_eq!(list.insert_at_back(1, 9), Err(IndexOutOfBounds { index: 1, size: 0 }));
+        list.insert_at_back(0, 2).unwrap();
+        list.insert_at_back(1, 1).unwrap();
+        list.insert_at_back(0, 4).unwrap();
+        list.insert_at_back(1, 3).unwrap();
+        list.insert_at_back(list.size(), 0).unwrap();
 
-        //series of insertions and removals
-        list.push_back(1);
-        list.push_back(3);
-        list.push_back(5);
-        list.insert_at(0, 0);
-        list.insert_at(2, 2);
-        assert_eq!(list.remove_at(4), Some(5));
-        list.insert_at(4, 4);
-        
-        //dummy insertions do not insert anything
-        list.insert_at(6, 6);
-        list.insert_at(7, 7);
+        // invalid insertions report the index and do nothing
+        assert_eq!(list.insert_at_back(6, 9), Err(IndexOutOfBounds { index: 6, size: 5 }));
+        assert_eq!(list.size(), 5);
+        assert_list_eq!(list, [0, 1, 2, 3, 4]);
 
+        assert_eq!(list.remove_at_back(5), None);
+        assert_eq!(list.try_remove_at_back(5), Err(IndexOutOfBounds { index: 5, size: 5 }));
+        assert_eq!(list.try_remove_at_back(usize::MAX), Err(IndexOutOfBounds { index: usize::MAX, size: 5 }));
         assert_eq!(list.size(), 5);
+        assert_eq!(list.remove_at_back(2), Some(2));
+        assert_eq!(list.remove_at_back(0), Some(4));
+        assert_eq!(list.try_remove_at_back(2), Ok(0));
+        assert_list_eq!(list, [1, 3]);
+        assert_list_invariants!(list);
 
-        assert_eq!(list.remove_at(1), Some(1));
-        assert_eq!(list.remove_at(2), Some(3));
-        assert_eq!(list.remove_at(1), Some(2));
-        assert_eq!(list.remove_at(1), Some(4));
-        assert_eq!(list.remove_at(0), Some(0));
+        let mut empty : CdlList<u32> = CdlList::new();
+        assert_eq!(empty.try_remove_at_back(0), Err(IndexOutOfBounds { index: 0, size: 0 }));
+    }
 
-        assert!(list.is_empty());
+    #[test]
+    fn test_back_matches_forward() {
+        // each back-relative call must agree with the equivalent forward call
+        for index in 0..=6 {
+            let mut forward : CdlList<u32> = CdlList::new();
+            let mut backward : CdlList<u32> = CdlList::new();
+            for i in 0..6 {
+                forward.push_back(i);
+                backward.push_back(i);
+            }
+
+            forward.insert_at(6 - index, 100).unwrap();
+            backward.insert_at_back(index, 100).unwrap();
+            assert_eq!(*backward.get_from_back(index).unwrap().borrow(), 100);
+
+            let at = 6 - index;
+            assert_eq!(forward.remove_at(at), backward.remove_at_back(index));
+            assert_eq!(forward.remove_at(0), backward.remove_at_back(backward.size() - 1));
+            assert_eq!(drain(&mut forward), drain(&mut backward));
+        }
+    }
+
+    #[test]
+    fn test_push_closes_ring() {
+        // tail->next and head->prev must follow each push around the circle, 
+        // or they are left pointing at a node that may since have been removed
+        let mut list : CdlList<char> = CdlList::new();
+        list.push_back('b');
+        list.push_back('c');
+        list.push_front('a');
+        assert_eq!(list.remove_at(1), Some('b'));
+        assert_eq!(list.to_string_chars(), "ac");
+
+        let mut list : CdlList<char> = CdlList::new();
+        list.push_front('b');
+        list.push_front('a');
+        list.push_back('c');
+        assert_eq!(list.remove_at(1), Some('b'));
+        assert_eq!(list.to_string_chars(), "ac");
+        assert_eq!(list.pop_back(), Some('c'));
+        assert_eq!(list.pop_back(), Some('a'));
+    }
+
+    #[test]
+    fn test_string_chars() {
+        let list = CdlList::from("");
+        assert!(list.is_empty());
+        assert_eq!(list.to_string_chars(), "");
+
+        let mut list = CdlList::from("añ🦀b");
+        assert_eq!(list.size(), 4);
+        assert_eq!(list.to_string_chars(), "añ🦀b");
+
+        assert_eq!(list.pop_back(), Some('b'));
+        list.push_front('z');
+        assert_eq!(list.to_string_chars(), "zañ🦀");
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_num_helpers() {
+        let mut list : CdlList<i32> = CdlList::new();
+        assert_eq!(list.sum(), 0);
+        assert_eq!(list.product(), 1);
+        assert_eq!(list.checked_sum(), Some(0));
+        assert_eq!(list.mean(), None);
+
+        list.push_back(-4);
+        list.push_back(10);
+        list.push_back(3);
+        assert_eq!(list.sum(), 9);
+        assert_eq!(list.product(), -120);
+        assert_eq!(list.checked_sum(), Some(9));
+        assert_eq!(list.mean(), Some(3.0));
+
+        list.push_back(i32::MAX);
+        assert_eq!(list.checked_sum(), None);
+
+        // overflow is checked at each step, in order
+        let mut list : CdlList<i32> = CdlList::new();
+        list.push_back(i32::MAX);
+        list.push_back(i32::MIN);
+        list.push_back(i32::MAX);
+        assert_eq!(list.checked_sum(), Some(-1 + i32::MAX));
+
+        let mut floats : CdlList<f64> = CdlList::new();
+        floats.push_back(0.5);
+        floats.push_back(1.5);
+        floats.push_back(4.0);
+        assert_eq!(floats.sum(), 6.0);
+        assert_eq!(floats.product(), 3.0);
+        assert_eq!(floats.mean(), Some(2.0));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[]);
+        let list = CdlList::<u8>::arbitrary(&mut u).unwrap();
+        assert!(list.is_empty());
+
+        // taking the rest of the input agrees with the std collections
+        let bytes = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+        let mut list = CdlList::<u8>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        let expected = Vec::<u8>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        assert_eq!(list.size(), expected.len());
+        for b in expected {
+            assert_eq!(list.pop_front(), Some(b));
+        }
+
+        // lengths never exceed what the remaining input can supply
+        let bytes : Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&bytes);
+        let list = CdlList::<u32>::arbitrary(&mut u).unwrap();
+        assert!(list.size() <= bytes.len() / 4);
+
+        assert_eq!(CdlList::<u8>::size_hint(0), (0, None));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let empty : CdlList<String> = CdlList::new();
+        assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+        let back : CdlList<String> = serde_json::from_str("[]").unwrap();
+        assert!(back.is_empty());
+        assert_list_invariants!(back);
+
+        let one = cdl_list![String::from("a")];
+        let json = serde_json::to_string(&one).unwrap();
+        assert_eq!(json, r#"["a"]"#);
+        let back : CdlList<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, one);
+        assert_list_invariants!(back);
+
+        // the head is wherever rotation left it, not where it was pushed
+        let mut list : CdlList<String> = ["x", "y", "z", "w"].into_iter().map(String::from).collect();
+        list.rotate_left(1);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, r#"["y","z","w","x"]"#);
+        let back : CdlList<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.size(), 4);
+        assert_eq!(back, list);
+        assert_list_invariants!(back);
+
+        // interchangeable with a Vec, including nested
+        let nested : CdlList<CdlList<u32>> = serde_json::from_str("[[1, 2], [], [3]]").unwrap();
+        assert_eq!(serde_json::to_string(&nested).unwrap(), "[[1,2],[],[3]]");
+        let as_vec : Vec<Vec<u32>> = serde_json::from_str(&serde_json::to_string(&nested).unwrap()).unwrap();
+        assert_eq!(as_vec, [vec![1, 2], vec![], vec![3]]);
+
+        assert!(serde_json::from_str::<CdlList<u32>>("{}").is_err());
+        assert!(serde_json::from_str::<CdlList<u32>>("[1, \"two\"]").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary() {
+        use std::io::{self, Cursor, Write};
+
+        // bincode lays integers and strings out the same way write_to does
+        let mut words = cdl_list![String::from("ring"), String::new(), String::from("é")];
+        words.rotate_left(1);
+        let mut bytes = Vec::new();
+        words.write_to(&mut bytes).unwrap();
+        assert_eq!(bincode::serialize(&words).unwrap(), bytes);
+        let back : CdlList<String> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back, words);
+        assert_list_invariants!(back);
+        assert_eq!(CdlList::<String>::read_from(&mut Cursor::new(&bytes)).unwrap(), words);
+
+        // elements are written straight to the writer as the list is walked
+        struct Counter(usize);
+        impl Write for Counter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0 += buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let n = 1_000_000;
+        let big : CdlList<u32> = (0..n).collect();
+        let mut counter = Counter(0);
+        bincode::serialize_into(&mut counter, &big).unwrap();
+        assert_eq!(counter.0, 8 + 4 * n as usize);
+
+        let mut bytes = Vec::new();
+        big.write_to(&mut bytes).unwrap();
+        let back : CdlList<u32> = bincode::deserialize_from(Cursor::new(&bytes)).unwrap();
+        assert_eq!(back, big);
+
+        // a truncated stream is an error rather than a shorter list
+        assert!(bincode::deserialize::<CdlList<u32>>(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn prop_cdl_list_strategy(list in cdl_list::cdl_list_strategy(0u8..10, 2..8)) {
+            proptest::prop_assert!((2..8).contains(&list.size()));
+            proptest::prop_assert!(list.validate().is_ok());
+            proptest::prop_assert!(list.iter().all(|x| *x.borrow() < 10));
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn test_cdl_list_strategy_shrinks() {
+        use proptest::{test_runner::{TestError, TestRunner}, prelude::any};
+
+        // a list holding anything over 100 fails, and should shrink to just that element
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&cdl_list::cdl_list_strategy(any::<u8>(), 0..32), |list| {
+            proptest::prop_assert!(list.validate().is_ok());
+            proptest::prop_assert!(list.iter().all(|x| *x.borrow() <= 100));
+            Ok(())
+        });
+
+        match result {
+            Err(TestError::Fail(_, minimal)) => {
+                assert_eq!(minimal, [101]);
+                assert_list_invariants!(minimal);
+            }
+            other => panic!("expected a failure, got {:?}", other.map_err(|e| e.to_string())),
+        }
+    }
+
+    #[test]
+    fn test_assert_list_eq() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert_list_eq!(list, Vec::<u32>::new());
+        assert_list_invariants!(list);
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_list_eq!(list, [1, 2, 3]);
+        assert_list_eq!(list, vec![1, 2, 3]);
+        assert_list_invariants!(list);
+
+        // the list is left intact
+        assert_eq!(list.size(), 3);
+    }
+
+    #[test]
+    fn test_assert_list_eq_message() {
+        let mut list : CdlList<u32> = CdlList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let err = panic::catch_unwind(panic::AssertUnwindSafe(|| assert_list_eq!(list, [1, 5, 3]))).unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert!(msg.contains("at index 1"));
+        assert!(msg.contains("actual: [1, 2, 3]"));
+        assert!(msg.contains("expected: [1, 5, 3]"));
+
+        let err = panic::catch_unwind(panic::AssertUnwindSafe(|| assert_list_eq!(list, [1, 2]))).unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert!(msg.contains("at index 2"));
+    }
+
+    #[test]
+    fn test_resize() {
+        use std::rc::Rc;
+
+        let mut list : CdlList<u32> = CdlList::new();
+        list.resize(0, 9);
+        assert!(list.is_empty());
+        list.resize(3, 9);
+        assert_list_eq!(list, [9, 9, 9]);
+        assert_list_invariants!(list);
+
+        list.push_front(1);
+        list.resize(6, 0);
+        assert_list_eq!(list, [1, 9, 9, 9, 0, 0]);
+        list.resize(6, 5);
+        assert_list_eq!(list, [1, 9, 9, 9, 0, 0]);
+        list.resize(2, 5);
+        assert_list_eq!(list, [1, 9]);
+        assert_list_invariants!(list);
+        list.resize(0, 5);
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+
+        // nothing is leaked when growing, and shrinking drops the popped 
+        // elements as well as the value
+        let rc = Rc::new(());
+        let mut list = CdlList::new();
+        list.resize(4, Rc::clone(&rc));
+        assert_eq!(Rc::strong_count(&rc), 5);
+        list.resize(1, Rc::clone(&rc));
+        assert_eq!(Rc::strong_count(&rc), 2);
+    }
+
+    #[test]
+    fn test_clear() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        let rc = Rc::new(());
+        let mut list : CdlList<Rc<()>> = (0..5).map(|_| Rc::clone(&rc)).collect();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::Cleared { len } = event {
+                log.borrow_mut().push(len);
+            }
+        }));
+        let h = list.handle_at(4).unwrap();
+
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(Rc::strong_count(&rc), 1);
+        assert!(list.peek_handle(&h).is_none());
+        assert_list_invariants!(list);
+
+        // clearing an empty list reports nothing
+        list.clear();
+        assert_eq!(*events.borrow(), [5]);
+
+        // the list is usable again, and a single element clears too
+        list.push_back(Rc::clone(&rc));
+        assert_list_invariants!(list);
+        list.clear();
+        assert_eq!(*events.borrow(), [5, 1]);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        // long enough to overflow the stack if nodes were freed recursively
+        let mut list : CdlList<u32> = (0..1_000_000).collect();
+        list.rotate_left(500_000);
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_observer() {
+        use cdl_list::ListEvent::{self, *};
+        use std::{cell::RefCell, rc::Rc};
+
+        fn owned(event: ListEvent<&u32>) -> ListEvent<u32> {
+            match event {
+                Pushed { front, value } => Pushed { front, value: *value },
+                Popped { front, value } => Popped { front, value: *value },
+                InsertedAt { index, value } => InsertedAt { index, value: *value },
+                RemovedAt { index, value } => RemovedAt { index, value: *value },
+                Cleared { len } => Cleared { len },
+            }
+        }
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let log_clone = Rc::clone(&log);
+
+        let mut list : CdlList<u32> = CdlList::new();
+        list.set_observer(Box::new(move |event| log_clone.borrow_mut().push(owned(event))));
+
+        list.push_back(1);
+        list.push_front(0);
+        list.insert_at(2, 3).unwrap();
+        list.insert_at(2, 2).unwrap();
+        list.insert_at_back(0, 4).unwrap();
+        list.remove_at(1);
+        list.remove_at_back(0);
+        list.pop_front();
+        list.replace_range(0..1, [7, 8]);
+        list.pop_back();
+        list.pop_back();
+        list.pop_back();
+        assert!(list.pop_back().is_none());
+
+        assert_eq!(*log.borrow(), vec![
+            Pushed { front: false, value: 1 },
+            Pushed { front: true, value: 0 },
+            InsertedAt { index: 2, value: 3 },
+            InsertedAt { index: 2, value: 2 },
+            InsertedAt { index: 4, value: 4 },
+            RemovedAt { index: 1, value: 1 },
+            RemovedAt { index: 3, value: 4 },
+            Popped { front: true, value: 0 },
+            RemovedAt { index: 0, value: 2 },
+            InsertedAt { index: 0, value: 7 },
+            InsertedAt { index: 1, value: 8 },
+            Popped { front: false, value: 3 },
+            Popped { front: false, value: 8 },
+            Popped { front: false, value: 7 },
+        ]);
+
+        // no events once the observer is removed
+        assert!(list.remove_observer().is_some());
+        list.push_back(1);
+        assert_eq!(log.borrow().len(), 14);
+    }
+
+    #[test]
+    #[cfg(feature = "instrumentation")]
+    fn test_instrumentation() {
+        use cdl_list::ListStats;
+
+        let mut list : CdlList<u32> = CdlList::new();
+        for i in 0..10 {
+            list.push_back(i);
+        }
+        assert_eq!(list.stats(), ListStats { pushes: 10, ..Default::default() });
+
+        // walks back from the tail: 9, 8, 7, 6
+        list.reset_stats();
+        list.insert_at(7, 100).unwrap();
+        assert_eq!(list.stats(), ListStats { seeks: 1, nodes_traversed: 3, ..Default::default() });
+
+        // walks forward from the head: 0, 1, 2, 3, 4
+        list.reset_stats();
+        assert_eq!(list.remove_at(5), Some(5));
+        assert_eq!(list.stats(), ListStats { seeks: 1, nodes_traversed: 4, ..Default::default() });
+
+        // ends of the list are pushes and pops, not seeks
+        list.reset_stats();
+        list.insert_at(0, 200).unwrap();
+        list.insert_at(list.size(), 300).unwrap();
+        list.remove_at(0);
+        list.pop_back();
+        assert_eq!(list.stats(), ListStats { pushes: 2, pops: 2, ..Default::default() });
+
+        // tail-relative lookups always start from the tail
+        list.reset_stats();
+        assert_eq!(*list.get_from_back(6).unwrap().borrow(), 3);
+        assert_eq!(list.stats(), ListStats { seeks: 1, nodes_traversed: 6, ..Default::default() });
+
+        // counters are per list
+        let other : CdlList<u32> = CdlList::new();
+        assert_eq!(other.stats(), ListStats::default());
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn test_tracing() {
+        use std::sync::{Arc, Mutex};
+        use tracing::{field::{Field, Visit}, span, Event, Metadata, Subscriber};
+
+        // Records each event's fields as "name=value" strings
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        struct Fields(Vec<String>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push(format!("{}={}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+                metadata.target() == "cdl_list_rs"
+            }
+
+            fn event(&self, event: &Event<'_>) {
+                assert_eq!(*event.metadata().level(), tracing::Level::TRACE);
+                let mut fields = Fields(Vec::new());
+                event.record(&mut fields);
+                self.0.lock().unwrap().push(fields.0.join(" "));
+            }
+
+            fn new_span(&self, _: &span::Attributes<'_>) -> span::Id { span::Id::from_u64(1) }
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+            fn enter(&self, _: &span::Id) {}
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut other = cdl_list![3];
+        let mut more = cdl_list![4];
+        tracing::subscriber::with_default(Recorder(Arc::clone(&log)), || {
+            let mut list : CdlList<u32> = CdlList::new();
+            list.push_back(1);
+            list.push_front(0);
+            list.insert_at(1, 5).unwrap();
+            list.remove_at(2);
+            list.replace_range(0..1, [7, 8]);
+
+            list.set_trace_values(true);
+            list.pop_back();
+
+            // with a subscriber listening, splices still report each element
+            list.append(&mut other);
+            list.splice_at(1, &mut more).unwrap();
+        });
+
+        assert_eq!(*log.lock().unwrap(), vec![
+            "op=push front=false size=1",
+            "op=push front=true size=2",
+            "op=insert_at index=1 size=3",
+            "op=remove_at index=2 size=2",
+            "op=remove_at index=0 size=1",
+            "op=insert_at index=0 size=2",
+            "op=insert_at index=1 size=3",
+            "op=replace_range start=0 removed=1 inserted=2 size=3",
+            "op=pop front=false size=2 value=5",
+            "op=remove_at index=0 size=0",
+            "op=push front=false size=3 value=3",
+            "op=remove_at index=0 size=0",
+            "op=insert_at index=1 size=4 value=4",
+        ]);
+    }
+
+    #[test]
+    fn test_static_capacity() {
+        let mut list : StaticCdlList<u32, 3> = StaticCdlList::new();
+        assert_eq!(list.capacity(), 3);
+        assert_eq!(list.push_back(1), Ok(()));
+        assert_eq!(list.push_back(2), Ok(()));
+        assert_eq!(list.push_front(0), Ok(()));
+        assert!(list.is_full());
+
+        // a full list gives every value back and is left unchanged
+        assert_eq!(list.push_back(3), Err(3));
+        assert_eq!(list.push_front(3), Err(3));
+        assert_eq!(list.insert_at(1, 3), Err(3));
+        assert_list_eq!(list, [0, 1, 2]);
+        assert_list_invariants!(list);
+
+        // freed slots are reused, over and over
+        for i in 10..20 {
+            assert_eq!(list.remove_at(1), Some(if i == 10 { 1 } else { i - 1 }));
+            assert_eq!(list.insert_at(1, i), Ok(()));
+            assert_list_invariants!(list);
+        }
+        assert_list_eq!(list, [0, 19, 2]);
+
+        // a list with no room at all
+        let mut empty : StaticCdlList<u32, 0> = StaticCdlList::new();
+        assert_eq!(empty.push_back(1), Err(1));
+        assert!(empty.pop_front().is_none());
+        assert_list_invariants!(empty);
+    }
+
+    #[test]
+    fn test_cursor_replace_current() {
+        let mut list : CdlList<u32> = CdlList::new();
+        {
+            let mut cursor = list.cursor_front_mut();
+            assert!(cursor.index().is_none());
+            assert!(cursor.replace_current(1).is_none());
+            assert!(cursor.take_current().is_none());
+            cursor.move_next();
+            assert!(cursor.current().is_none());
+        }
+        assert!(list.is_empty());
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.replace_current(20), Some(2));
+        assert_eq!(cursor.index(), Some(2));
+
+        // wrap around backwards from the head to the tail
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(4));
+        assert_eq!(cursor.take_current(), Some(4));
+        assert_eq!(cursor.replace_current(40), Some(0));
+
+        // and forwards from the tail to the head
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.replace_current(100), Some(0));
+        assert_eq!(*cursor.current().unwrap(), 100);
+        drop(cursor);
+
+        assert_list_eq!(list, [100, 1, 20, 3, 40]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_peek_handle() {
+        let mut list : CdlList<u32> = CdlList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let h = list.handle_at(2).unwrap();
+        assert_eq!(*list.peek_handle(&h).unwrap().borrow(), 2);
+
+        // the handle follows its node as the list changes around it
+        list.push_front(100);
+        list.remove_at(1);
+        *list.peek_handle_mut(&h).unwrap().borrow_mut() = 20;
+        assert_eq!(*list.get_from_back(2).unwrap().borrow(), 20);
+        assert_list_eq!(list, [100, 1, 20, 3, 4]);
+
+        // handles are not accepted by other lists
+        let mut other : CdlList<u32> = CdlList::new();
+        other.push_back(20);
+        assert!(other.peek_handle(&h).is_none());
+        assert!(other.peek_handle_mut(&h).is_none());
+
+        // nodes moved into another list take the handle with them
+        let removed = list.replace_range(2..3, []).unwrap();
+        assert!(list.peek_handle(&h).is_none());
+        assert_eq!(*removed.peek_handle(&h).unwrap().borrow(), 20);
+        drop(removed);
+
+        // handles to removed nodes are stale
+        let h = list.handle_at(3).unwrap();
+        let copy = h.clone();
+        assert_eq!(list.pop_back(), Some(4));
+        assert!(list.peek_handle(&h).is_none());
+        assert!(list.peek_handle_mut(&copy).is_none());
+        assert_eq!(format!("{:?}", h), "NodeHandle(<stale>)");
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_handle_index() {
+        let mut list : CdlList<u32> = CdlList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
+
+        let handles : Vec<_> = (0..6).map(|i| list.handle_at(i).unwrap()).collect();
+        for (i, h) in handles.iter().enumerate() {
+            assert_eq!(list.handle_index(h), Some(i));
+        }
+
+        // the index tracks the node, not its original position
+        list.rotate_left(2);
+        assert_list_eq!(list, [2, 3, 4, 5, 0, 1]);
+        assert_eq!(list.handle_index(&handles[0]), Some(4));
+        assert_eq!(list.handle_index(&handles[2]), Some(0));
+
+        list.rotate_right(3);
+        assert_list_eq!(list, [5, 0, 1, 2, 3, 4]);
+        assert_eq!(list.handle_index(&handles[5]), Some(0));
+        assert_eq!(list.handle_index(&handles[4]), Some(5));
+
+        list.remove_at(2);
+        list.insert_at(0, 100).unwrap();
+        assert_list_eq!(list, [100, 5, 0, 2, 3, 4]);
+        assert_eq!(list.handle_index(&handles[2]), Some(3));
+        assert_eq!(list.handle_index(&handles[1]), None);
+
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.handle_index(&handles[4]), None);
+        assert_eq!(list.handle_index(&handles[3]), Some(4));
+
+        let other : CdlList<u32> = CdlList::new();
+        assert_eq!(other.handle_index(&handles[3]), None);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut list : CdlList<u32> = CdlList::new();
+        list.rotate_left(3);
+        list.rotate_right(3);
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.rotate_left(1);
+        assert_list_eq!(list, [1]);
+
+        for i in 2..6 {
+            list.push_back(i);
+        }
+        for n in 0..12 {
+            list.rotate_left(n);
+            assert_list_invariants!(list);
+            list.rotate_right(n);
+            assert_list_eq!(list, [1, 2, 3, 4, 5]);
+        }
+
+        list.rotate_left(7);
+        assert_list_eq!(list, [3, 4, 5, 1, 2]);
+        list.push_back(6);
+        list.push_front(0);
+        assert_list_eq!(list, [0, 3, 4, 5, 1, 2, 6]);
+        assert_list_invariants!(list);
+
+        assert_eq!(list.to_string(), "... <=> 0 <=> 3 <=> 4 <=> 5 <=> 1 <=> 2 <=> 6 <=> ...");
+        while list.pop_back().is_some() {
+            assert_list_invariants!(list);
+        }
+    }
+
+    #[test]
+    fn test_cursor_two_pointer_walk() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert!(list.cursor_front().current().is_none());
+
+        for i in 0..7 {
+            list.push_back(i);
+        }
+
+        // tortoise and hare: in a cycle of n nodes, the hare laps the tortoise 
+        // after exactly n steps
+        let mut tortoise = list.cursor_front();
+        let mut hare = list.cursor_front();
+        let mut steps = 0;
+        loop {
+            tortoise.move_next();
+            hare.move_next();
+            hare.move_next();
+            steps += 1;
+
+            if tortoise.index() == hare.index() {
+                break;
+            }
+        }
+        assert_eq!(steps, list.size());
+        assert_eq!(*tortoise.current().unwrap(), *hare.current().unwrap());
+        assert_eq!(tortoise.index(), Some(0));
+    }
+
+    #[test]
+    fn test_cursor_wraps_around() {
+        let mut list : CdlList<u32> = CdlList::new();
+
+        // moving a cursor over an empty list does nothing
+        let mut cursor = list.cursor_back();
+        cursor.move_next();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        assert!(cursor.current().is_none());
+        drop(cursor);
+
+        // a single element is its own neighbour
+        list.push_back(7);
+        let mut cursor = list.cursor_front();
+        cursor.move_prev();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(0), 7));
+        cursor.move_next();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(0), 7));
+        drop(cursor);
+
+        // indices count from the head after a rotation
+        for i in 8..11 {
+            list.push_back(i);
+        }
+        list.rotate_left(2);
+        let mut cursor = list.cursor_front();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(0), 9));
+        cursor.move_prev();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(3), 8));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(1), 10));
+        let back = list.cursor_back();
+        assert_eq!((back.index(), *back.current().unwrap()), (Some(3), 8));
+    }
+
+    #[test]
+    fn test_cursors_interleave() {
+        let mut list : CdlList<u32> = CdlList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let mut a = list.cursor_front();
+        let mut b = list.cursor_back();
+        let mut c = a.clone();
+
+        for _ in 0..12 {
+            a.move_next();
+            b.move_prev();
+            c.move_next();
+            c.move_next();
+
+            // hold borrows from all three cursors, and the list, at once
+            let (x, y, z) = (a.current().unwrap(), b.current().unwrap(), c.current().unwrap());
+            let head = list.peek_front().unwrap();
+            assert_eq!(*x as usize, a.index().unwrap());
+            assert_eq!(*y as usize, b.index().unwrap());
+            assert_eq!(*z as usize, c.index().unwrap());
+            assert_eq!(*head, 0);
+        }
+
+        assert_eq!(a.index(), Some(2));
+        assert_eq!(b.index(), Some(2));
+        assert_eq!(c.index(), Some(4));
+    }
+
+    #[test]
+    fn test_resort_at() {
+        fn sorted_list(vals: &[u32]) -> CdlList<u32> {
+            let mut list = CdlList::new();
+            for &v in vals {
+                list.push_back(v);
+            }
+            list
+        }
+        fn set(list: &mut CdlList<u32>, index: usize, val: u32) {
+            list.remove_at(index);
+            list.insert_at(index, val).unwrap();
+        }
+
+        // no movement
+        let mut list = sorted_list(&[1, 3, 5, 7, 9]);
+        set(&mut list, 2, 4);
+        assert_eq!(list.resort_at(2), 2);
+        assert_list_eq!(list, [1, 3, 4, 7, 9]);
+
+        // up, down, and to both extremes
+        set(&mut list, 1, 8);
+        assert_eq!(list.resort_at(1), 3);
+        assert_list_eq!(list, [1, 4, 7, 8, 9]);
+
+        set(&mut list, 3, 2);
+        assert_eq!(list.resort_at(3), 1);
+        assert_list_eq!(list, [1, 2, 4, 7, 9]);
+
+        set(&mut list, 0, 10);
+        assert_eq!(list.resort_at(0), 4);
+        assert_list_eq!(list, [2, 4, 7, 9, 10]);
+
+        set(&mut list, 4, 0);
+        assert_eq!(list.resort_at(4), 0);
+        assert_list_eq!(list, [0, 2, 4, 7, 9]);
+        assert!(list.is_sorted());
+        assert_list_invariants!(list);
+
+        // equal keys keep their order, and the moved element stops at them
+        let mut list : CdlList<(u32, char)> = CdlList::new();
+        for (i, c) in "abcdef".chars().enumerate() {
+            list.push_back((i as u32 / 2, c));
+        }
+        list.remove_at(5);
+        list.push_back((1, 'f'));
+        assert_eq!(list.resort_by_key(5, |e| e.0), 4);
+        assert_list_eq!(list, [(0, 'a'), (0, 'b'), (1, 'c'), (1, 'd'), (1, 'f'), (2, 'e')]);
+
+        list.remove_at(0);
+        list.push_front((1, 'a'));
+        assert_eq!(list.resort_by_key(0, |e| e.0), 1);
+        assert_list_eq!(list, [(0, 'b'), (1, 'a'), (1, 'c'), (1, 'd'), (1, 'f'), (2, 'e')]);
+        assert_list_invariants!(list);
+
+        let mut single = sorted_list(&[1]);
+        assert_eq!(single.resort_at(0), 0);
+        assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| single.resort_at(1))).is_err());
+    }
+
+    #[test]
+    fn test_retain_matches_vec() {
+        // every keep/drop pattern over a list of 8, so runs of removals cover the 
+        // head, the tail, and the seam between them
+        for mask in 0u32..256 {
+            let mut list : CdlList<_> = (0..8).collect();
+            let mut model : Vec<u32> = (0..8).collect();
+            list.retain(|x| mask & (1 << x) != 0);
+            model.retain(|x| mask & (1 << x) != 0);
+
+            assert_list_eq!(list, model);
+            assert_list_invariants!(list);
+            assert_eq!(list.peek_front().map(|x| *x), model.first().copied());
+            assert_eq!(list.peek_back().map(|x| *x), model.last().copied());
+        }
+
+        // the predicate sees each element once, in order
+        let mut list = cdl_list![5, 6, 7];
+        let mut seen = Vec::new();
+        list.retain(|x| {
+            seen.push(*x);
+            *x != 6
+        });
+        assert_eq!(seen, [5, 6, 7]);
+        assert_list_eq!(list, [5, 7]);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        let mut list = cdl_list![4, 1, 3, 1, 2];
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::RemovedAt { index, value } = event {
+                log.borrow_mut().push((index, *value));
+            }
+        }));
+
+        // each round counts every element down and drops the ones that hit zero, 
+        // including the head and the tail
+        let mut rounds = Vec::new();
+        while !list.is_empty() {
+            list.retain_mut(|x| {
+                *x -= 1;
+                *x > 0
+            });
+            assert_list_invariants!(list);
+            rounds.push(list.size());
+        }
+        assert_eq!(rounds, [3, 2, 1, 0]);
+        assert_eq!(*events.borrow(), [(1, 0), (2, 0), (2, 0), (1, 0), (0, 0)]);
+
+        // kept elements keep their changes, matching Vec::retain_mut
+        let mut list : CdlList<_> = (0..10).collect();
+        let mut model : Vec<u32> = (0..10).collect();
+        let bump = |x: &mut u32| {
+            *x *= 3;
+            *x % 4 != 1
+        };
+        list.retain_mut(bump);
+        model.retain_mut(bump);
+        assert_list_eq!(list, model);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_retain_with_index() {
+        // index only: indices are from before any removals
+        let mut list = cdl_list![0, 10, 20, 30, 40, 50, 60, 70, 80];
+        let mut seen = Vec::new();
+        list.retain_with_index(|index, _| {
+            seen.push(index);
+            index % 3 != 0
+        });
+        assert_eq!(seen, (0..9).collect::<Vec<_>>());
+        assert_list_eq!(list, [10, 20, 40, 50, 70, 80]);
+        assert_list_invariants!(list);
+
+        // value only
+        let mut list = cdl_list![0, 10, 20, 30, 40, 50, 60, 70, 80];
+        list.retain_with_index(|_, val| *val >= 30 && *val < 60);
+        assert_list_eq!(list, [30, 40, 50]);
+        assert_list_invariants!(list);
+
+        // both: drop every third element unless it's a multiple of 40
+        let mut list = cdl_list![0, 10, 20, 30, 40, 50, 60, 70, 80];
+        list.retain_with_index(|index, val| index % 3 != 2 || val % 40 == 0);
+        assert_list_eq!(list, [0, 10, 30, 40, 60, 70, 80]);
+        assert_list_invariants!(list);
+
+        // removing everything, or nothing
+        let mut list = cdl_list![0, 10, 20, 30];
+        list.retain_with_index(|_, _| true);
+        assert_list_eq!(list, [0, 10, 20, 30]);
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+        list.retain(|_| false);
+
+        // the list still works afterwards
+        list.push_back(1);
+        list.push_front(0);
+        assert_list_eq!(list, [0, 1]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_cycle_collect() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert!(list.cycle_collect(10).is_empty());
+        assert!(list.cycle_collect_from(3, 10).is_empty());
+
+        for i in 1..=4 {
+            list.push_back(i);
+        }
+        assert!(list.cycle_collect(0).is_empty());
+        assert_eq!(list.cycle_collect(3), vec![1, 2, 3]);
+        assert_eq!(list.cycle_collect(4), vec![1, 2, 3, 4]);
+        assert_eq!(list.cycle_collect(10), vec![1, 2, 3, 4, 1, 2, 3, 4, 1, 2]);
+
+        assert_eq!(list.cycle_collect_from(3, 3), vec![4, 1, 2]);
+        assert_eq!(list.cycle_collect_from(1, 4), vec![2, 3, 4, 1]);
+        assert_eq!(list.cycle_collect_from(2, 10), vec![3, 4, 1, 2, 3, 4, 1, 2, 3, 4]);
+        assert_eq!(list.cycle_collect_from(9, 2), vec![2, 3]);
+
+        // the list is unchanged
+        assert_list_eq!(list, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_map_in_place() {
+        use std::{cell::Cell, rc::Rc};
+
+        let mut list : CdlList<u32> = CdlList::new();
+        list.map_in_place(|v| v + 1);
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.map_in_place(|v| v * 10);
+        assert_list_eq!(list, [10]);
+
+        for i in 2..=5 {
+            list.push_back(i);
+        }
+        list.map_in_place(|v| v * 2);
+        assert_list_eq!(list, [20, 4, 6, 8, 10]);
+        assert_list_invariants!(list);
+
+        // counts drops, so elements lost to a panic aren't leaked or dropped twice
+        #[derive(Debug)]
+        struct Counted(u32, Rc<Cell<usize>>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+        impl PartialEq<u32> for Counted {
+            fn eq(&self, other: &u32) -> bool {
+                self.0 == *other
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut list : CdlList<Counted> = CdlList::new();
+        for i in 0..5 {
+            list.push_back(Counted(i, Rc::clone(&drops)));
+        }
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.map_in_place(|mut c| {
+                assert!(c.0 != 2, "no twos");
+                c.0 += 100;
+                c
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 1);
+        assert_list_eq!(list, [100, 101, 3, 4]);
+        assert_list_invariants!(list);
+
+        // and the list is fully usable afterwards
+        list.push_front(Counted(7, Rc::clone(&drops)));
+        list.map_in_place(|c| c);
+        assert_list_eq!(list, [7, 100, 101, 3, 4]);
+        drop(list);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn test_move_range() {
+        use cdl_list::MoveRangeError;
+
+        // forward
+        let mut list : CdlList<u32> = (0..8).collect();
+        let h = list.handle_at(2).unwrap();
+        assert_eq!(list.move_range(1..3, 6), Ok(()));
+        assert_list_eq!(list, [0, 3, 4, 5, 1, 2, 6, 7]);
+        assert_eq!(list.handle_index(&h), Some(5));
+        assert_list_invariants!(list);
+
+        // backward
+        assert_eq!(list.move_range(5..7, 1), Ok(()));
+        assert_list_eq!(list, [0, 2, 6, 3, 4, 5, 1, 7]);
+        assert_eq!(list.handle_index(&h), Some(1));
+        assert_list_invariants!(list);
+
+        // to the very front and back, including ranges touching the head and tail
+        assert_eq!(list.move_range(6..8, 0), Ok(()));
+        assert_list_eq!(list, [1, 7, 0, 2, 6, 3, 4, 5]);
+        assert_eq!(list.move_range(0..3, 8), Ok(()));
+        assert_list_eq!(list, [2, 6, 3, 4, 5, 1, 7, 0]);
+        assert_eq!(list.move_range(0..8, 8), Ok(()));
+        assert_list_eq!(list, [2, 6, 3, 4, 5, 1, 7, 0]);
+        assert_list_invariants!(list);
+
+        // no-ops
+        assert_eq!(list.move_range(2..5, 2), Ok(()));
+        assert_eq!(list.move_range(2..5, 5), Ok(()));
+        assert_eq!(list.move_range(3..3, 7), Ok(()));
+        assert_list_eq!(list, [2, 6, 3, 4, 5, 1, 7, 0]);
+
+        // errors leave the list untouched
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 5..2;
+        assert_eq!(list.move_range(reversed, 0), Err(MoveRangeError::SourceOutOfBounds));
+        assert_eq!(list.move_range(6..9, 0), Err(MoveRangeError::SourceOutOfBounds));
+        assert_eq!(list.move_range(0..2, 9), Err(MoveRangeError::DestinationOutOfBounds));
+        assert_eq!(list.move_range(2..5, 3), Err(MoveRangeError::DestinationInSource));
+        assert_eq!(list.move_range(2..5, 4), Err(MoveRangeError::DestinationInSource));
+        assert_list_eq!(list, [2, 6, 3, 4, 5, 1, 7, 0]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_repeat() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static CLONES: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Cloned(u32);
+
+        impl Clone for Cloned {
+            fn clone(&self) -> Self {
+                CLONES.with(|c| c.set(c.get() + 1));
+                Cloned(self.0)
+            }
+        }
+
+        let mut list : CdlList<Cloned> = CdlList::new();
+        assert_eq!(list.repeat(5).size(), 0);
+
+        list.push_back(Cloned(1));
+        list.push_back(Cloned(2));
+        list.push_back(Cloned(3));
+
+        let none = list.repeat(0);
+        assert!(none.is_empty());
+        assert_list_invariants!(none);
+        assert_eq!(CLONES.with(Cell::get), 0);
+
+        let one = list.repeat(1);
+        assert_list_eq!(one, [Cloned(1), Cloned(2), Cloned(3)]);
+        assert_list_invariants!(one);
+        assert_eq!(CLONES.with(Cell::get), 3);
+
+        // the tail of each copy is followed by the head of the next
+        let many = list.repeat(4);
+        assert_eq!(many.size(), 12);
+        assert_eq!(CLONES.with(Cell::get), 3 + 12);
+        for (i, val) in many.cycle_collect(12).iter().enumerate() {
+            assert_eq!(val.0, i as u32 % 3 + 1);
+        }
+        assert_list_invariants!(many);
+
+        // the new list owns its nodes, so it can be changed independently
+        let mut many = many;
+        let h = many.handle_at(5).unwrap();
+        assert!(list.peek_handle(&h).is_none());
+        many.remove_at(0);
+        assert_eq!(many.handle_index(&h), Some(4));
+        assert_eq!(list.size(), 3);
+
+        // a single element repeats too
+        let mut single : CdlList<u32> = CdlList::new();
+        single.push_back(7);
+        let sevens = single.repeat(3);
+        assert_list_eq!(sevens, [7, 7, 7]);
+        assert_list_invariants!(sevens);
+    }
+
+    #[test]
+    fn test_eq_by_cmp_by() {
+        use std::cmp::Ordering;
+
+        #[derive(Debug)]
+        struct Reading {
+            sensor: u32,
+            celsius: f64,
+        }
+
+        let mut a : CdlList<f64> = CdlList::new();
+        let mut b : CdlList<f64> = CdlList::new();
+        for i in 0..5 {
+            a.push_back(i as f64 * 0.1);
+            b.push_back(i as f64 / 10.0 + 1e-12);
+        }
+
+        let close = |x: &f64, y: &f64| (x - y).abs() < 1e-9;
+        assert!(a.eq_by(&b, close));
+        assert!(!a.eq_by(&b, |x, y| x == y));
+
+        // the walk stops at the first mismatch
+        b.insert_at(1, 100.0).unwrap();
+        b.pop_back();
+        let mut calls = 0;
+        assert!(!a.eq_by(&b, |x, y| { calls += 1; close(x, y) }));
+        assert_eq!(calls, 2);
+
+        // different sizes are unequal without comparing anything
+        b.pop_back();
+        assert!(!a.eq_by(&b, |_, _| panic!("compared lists of different sizes")));
+
+        // comparing against a different element type
+        let mut readings : CdlList<Reading> = CdlList::new();
+        let mut ids : CdlList<u32> = CdlList::new();
+        for i in 0..3 {
+            readings.push_back(Reading { sensor: i, celsius: 20.0 + i as f64 });
+            ids.push_back(i);
+        }
+        assert!(readings.eq_by(&ids, |r, id| r.sensor == *id));
+
+        let empty : CdlList<u32> = CdlList::new();
+        assert!(CdlList::<Reading>::new().eq_by(&empty, |_, _| false));
+
+        // comparing by a projection of the elements
+        let mut other : CdlList<Reading> = CdlList::new();
+        for i in 0..3 {
+            other.push_back(Reading { sensor: i, celsius: -1.0 });
+        }
+        let by_sensor = |x: &Reading, y: &Reading| x.sensor.cmp(&y.sensor);
+        assert_eq!(readings.cmp_by(&other, by_sensor), Ordering::Equal);
+        assert_eq!(readings.cmp_by(&other, |x, y| x.celsius.total_cmp(&y.celsius)), Ordering::Greater);
+
+        // a prefix is less than the longer list, as with slices
+        other.pop_back();
+        assert_eq!(readings.cmp_by(&other, by_sensor), Ordering::Greater);
+        assert_eq!(other.cmp_by(&readings, by_sensor), Ordering::Less);
+        assert_eq!(CdlList::new().cmp_by(&other, by_sensor), Ordering::Less);
+
+        // an earlier difference wins over the lengths
+        other.cursor_back_mut().current().unwrap().sensor = 5;
+        assert_eq!(readings.cmp_by(&other, by_sensor), Ordering::Less);
+    }
+
+    #[test]
+    fn test_prefix_sums_and_scan() {
+        use std::num::Wrapping;
+
+        let mut list : CdlList<i32> = CdlList::new();
+        assert!(list.prefix_sums().is_empty());
+        assert!(list.scan((), |_, x| *x).is_empty());
+
+        for x in [3, -1, 4, -1, 5] {
+            list.push_back(x);
+        }
+        let sums = list.prefix_sums();
+        assert_list_eq!(sums, [3, 2, 6, 5, 10]);
+        assert_list_invariants!(sums);
+
+        // the input is untouched, and the output is a separate list
+        assert_list_eq!(list, [3, -1, 4, -1, 5]);
+        let h = sums.handle_at(0).unwrap();
+        assert!(list.peek_handle(&h).is_none());
+
+        // overflow follows the element type's Add
+        let mut bytes : CdlList<Wrapping<u8>> = CdlList::new();
+        for x in [200, 50, 10] {
+            bytes.push_back(Wrapping(x));
+        }
+        assert_list_eq!(bytes.prefix_sums(), [Wrapping(200), Wrapping(250), Wrapping(4)]);
+
+        // scan can produce a different type and carry any state
+        let running_max = list.scan(i32::MIN, |max, x| {
+            *max = (*max).max(*x);
+            format!("{}/{}", x, max)
+        });
+        assert_list_eq!(running_max, ["3/3", "-1/3", "4/4", "-1/4", "5/5"]);
+        assert_list_invariants!(running_max);
+
+        let mut single : CdlList<u32> = CdlList::new();
+        single.push_back(7);
+        assert_list_eq!(single.prefix_sums(), [7]);
+        assert_list_invariants!(single.prefix_sums());
+    }
+
+    #[test]
+    fn test_adjacent_differences() {
+        use std::num::Wrapping;
+
+        // seconds into the day, wrapping at midnight
+        const DAY: u32 = 86_400;
+        let mut times : CdlList<Wrapping<u32>> = CdlList::new();
+        assert!(times.adjacent_differences().is_empty());
+        assert!(times.adjacent_differences_linear().is_empty());
+
+        for t in [3_600, 43_200, 82_800] {
+            times.push_back(Wrapping(t));
+        }
+
+        // the head's difference is taken from the tail, around the ring
+        let diffs = times.adjacent_differences();
+        assert_eq!(diffs.size(), 3);
+        assert_eq!(*diffs.peek_front().unwrap() + Wrapping(DAY), Wrapping(7_200));
+        assert_eq!(*diffs.get_from_back(1).unwrap().borrow(), Wrapping(39_600));
+        assert_eq!(*diffs.peek_back().unwrap(), Wrapping(39_600));
+        assert_list_invariants!(diffs);
+
+        let linear = times.adjacent_differences_linear();
+        assert_list_eq!(linear, [Wrapping(39_600), Wrapping(39_600)]);
+        assert_list_invariants!(linear);
+
+        // differences undo prefix sums, apart from the head
+        let mut list : CdlList<i64> = CdlList::new();
+        for x in [4, -2, 7, 0, 3] {
+            list.push_back(x);
+        }
+        let round_trip = list.prefix_sums().adjacent_differences();
+        assert_list_eq!(round_trip, [4 - 12, -2, 7, 0, 3]);
+
+        // a single element is its own predecessor
+        let mut single : CdlList<i64> = CdlList::new();
+        single.push_back(9);
+        assert_list_eq!(single.adjacent_differences(), [0]);
+        assert_list_invariants!(single.adjacent_differences());
+        assert!(single.adjacent_differences_linear().is_empty());
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        use cdl_list::{Decode, Encode};
+        use std::{fmt::Debug, io::{Cursor, ErrorKind}};
+
+        fn round_trip<T: Debug + PartialEq + Clone + Encode + Decode>(vals: &[T]) {
+            let mut list : CdlList<T> = CdlList::new();
+            for val in vals {
+                list.push_back(val.clone());
+            }
+
+            let mut bytes = Vec::new();
+            list.write_to(&mut bytes).unwrap();
+
+            // trailing data is left for the next reader
+            bytes.push(0xAA);
+            let mut r = Cursor::new(&bytes);
+            let copy = CdlList::<T>::read_from(&mut r).unwrap();
+            assert_list_eq!(copy, vals);
+            assert_list_invariants!(copy);
+            assert_eq!(r.position() as usize, bytes.len() - 1);
+            bytes.pop();
+
+            // every truncation fails cleanly
+            for len in 0..bytes.len() {
+                let err = CdlList::<T>::read_from(&mut Cursor::new(&bytes[..len])).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::UnexpectedEof, "truncated to {} bytes", len);
+            }
+        }
+
+        round_trip::<u8>(&[]);
+        round_trip(&[0u8, 1, 255]);
+        round_trip(&[u16::MAX, 0x1234]);
+        round_trip(&[7u32, u32::MAX]);
+        round_trip(&[u64::MAX, 1 << 40]);
+        round_trip(&[u128::MAX, 3]);
+        round_trip(&[i8::MIN, -1, i8::MAX]);
+        round_trip(&[i16::MIN, -300]);
+        round_trip(&[i32::MIN, 0, i32::MAX]);
+        round_trip(&[i64::MIN, -1]);
+        round_trip(&[i128::MIN, 1]);
+        round_trip(&[usize::MAX, 0]);
+        round_trip(&[isize::MIN, 42]);
+        round_trip(&[String::new(), String::from("ring"), String::from("ünïcödé")]);
+        round_trip(&[vec![], vec![1u8, 2, 3], vec![0u8; 300]]);
+
+        // the size and each element are framed as documented
+        let mut list : CdlList<String> = CdlList::new();
+        list.push_back(String::from("hi"));
+        let mut bytes = Vec::new();
+        list.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, b'h', b'i']);
+
+        // a huge claimed size doesn't allocate, it just runs out of input
+        let mut huge = u64::MAX.to_le_bytes().to_vec();
+        huge.extend_from_slice(&[0xFF; 8]);
+        let err = CdlList::<Vec<u8>>::read_from(&mut Cursor::new(&huge)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        let mut bad_utf8 = Vec::new();
+        1u64.encode(&mut bad_utf8).unwrap();
+        vec![0xC3u8, 0x28].encode(&mut bad_utf8).unwrap();
+        let err = CdlList::<String>::read_from(&mut Cursor::new(&bad_utf8)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(String::decode(&mut Cursor::new(&bad_utf8[8..])).unwrap_err().kind(), ErrorKind::InvalidData);
+
+        // nothing more is encoded after the first error
+        struct FailAfter(usize);
+        impl std::io::Write for FailAfter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                match self.0.checked_sub(1) {
+                    Some(left) => {
+                        self.0 = left;
+                        Ok(buf.len())
+                    },
+                    None => Err(std::io::Error::new(ErrorKind::Other, "full")),
+                }
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+        struct Counted<'a>(&'a std::cell::Cell<usize>);
+        impl Encode for Counted<'_> {
+            fn encode<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+                self.0.set(self.0.get() + 1);
+                w.write_all(&[0])
+            }
+        }
+        let encoded = std::cell::Cell::new(0);
+        let list : CdlList<Counted> = (0..100).map(|_| Counted(&encoded)).collect();
+        let err = list.write_to(&mut FailAfter(4)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(encoded.get(), 4);
+    }
+
+    #[test]
+    fn test_write_bytes_and_chars() {
+        use std::{cell::RefCell, fmt::Write as _, io::{BufWriter, Write}, rc::Rc};
+
+        fn drain<T>(list: &mut CdlList<T>) -> Vec<T> {
+            let mut out = Vec::new();
+            while let Some(val) = list.pop_front() {
+                out.push(val);
+            }
+            out
+        }
+
+        let pushed = Rc::new(RefCell::new(Vec::new()));
+        let pushed_clone = Rc::clone(&pushed);
+
+        let mut bytes : CdlList<u8> = CdlList::new();
+        bytes.push_back(b'>');
+        bytes.set_observer(Box::new(move |event| {
+            if let cdl_list::ListEvent::Pushed { front: false, value } = event {
+                pushed_clone.borrow_mut().push(*value);
+            }
+        }));
+
+        assert_eq!(bytes.write(b"").unwrap(), 0);
+        assert_eq!(bytes.write(b"ab").unwrap(), 2);
+        write!(bytes, " {}", 10).unwrap();
+        bytes.flush().unwrap();
+        assert_list_invariants!(bytes);
+
+        // each byte is reported as pushed, in order
+        assert_eq!(*pushed.borrow(), b"ab 10");
+        bytes.remove_observer();
+
+        // through a chained writer and an encoder
+        {
+            let mut w = BufWriter::with_capacity(4, &mut bytes);
+            w.write_all(b"|xyz|").unwrap();
+            let mut nums : CdlList<u16> = CdlList::new();
+            nums.push_back(0x0102);
+            nums.write_to(&mut w).unwrap();
+        }
+        assert_list_invariants!(bytes);
+        assert_eq!(drain(&mut bytes), b">ab 10|xyz|\x01\0\0\0\0\0\0\0\x02\x01");
+        assert!(bytes.is_empty());
+
+        let mut chars = CdlList::from("a");
+        let accent = 'é';
+        write!(chars, "{}{:>3}", accent, 7).unwrap();
+        chars.write_char('!').unwrap();
+        assert_list_invariants!(chars);
+        assert_eq!(drain(&mut chars), ['a', 'é', ' ', ' ', '7', '!']);
+    }
+
+    #[test]
+    fn test_read_bytes() {
+        use std::io::{ErrorKind, Read, Write};
+
+        let mut list : CdlList<u8> = CdlList::new();
+        let mut buf = [0; 4];
+        assert_eq!(list.read(&mut buf).unwrap(), 0);
+
+        // a buffer smaller than the list takes bytes in push order
+        list.write_all(b"0123456789").unwrap();
+        let mut out = Vec::new();
+        loop {
+            let n = list.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            assert!(n == 4 || list.is_empty());
+            out.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(out, b"0123456789");
+        assert_list_invariants!(list);
+
+        // an empty destination reads nothing
+        list.push_back(1);
+        assert_eq!(list.read(&mut []).unwrap(), 0);
+        assert_eq!(list.size(), 1);
+
+        // interleaved writes and reads behave like a queue
+        list.write_all(b"ab").unwrap();
+        let mut two = [0; 2];
+        list.read_exact(&mut two).unwrap();
+        assert_eq!(two, [1, b'a']);
+        list.write_all(b"cd").unwrap();
+        list.push_front(b'_');
+        list.read_exact(&mut two).unwrap();
+        assert_eq!(&two, b"_b");
+        let mut rest = Vec::new();
+        assert_eq!(list.read_to_end(&mut rest).unwrap(), 2);
+        assert_eq!(rest, b"cd");
+        assert_eq!(list.read_exact(&mut two).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+        assert_list_invariants!(list);
+
+        // a list can carry an encoded list to a decoder
+        let mut words : CdlList<String> = CdlList::new();
+        words.push_back(String::from("round"));
+        words.push_back(String::from("trip"));
+        words.write_to(&mut list).unwrap();
+        list.write_all(b"!").unwrap();
+        let copy = CdlList::<String>::read_from(&mut list).unwrap();
+        assert_list_eq!(copy, ["round", "trip"]);
+        assert_list_eq!(list, [b'!']);
+    }
+
+    #[test]
+    fn test_collect_results_and_options() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static DROPS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(Debug)]
+        struct Counted(u32);
+
+        // compare against plain numbers, so expected values aren't counted
+        impl PartialEq<u32> for Counted {
+            fn eq(&self, other: &u32) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.with(|d| d.set(d.get() + 1));
+            }
+        }
+
+        fn results(errs: &[usize], n: usize) -> CdlList<Result<Counted, Counted>> {
+            let mut list = CdlList::new();
+            for i in 0..n {
+                let val = Counted(i as u32);
+                list.push_back(if errs.contains(&i) { Err(val) } else { Ok(val) });
+            }
+            list
+        }
+
+        let drops = || DROPS.with(Cell::get);
+
+        let ok = results(&[], 4).collect_results().unwrap();
+        assert_list_eq!(ok, [0, 1, 2, 3]);
+        assert_list_invariants!(ok);
+        assert_eq!(drops(), 0);
+        drop(ok);
+        assert_eq!(drops(), 4);
+
+        // an Err at the head drops every other element
+        let err = results(&[0, 2], 4).collect_results().unwrap_err();
+        assert_eq!(err, 0);
+        assert_eq!(drops(), 4 + 3);
+        drop(err);
+
+        // an Err at the tail drops the successes collected so far
+        let err = results(&[3], 4).collect_results().unwrap_err();
+        assert_eq!(err, 3);
+        assert_eq!(drops(), 8 + 3);
+        drop(err);
+        assert_eq!(drops(), 12);
+
+        let empty : CdlList<Result<u32, ()>> = CdlList::new();
+        assert!(empty.collect_results().unwrap().is_empty());
+
+        let mut options : CdlList<Option<Counted>> = CdlList::new();
+        for i in 0..3 {
+            options.push_back(Some(Counted(i)));
+        }
+        let somes = options.collect_options().unwrap();
+        assert_list_eq!(somes, [0, 1, 2]);
+        drop(somes);
+        assert_eq!(drops(), 15);
+
+        let mut options : CdlList<Option<Counted>> = CdlList::new();
+        options.push_back(Some(Counted(0)));
+        options.push_back(None);
+        options.push_back(Some(Counted(2)));
+        assert!(options.collect_options().is_none());
+        assert_eq!(drops(), 17);
+    }
+
+    #[test]
+    fn test_get_wrapping_and_offset() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert!(list.get_wrapping(0).is_none());
+        assert!(list.get_offset(3, -1).is_none());
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        for index in [0, 4, 5, 7, 12, 1_000_003, usize::MAX - 1, usize::MAX] {
+            assert_eq!(*list.get_wrapping(index).unwrap().borrow(), (index % 5) as u32);
+        }
+
+        // forwards and backwards across the seam between tail and head
+        assert_eq!(*list.get_offset(4, 1).unwrap().borrow(), 0);
+        assert_eq!(*list.get_offset(4, 3).unwrap().borrow(), 2);
+        assert_eq!(*list.get_offset(0, -1).unwrap().borrow(), 4);
+        assert_eq!(*list.get_offset(1, -3).unwrap().borrow(), 3);
+        assert_eq!(*list.get_offset(2, 0).unwrap().borrow(), 2);
+        assert_eq!(*list.get_offset(7, -2).unwrap().borrow(), 0);
+        assert_eq!(*list.get_offset(0, -1_000_000).unwrap().borrow(), 0);
+        assert_eq!(*list.get_offset(usize::MAX, isize::MIN).unwrap().borrow(), 2);
+        assert_eq!(*list.get_offset(usize::MAX, isize::MAX).unwrap().borrow(), 2);
+
+        let mut single : CdlList<u32> = CdlList::new();
+        single.push_back(9);
+        assert_eq!(*single.get_wrapping(100).unwrap().borrow(), 9);
+        assert_eq!(*single.get_offset(3, -7).unwrap().borrow(), 9);
+    }
+
+    #[test]
+    fn test_wrapping_insert_and_remove() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert_eq!(list.remove_at_wrapping(5), None);
+
+        // an empty list has a single place to insert
+        list.insert_at_wrapping(7, 1);
+        assert_list_eq!(list, [1]);
+
+        // 0..=size match insert_at, then the count starts over at the head
+        list.insert_at_wrapping(1, 2);
+        assert_list_eq!(list, [1, 2]);
+        list.insert_at_wrapping(3, 0);
+        assert_list_eq!(list, [0, 1, 2]);
+        list.insert_at_wrapping(4 + 3, 3);
+        assert_list_eq!(list, [0, 1, 2, 3]);
+        list.insert_at_wrapping(5 * 100 + 2, 9);
+        assert_list_eq!(list, [0, 1, 9, 2, 3]);
+        assert_list_invariants!(list);
+
+        // counting on from a growing ring: 3 of 5 places, 6 of 6, then 9 of 7
+        let mut ring : CdlList<char> = CdlList::from("abcd");
+        let mut pos = 0;
+        for token in ['x', 'y', 'z'] {
+            pos += 3;
+            ring.insert_at_wrapping(pos, token);
+        }
+        assert_eq!(ring.to_string_chars(), "yazbcxd");
+        assert_list_invariants!(ring);
+
+        // removal has no extra position, so size is the head
+        assert_eq!(list.remove_at_wrapping(5), Some(0));
+        assert_eq!(list.remove_at_wrapping(4 * 2 + 3), Some(3));
+        assert_eq!(list.remove_at_wrapping(usize::MAX - 2), Some(9));
+        assert_list_eq!(list, [1, 2]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert!(list.neighbors(0).is_none());
+
+        // a single element neighbors itself on both sides
+        list.push_back(7);
+        {
+            let (prev, next) = list.neighbors(0).unwrap();
+            let (a, b) = (prev.borrow(), next.borrow());
+            assert_eq!((*a, *b), (7, 7));
+        }
+        assert!(list.with_neighbors_mut(0, |_, _, _| ()).is_none());
+
+        list.push_back(8);
+        {
+            let (prev, next) = list.neighbors(1).unwrap();
+            assert_eq!((*prev.borrow(), *next.borrow()), (7, 7));
+        }
+        assert!(list.with_neighbors_mut(1, |_, _, _| ()).is_none());
+
+        for i in 9..12 {
+            list.push_back(i);
+        }
+        assert!(list.neighbors(5).is_none());
+        assert!(list.with_neighbors_mut(5, |_, _, _| ()).is_none());
+        for (index, expected) in [(0, (11, 8)), (2, (8, 10)), (4, (10, 7))] {
+            let (prev, next) = list.neighbors(index).unwrap();
+            assert_eq!((*prev.borrow(), *next.borrow()), expected);
+        }
+
+        // one step of a rule-90 style automaton around the ring
+        let mut cells : CdlList<bool> = CdlList::new();
+        for alive in [false, false, true, false, false] {
+            cells.push_back(alive);
+        }
+        let next_gen : Vec<bool> = (0..cells.size())
+            .map(|i| {
+                let (l, r) = cells.neighbors(i).unwrap();
+                let alive = *l.borrow() != *r.borrow();
+                alive
+            })
+            .collect();
+        assert_eq!(next_gen, [false, true, false, true, false]);
+
+        // the neighbors of the head wrap around to the tail
+        assert_eq!(list.with_neighbors_mut(0, |prev, cur, next| {
+            std::mem::swap(prev, next);
+            *cur * 10
+        }), Some(70));
+        assert_list_eq!(list, [7, 11, 9, 10, 8]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_diff_apply() {
+        use cdl_list::Edit;
+
+        let a = CdlList::from("abcabba");
+        let b = CdlList::from("cbabac");
+        let script = a.diff(&b);
+        let mut c = CdlList::from("abcabba");
+        c.apply(&script);
+        assert_eq!(c.to_string_chars(), "cbabac");
+        assert_list_invariants!(c);
+
+        // identical lists need no edits
+        assert!(a.diff(&CdlList::from("abcabba")).is_empty());
+
+        // either side empty
+        let empty : CdlList<char> = CdlList::new();
+        assert_eq!(empty.diff(&CdlList::from("ab")), vec![
+            Edit::Insert { index: 0, value: 'a' },
+            Edit::Insert { index: 1, value: 'b' },
+        ]);
+        assert_eq!(CdlList::from("ab").diff(&empty), vec![
+            Edit::Remove { index: 0 },
+            Edit::Remove { index: 0 },
+        ]);
+        assert!(empty.diff(&CdlList::new()).is_empty());
+
+        // differing runs pair up into replacements
+        assert_eq!(CdlList::from("axyzb").diff(&CdlList::from("apqb")), vec![
+            Edit::Replace { index: 1, value: 'p' },
+            Edit::Replace { index: 2, value: 'q' },
+            Edit::Remove { index: 3 },
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index 2 out of range for size 2")]
+    fn test_apply_out_of_range() {
+        let mut list = CdlList::from("ab");
+        list.apply(&[cdl_list::Edit::Remove { index: 2 }]);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_diff_apply_round_trip(
+            a in proptest::collection::vec(0u8..4, 0..24),
+            b in proptest::collection::vec(0u8..4, 0..24),
+        ) {
+            let mut list : CdlList<u8> = CdlList::new();
+            let mut other : CdlList<u8> = CdlList::new();
+            for &x in &a {
+                list.push_back(x);
+            }
+            for &x in &b {
+                other.push_back(x);
+            }
+
+            let script = list.diff(&other);
+            proptest::prop_assert!(script.len() <= a.len().max(b.len()) + a.len().min(b.len()));
+            list.apply(&script);
+            assert_list_eq!(list, b);
+            proptest::prop_assert!(list.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_lcs() {
+        for (a, b, expected) in [
+            ("ABCBDAB", "BDCABA", "BDAB"),
+            ("AGGTAB", "GXTXAYB", "GTAB"),
+            ("abc", "abc", "abc"),
+            ("abc", "def", ""),
+            ("", "abc", ""),
+            ("abc", "", ""),
+            ("a", "a", "a"),
+        ] {
+            let (a, b) = (CdlList::from(a), CdlList::from(b));
+            let common = a.lcs(&b);
+            assert_eq!(common.to_string_chars(), expected);
+            assert_list_invariants!(common);
+            assert_eq!(a.lcs_len(&b), expected.chars().count());
+            assert_eq!(b.lcs_len(&a), expected.chars().count());
+        }
+
+        assert!(CdlList::<char>::new().is_subsequence_of(&CdlList::new()));
+        assert!(CdlList::from("").is_subsequence_of(&CdlList::from("x")));
+        assert!(!CdlList::from("x").is_subsequence_of(&CdlList::from("")));
+        assert!(CdlList::from("aa").is_subsequence_of(&CdlList::from("bab a")));
+        assert!(!CdlList::from("aa").is_subsequence_of(&CdlList::from("bab")));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_lcs_is_common_subsequence(
+            a in proptest::collection::vec(0u8..4, 0..24),
+            b in proptest::collection::vec(0u8..4, 0..24),
+        ) {
+            let mut list : CdlList<u8> = CdlList::new();
+            let mut other : CdlList<u8> = CdlList::new();
+            for &x in &a {
+                list.push_back(x);
+            }
+            for &x in &b {
+                other.push_back(x);
+            }
+
+            let common = list.lcs(&other);
+            proptest::prop_assert!(common.is_subsequence_of(&list));
+            proptest::prop_assert!(common.is_subsequence_of(&other));
+            proptest::prop_assert_eq!(common.size(), list.lcs_len(&other));
+            proptest::prop_assert_eq!(common.size(), other.lcs_len(&list));
+        }
+    }
+
+    #[test]
+    fn test_replace_sequence() {
+        let needle = ['b', 'c'];
+        for (replacement, expected) in [
+            ("", "adbc"),
+            ("x", "axdbc"),
+            ("xy", "axydbc"),
+            ("wxyz", "awxyzdbc"),
+        ] {
+            let mut list = CdlList::from("abcdbc");
+            let h = list.handle_at(3).unwrap();
+            assert!(list.replace_sequence(&needle, replacement.chars()));
+            assert_eq!(list.to_string_chars(), expected);
+            assert_list_invariants!(list);
+
+            // untouched elements keep their nodes
+            assert_eq!(*list.peek_handle(&h).unwrap().borrow(), 'd');
+        }
+
+        // a match at the very end of the list
+        let mut list = CdlList::from("xyzab");
+        assert!(list.replace_sequence(&['a', 'b'], "!".chars()));
+        assert_eq!(list.to_string_chars(), "xyz!");
+        assert_list_invariants!(list);
+
+        // matches don't wrap from the tail to the head
+        let mut list = CdlList::from("bxa");
+        assert!(!list.replace_sequence(&['a', 'b'], "!".chars()));
+        assert!(!list.replace_sequence(&[], "!".chars()));
+        assert!(!list.replace_sequence(&['b', 'x', 'a', 'b'], "!".chars()));
+        assert!(list.replace_sequence(&['b', 'x', 'a'], "".chars()));
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+
+        // replace_all works left to right, without overlaps or rescanning
+        let mut list = CdlList::from("aaa");
+        assert_eq!(list.replace_all_sequences(&['a', 'a'], &['a', 'a', 'a']), 1);
+        assert_eq!(list.to_string_chars(), "aaaa");
+        assert_eq!(list.replace_all_sequences(&['a'], &[]), 4);
+        assert!(list.is_empty());
+        assert_eq!(list.replace_all_sequences(&['a'], &['b']), 0);
+
+        let mut list = CdlList::from("ab-ab-ab");
+        assert_eq!(list.replace_all_sequences(&['a', 'b'], &['c', 'd', 'e']), 3);
+        assert_eq!(list.to_string_chars(), "cde-cde-cde");
+        assert_list_invariants!(list);
+
+        // against str::replace, which also takes non-overlapping matches from 
+        // the left, including needles that partly match themselves
+        let mut state : u64 = 17;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+        for _ in 0..300 {
+            let text : String = (0..next() % 30).map(|_| if next() % 3 == 0 { 'b' } else { 'a' }).collect();
+            let needle = ["a", "aa", "ab", "aab", "aba", "abab", "ba"][next() % 7];
+            let replacement = ["", "x", "ab", "aab"][next() % 4];
+            let needle_chars : Vec<char> = needle.chars().collect();
+            let replacement_chars : Vec<char> = replacement.chars().collect();
+
+            let mut list = CdlList::from(text.as_str());
+            list.replace_all_sequences(&needle_chars, &replacement_chars);
+            assert_eq!(list.to_string_chars(), text.replace(needle, replacement));
+            assert_list_invariants!(list);
+
+            let mut list = CdlList::from(text.as_str());
+            assert_eq!(list.replace_sequence(&needle_chars, replacement.chars()), text.contains(needle));
+            assert_eq!(list.to_string_chars(), text.replacen(needle, replacement, 1));
+        }
+
+        // the observer sees each match replaced in turn, at its current index
+        let mut list = CdlList::from("xabyab");
+        let events = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = std::rc::Rc::clone(&events);
+        list.set_observer(Box::new(move |event| match event {
+            cdl_list::ListEvent::RemovedAt { index, value } => log.borrow_mut().push(('-', index, *value)),
+            cdl_list::ListEvent::InsertedAt { index, value } => log.borrow_mut().push(('+', index, *value)),
+            _ => {},
+        }));
+        assert_eq!(list.replace_all_sequences(&['a', 'b'], &['c', 'd', 'e']), 2);
+        assert_eq!(*events.borrow(), [
+            ('-', 1, 'a'), ('-', 1, 'b'), ('+', 1, 'c'), ('+', 2, 'd'), ('+', 3, 'e'),
+            ('-', 5, 'a'), ('-', 5, 'b'), ('+', 5, 'c'), ('+', 6, 'd'), ('+', 7, 'e'),
+        ]);
+
+        // one walk, however many matches there are
+        let mut list : CdlList<u32> = (0..200_000).map(|i| i % 2).collect();
+        assert_eq!(list.replace_all_sequences(&[0, 1], &[2]), 100_000);
+        assert_eq!(list.size(), 100_000);
+        assert!(list.iter().all(|x| *x.borrow() == 2));
+    }
+
+    #[test]
+    fn test_rotate_to() {
+        let mut list : CdlList<u32> = CdlList::new();
+        assert!(!list.rotate_to(|_| true));
+
+        list.extend([3, 8, 5, 8, 1]);
+        let h = list.handle_at(3).unwrap();
+
+        // the first match from the head wins, even when a later one exists
+        assert!(list.rotate_to(|x| *x == 8));
+        assert_list_eq!(list, [8, 5, 8, 1, 3]);
+        assert_list_invariants!(list);
+        assert_eq!(list.handle_index(&h), Some(2));
+
+        // a match at the head, or none at all, changes nothing
+        assert!(list.rotate_to(|x| *x == 8));
+        assert!(!list.rotate_to(|x| *x > 10));
+        assert_list_eq!(list, [8, 5, 8, 1, 3]);
+
+        // the predicate stops being called once it matches
+        let mut calls = 0;
+        assert!(list.rotate_to(|x| {
+            calls += 1;
+            *x == 1
+        }));
+        assert_eq!(calls, 4);
+        assert_list_eq!(list, [1, 3, 8, 5, 8]);
+
+        // the tail can become the head
+        assert!(list.rotate_to(|x| *x == 3));
+        assert_list_eq!(list, [3, 8, 5, 8, 1]);
+        assert!(list.rotate_to(|x| *x == 1));
+        assert_list_eq!(list, [1, 3, 8, 5, 8]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_rotate_head_to_handle() {
+        let mut list : CdlList<u32> = CdlList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
+        let head = list.handle_at(0).unwrap();
+        let tail = list.handle_at(5).unwrap();
+        let middle = list.handle_at(3).unwrap();
+
+        // the current head is a no-op
+        assert!(list.rotate_head_to_handle(&head));
+        assert_list_eq!(list, [0, 1, 2, 3, 4, 5]);
+
+        assert!(list.rotate_head_to_handle(&tail));
+        assert_list_eq!(list, [5, 0, 1, 2, 3, 4]);
+        assert_list_invariants!(list);
+
+        assert!(list.rotate_head_to_handle(&middle));
+        assert_list_eq!(list, [3, 4, 5, 0, 1, 2]);
+        assert_list_invariants!(list);
+        assert_eq!(list.handle_index(&head), Some(3));
+
+        // stale handles and handles into other lists change nothing
+        list.remove_at(list.handle_index(&tail).unwrap());
+        assert!(!list.rotate_head_to_handle(&tail));
+        let mut other : CdlList<u32> = CdlList::new();
+        other.push_back(9);
+        assert!(!list.rotate_head_to_handle(&other.handle_at(0).unwrap()));
+        assert_list_eq!(list, [3, 4, 0, 1, 2]);
+
+        let single = other.handle_at(0).unwrap();
+        assert!(other.rotate_head_to_handle(&single));
+        assert_list_eq!(other, [9]);
+        assert_list_invariants!(other);
+    }
+
+    #[test]
+    fn test_split_at_handle() {
+        let mut list : CdlList<u32> = CdlList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
+        let middle = list.handle_at(2).unwrap();
+        let tail = list.handle_at(5).unwrap();
+
+        let mut back = list.split_at_handle(&middle).unwrap();
+        assert_list_eq!(list, [0, 1]);
+        assert_list_eq!(back, [2, 3, 4, 5]);
+        assert_list_invariants!(list);
+        assert_list_invariants!(back);
+
+        // the handles now belong to the new list
+        assert!(list.peek_handle(&middle).is_none());
+        assert!(list.split_at_handle(&tail).is_none());
+        assert_eq!(back.handle_index(&tail), Some(3));
+        *back.peek_handle_mut(&middle).unwrap().borrow_mut() += 10;
+        assert_list_eq!(back, [12, 3, 4, 5]);
+
+        // splitting at the tail leaves a single element behind
+        let last = back.split_at_handle(&tail).unwrap();
+        assert_list_eq!(back, [12, 3, 4]);
+        assert_list_eq!(last, [5]);
+        assert_list_invariants!(back);
+        assert_list_invariants!(last);
+
+        // splitting at the head moves everything
+        let head = list.handle_at(0).unwrap();
+        let all = list.split_at_handle(&head).unwrap();
+        assert!(list.is_empty());
+        assert_list_eq!(all, [0, 1]);
+        assert_list_invariants!(list);
+        assert_list_invariants!(all);
+
+        // stale handles
+        drop(all);
+        assert!(list.split_at_handle(&head).is_none());
+    }
+
+    // Compares by key only, so merges can be checked for stability.
+    #[derive(Debug, Clone, Copy)]
+    struct Keyed(u8, usize);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut empty : CdlList<u32> = CdlList::new();
+        empty.sort();
+        assert!(empty.is_empty());
+        assert_list_invariants!(empty);
+
+        let mut list = cdl_list![7];
+        list.sort();
+        assert_list_eq!(list, [7]);
+        assert_list_invariants!(list);
+
+        // compare against a stable Vec sort, keying on the value and using the 
+        // index to check that equal keys keep their order
+        let mut state : u64 = 3;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as u32
+        };
+        for size in 0..70 {
+            let pairs : Vec<(u32, usize)> = (0..size).map(|i| (next() % 8, i)).collect();
+            let mut list : CdlList<(u32, usize)> = pairs.iter().copied().collect();
+            let mut model = pairs;
+            list.rotate_left(size / 3);
+            model.rotate_left(size / 3);
+
+            list.sort_by_key(|(key, _)| *key);
+            model.sort_by_key(|(key, _)| *key);
+            assert_list_eq!(list, model);
+            assert_list_invariants!(list);
+        }
+
+        // already sorted and reversed inputs, and a descending comparator
+        let mut list : CdlList<_> = (0..100).collect();
+        list.sort();
+        assert!(list.is_sorted());
+        list.sort_by(|a, b| b.cmp(a));
+        assert_list_eq!(list, (0..100).rev().collect::<Vec<_>>());
+        assert_list_invariants!(list);
+
+        // handles follow their elements to their sorted places
+        let mut list = cdl_list![30, 10, 20];
+        let h = list.handle_at(0).unwrap();
+        list.sort();
+        assert_eq!(list.handle_index(&h), Some(2));
+        assert_eq!(*list.peek_handle(&h).unwrap().borrow(), 30);
+
+        // no recursion, however long the list
+        let mut list : CdlList<u32> = (0..200_000).rev().collect();
+        list.sort();
+        assert_eq!(*list.peek_front().unwrap(), 0);
+        assert_eq!(*list.peek_back().unwrap(), 199_999);
+        assert!(list.is_sorted());
+    }
+
+    #[test]
+    fn test_sort_without_ord() {
+        // neither f64 nor this struct is Ord
+        #[derive(Debug, Clone, PartialEq)]
+        struct Reading {
+            sensor: &'static str,
+            value: f64
+        }
+        let reading = |sensor, value| Reading { sensor, value };
+
+        let mut list = CdlList::from([
+            reading("b", 2.5), reading("a", -1.0), reading("c", 2.5), reading("a", 0.5)
+        ]);
+        list.sort_by(|x, y| x.value.total_cmp(&y.value));
+        assert_list_eq!(list, [reading("a", -1.0), reading("a", 0.5), reading("b", 2.5), reading("c", 2.5)]);
+
+        // keys are returned by value, and ties keep their current order
+        list.sort_by_key(|r| std::cmp::Reverse(r.sensor));
+        assert_list_eq!(list, [reading("c", 2.5), reading("b", 2.5), reading("a", -1.0), reading("a", 0.5)]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_sort_panic_keeps_elements() {
+        use std::{panic, rc::Rc};
+
+        let rc = Rc::new(());
+        let mut list : CdlList<(u32, Rc<()>)> = (0..10).rev().map(|i| (i, Rc::clone(&rc))).collect();
+        let h = list.handle_at(3).unwrap();
+        let mut calls = 0;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                assert!(calls < 5, "comparator gave up");
+                a.0.cmp(&b.0)
+            });
+        }));
+        assert!(result.is_err());
+        assert_list_invariants!(list);
+        assert_eq!(Rc::strong_count(&rc), 11);
+        assert_eq!(list.peek_handle(&h).unwrap().borrow().0, 6);
+
+        // every element is still there, and the list sorts fine afterwards
+        list.sort_by_key(|(i, _)| *i);
+        let sorted : Vec<u32> = list.iter().map(|e| e.borrow().0).collect();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+        drop(list);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        // long enough to overflow the stack if the chains were freed recursively
+        let mut list : CdlList<u32> = (0..1_000_000).rev().collect();
+        let mut calls = 0;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                assert!(calls < 1_500_000, "comparator gave up");
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.size(), 1_000_000);
+        list.sort();
+        assert!(list.iter().map(|e| *e.borrow()).eq(0..1_000_000));
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        // empty list, then the head, tail and middle
+        let mut list : CdlList<u32> = CdlList::new();
+        assert_eq!(list.insert_sorted(5), 0);
+        assert_eq!(list.insert_sorted(1), 0);
+        assert_eq!(list.insert_sorted(9), 2);
+        assert_eq!(list.insert_sorted(7), 2);
+        assert_eq!(list.insert_sorted(3), 1);
+        assert_list_eq!(list, [1, 3, 5, 7, 9]);
+        assert_list_invariants!(list);
+
+        // equal elements go after the ones already there, like a stable sort
+        let mut list : CdlList<(u32, char)> = CdlList::new();
+        for (value, tag) in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e'), (2, 'f')] {
+            list.insert_sorted_by_key((value, tag), |(key, _)| *key);
+        }
+        assert_list_eq!(list, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'f'), (3, 'e')]);
+        assert_list_invariants!(list);
+
+        // against a Vec kept sorted by partition_point
+        let mut state : u64 = 5;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as u32
+        };
+        let mut list : CdlList<(u32, usize)> = CdlList::new();
+        let mut model : Vec<(u32, usize)> = Vec::new();
+        for i in 0..200 {
+            let value = (next() % 20, i);
+            let expected = model.partition_point(|(key, _)| *key <= value.0);
+            model.insert(expected, value);
+            assert_eq!(list.insert_sorted_by(value, |x, y| x.0.cmp(&y.0)), expected);
+        }
+        assert_list_eq!(list, model);
+        assert_list_invariants!(list);
+
+        // reported as a single insertion at the returned index
+        let mut list = cdl_list![10, 20, 30];
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::InsertedAt { index, value } = event {
+                log.borrow_mut().push((index, *value));
+            }
+        }));
+        assert_eq!(list.insert_sorted(25), 2);
+        assert_eq!(list.insert_sorted(0), 0);
+        assert_eq!(*events.borrow(), [(2, 25), (0, 0)]);
+        assert_list_eq!(list, [0, 10, 20, 25, 30]);
+    }
+
+    #[test]
+    fn test_merge() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        // empty on either side
+        let mut list : CdlList<u32> = CdlList::new();
+        list.merge(CdlList::new());
+        assert!(list.is_empty());
+        list.merge(cdl_list![1, 3]);
+        assert_list_eq!(list, [1, 3]);
+        assert_list_invariants!(list);
+        list.merge(CdlList::new());
+        assert_list_eq!(list, [1, 3]);
+
+        // stable against a sorted concatenation, tagging which list each came from
+        let mut state : u64 = 11;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as u32
+        };
+        for (n, m) in [(1, 1), (3, 0), (0, 4), (5, 9), (17, 2), (40, 40)] {
+            let mut a : Vec<(u32, char)> = (0..n).map(|_| (next() % 6, 'a')).collect();
+            let mut b : Vec<(u32, char)> = (0..m).map(|_| (next() % 6, 'b')).collect();
+            a.sort_by_key(|(key, _)| *key);
+            b.sort_by_key(|(key, _)| *key);
+
+            let mut list : CdlList<(u32, char)> = a.iter().copied().collect();
+            list.merge_by(b.iter().copied().collect(), |x, y| x.0.cmp(&y.0));
+            let mut model = [a, b].concat();
+            model.sort_by_key(|(key, _)| *key);
+            assert_list_eq!(list, model);
+            assert_list_invariants!(list);
+        }
+
+        // other's elements are reported as insertions at their final indices, 
+        // and its handles now resolve in this list
+        let mut list = cdl_list![2, 4, 6];
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::InsertedAt { index, value } = event {
+                log.borrow_mut().push((index, *value));
+            }
+        }));
+        let other = cdl_list![1, 5, 7, 8];
+        let h = other.handle_at(2).unwrap();
+        list.merge(other);
+        assert_list_eq!(list, [1, 2, 4, 5, 6, 7, 8]);
+        assert_list_invariants!(list);
+        assert_eq!(*events.borrow(), [(0, 1), (3, 5), (5, 7), (6, 8)]);
+        assert_eq!(list.handle_index(&h), Some(5));
+
+        // replaying the events against the original list rebuilds the result
+        let mut replay = vec![2, 4, 6];
+        for &(index, value) in events.borrow().iter() {
+            replay.insert(index, value);
+        }
+        assert_list_eq!(list, replay);
+    }
+
+    #[test]
+    fn test_merge_panic_keeps_elements() {
+        use std::panic;
+
+        let mut list = cdl_list![0, 2, 4, 6];
+        let other = cdl_list![1, 3, 5];
+        let h = other.handle_at(1).unwrap();
+        let mut calls = 0;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.merge_by(other, |a, b| {
+                calls += 1;
+                assert!(calls < 3, "comparator gave up");
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+        assert_list_invariants!(list);
+        assert_eq!(*list.peek_handle(&h).unwrap().borrow(), 3);
+        list.sort();
+        assert_list_eq!(list, [0, 1, 2, 3, 4, 5, 6]);
+
+        // long enough to overflow the stack if the chains were freed recursively
+        let mut list : CdlList<u32> = (0..500_000).map(|i| i * 2).collect();
+        let other : CdlList<u32> = (0..500_000).map(|i| i * 2 + 1).collect();
+        let mut calls = 0;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.merge_by(other, |a, b| {
+                calls += 1;
+                assert!(calls < 250_000, "comparator gave up");
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.size(), 1_000_000);
+        list.sort();
+        assert!(list.iter().map(|e| *e.borrow()).eq(0..1_000_000));
+    }
+
+    #[test]
+    fn test_merge_all() {
+        assert!(CdlList::<u32>::merge_all(vec![]).is_empty());
+        let single = CdlList::merge_all(vec![cdl_list![3, 1, 2]]);
+        assert_list_eq!(single, [3, 1, 2]);
+
+        let shards = vec![
+            cdl_list![1, 5, 9, 9],
+            cdl_list![],
+            cdl_list![2, 5],
+            cdl_list![0, 1, 5, 10, 11, 12],
+            cdl_list![9],
+        ];
+        let merged = CdlList::merge_all(shards);
+        assert_list_eq!(merged, [0, 1, 1, 2, 5, 5, 5, 9, 9, 9, 10, 11, 12]);
+        assert_list_invariants!(merged);
+
+        // equal keys come out in input order, by shard and then within a shard
+        let shards = vec![
+            cdl_list![Keyed(1, 0), Keyed(2, 1), Keyed(2, 2)],
+            cdl_list![Keyed(0, 3), Keyed(2, 4)],
+            cdl_list![Keyed(1, 5), Keyed(2, 6)],
+        ];
+        let merged = CdlList::merge_all(shards);
+        let mut order = Vec::new();
+        let mut cursor = merged.cursor_front();
+        for _ in 0..merged.size() {
+            order.push(cursor.current().unwrap().1);
+            cursor.move_next();
+        }
+        assert_eq!(order, [3, 0, 5, 1, 2, 4, 6]);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_merge_all_matches_sort(
+            shards in proptest::collection::vec(proptest::collection::vec(0u8..8, 0..12), 0..6),
+        ) {
+            let mut expected : Vec<u8> = shards.concat();
+            expected.sort();
+
+            let lists = shards.into_iter()
+                .map(|mut shard| {
+                    shard.sort();
+                    CdlList::from(shard)
+                })
+                .collect();
+            let merged = CdlList::merge_all(lists);
+            assert_list_eq!(merged, expected);
+            proptest::prop_assert!(merged.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_middle_and_split_half() {
+        for n in [0, 1, 2, 3, 4, 5, 100, 101] {
+            let vals : Vec<u32> = (0..n).collect();
+            let list = CdlList::from(vals.clone());
+            let mid = (n as usize).div_ceil(2);
+
+            match list.middle() {
+                Some(m) => assert_eq!(*m.borrow(), vals[mid - 1], "size {}", n),
+                None => assert_eq!(n, 0),
+            }
+
+            let (front, back) = list.split_half();
+            assert_list_eq!(front, vals[..mid]);
+            assert_list_eq!(back, vals[mid..]);
+            assert_list_invariants!(front);
+            assert_list_invariants!(back);
+        }
+    }
+
+    #[test]
+    fn test_traverse() {
+        use std::ops::ControlFlow;
+
+        let list = cdl_list![3, 1, 4, 1, 5, 9, 2, 6];
+
+        let mut seen = Vec::new();
+        let none : Option<()> = list.traverse(|&x| {
+            seen.push(x);
+            ControlFlow::Continue(())
+        });
+        assert!(none.is_none());
+        assert_eq!(seen, [3, 1, 4, 1, 5, 9, 2, 6]);
+
+        seen.clear();
+        let none : Option<()> = list.traverse_rev(|&x| {
+            seen.push(x);
+            ControlFlow::Continue(())
+        });
+        assert!(none.is_none());
+        assert_eq!(seen, [6, 2, 9, 5, 1, 4, 1, 3]);
+
+        // nothing is visited after a break
+        seen.clear();
+        let found = list.traverse(|&x| {
+            seen.push(x);
+            if x > 4 { ControlFlow::Break(x * 10) } else { ControlFlow::Continue(()) }
+        });
+        assert_eq!(found, Some(50));
+        assert_eq!(seen, [3, 1, 4, 1, 5]);
+
+        seen.clear();
+        let found = list.traverse_rev(|&x| {
+            seen.push(x);
+            if x == 1 { ControlFlow::Break("one") } else { ControlFlow::Continue(()) }
+        });
+        assert_eq!(found, Some("one"));
+        assert_eq!(seen, [6, 2, 9, 5, 1]);
+
+        let empty : CdlList<u32> = CdlList::new();
+        let mut calls = 0;
+        assert!(empty.traverse(|_| { calls += 1; ControlFlow::Break(()) }).is_none());
+        assert!(empty.traverse_rev(|_| { calls += 1; ControlFlow::Break(()) }).is_none());
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn test_remove_between() {
+        let mut list : CdlList<_> = (0..8).collect();
+        let h : Vec<_> = (0..8).map(|i| list.handle_at(i).unwrap()).collect();
+
+        // a span that doesn't wrap
+        let removed = list.remove_between(&h[2], &h[5], false).unwrap();
+        assert_list_eq!(removed, [3, 4]);
+        assert_list_eq!(list, [0, 1, 2, 5, 6, 7]);
+        assert_list_invariants!(list);
+        assert_list_invariants!(removed);
+
+        // a span that wraps past the tail, with its handles moving to the result
+        let removed = list.remove_between(&h[6], &h[1], true).unwrap();
+        assert_list_eq!(removed, [6, 7, 0, 1]);
+        assert_list_eq!(list, [2, 5]);
+        assert_list_invariants!(list);
+        assert_list_invariants!(removed);
+        assert_eq!(removed.handle_index(&h[0]), Some(2));
+
+        // stale handles and handles from another list
+        assert!(list.remove_between(&h[0], &h[2], true).is_none());
+        assert!(list.remove_between(&h[2], &h[3], true).is_none());
+        assert_list_eq!(list, [2, 5]);
+
+        // neighbours with nothing strictly between them
+        assert!(list.remove_between(&h[2], &h[5], false).unwrap().is_empty());
+        assert!(list.remove_between(&h[5], &h[2], false).unwrap().is_empty());
+
+        // equal handles
+        assert!(list.remove_between(&h[5], &h[5], false).unwrap().is_empty());
+        assert_list_eq!(list, [2, 5]);
+        let removed = list.remove_between(&h[5], &h[5], true).unwrap();
+        assert_list_eq!(removed, [5]);
+        assert_list_eq!(list, [2]);
+        assert_list_invariants!(list);
+
+        // going all the way around from the head takes everything
+        let mut list : CdlList<_> = (0..4).collect();
+        let from = list.handle_at(1).unwrap();
+        let to = list.handle_at(0).unwrap();
+        let removed = list.remove_between(&from, &to, true).unwrap();
+        assert_list_eq!(removed, [1, 2, 3, 0]);
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_swap_remove_value() {
+        // a match in the middle takes the tail's place, along with its handle
+        let mut list = cdl_list![1, 2, 3, 2, 5];
+        let tail = list.handle_at(4).unwrap();
+        assert_eq!(list.swap_remove_value(&2), Some(2));
+        assert_list_eq!(list, [1, 5, 3, 2]);
+        assert_eq!(list.handle_index(&tail), Some(1));
+        assert_list_invariants!(list);
+
+        // the head
+        assert_eq!(list.swap_remove_value(&1), Some(1));
+        assert_list_eq!(list, [2, 5, 3]);
+        assert_list_invariants!(list);
+
+        // the tail itself just goes
+        assert_eq!(list.swap_remove_value(&3), Some(3));
+        assert_list_eq!(list, [2, 5]);
+        assert_list_invariants!(list);
+
+        // the head of two
+        assert_eq!(list.swap_remove_value(&2), Some(2));
+        assert_list_eq!(list, [5]);
+        assert_list_invariants!(list);
+
+        assert_eq!(list.swap_remove_value(&4), None);
+        assert_list_eq!(list, [5]);
+
+        // the only element
+        assert_eq!(list.swap_remove_value(&5), Some(5));
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+        assert_eq!(list.swap_remove_value(&5), None);
+    }
+
+    #[test]
+    fn test_splitn() {
+        fn splitn(s: &str, n: usize, sep: char) -> Vec<String> {
+            let parts = CdlList::from(s).splitn(n, |&c| c == sep);
+            for part in &parts {
+                assert_list_invariants!(part);
+            }
+            parts.iter().map(|part| part.to_string_chars()).collect()
+        }
+
+        // the examples from str::splitn, checked against it too
+        for (s, n, sep) in [
+            ("Mary had a little lambda", 3, ' '),
+            ("lionXXtigerXleopard", 3, 'X'),
+            ("abcXdef", 1, 'X'),
+            ("", 1, 'X'),
+            ("abc1defXghi", 2, 'X'),
+            ("XabcX", 5, 'X'),
+            ("XabcX", 2, 'X'),
+            ("abc", 5, 'X'),
+            ("", 3, 'X'),
+            ("abcXdef", 0, 'X'),
+        ] {
+            let expected : Vec<String> = s.splitn(n, sep).map(String::from).collect();
+            assert_eq!(splitn(s, n, sep), expected, "{:?}.splitn({}, {:?})", s, n, sep);
+        }
+
+        assert_eq!(splitn("Mary had a little lambda", 3, ' '), ["Mary", "had", "a little lambda"]);
+        assert_eq!(splitn("lionXXtigerXleopard", 3, 'X'), ["lion", "", "tigerXleopard"]);
+        assert_eq!(splitn("abcXdef", 1, 'X'), ["abcXdef"]);
+        assert_eq!(splitn("", 1, 'X'), [""]);
+        assert!(splitn("abcXdef", 0, 'X').is_empty());
+
+        // pred isn't called on the final part
+        let mut calls = 0;
+        let list = cdl_list![1, 0, 2, 0, 3, 0, 4];
+        let parts = list.splitn(2, |&x| { calls += 1; x == 0 });
+        assert_eq!(calls, 2);
+        assert_list_eq!(parts[0], [1]);
+        assert_list_eq!(parts[1], [2, 0, 3, 0, 4]);
+    }
+
+    #[test]
+    fn test_rfind_and_rposition() {
+        let list = cdl_list![5, 1, 4, 1, 5, 9];
+
+        // the tail takes one call
+        let mut calls = 0;
+        let found = list.rfind(|&x| { calls += 1; x == 9 }).unwrap();
+        assert_eq!(*found.borrow(), 9);
+        assert_eq!(calls, 1);
+
+        // the head takes a full walk, and finds the last of the equal elements
+        calls = 0;
+        assert_eq!(list.rposition(|&x| { calls += 1; x == 5 }), Some(4));
+        assert_eq!(calls, 2);
+        calls = 0;
+        assert_eq!(list.rposition(|&x| { calls += 1; x > 0 && x < 5 && x % 2 == 0 }), Some(2));
+        assert_eq!(calls, 4);
+        calls = 0;
+        let found = list.rfind(|&x| { calls += 1; x == 5 && calls == 6 }).unwrap();
+        assert_eq!(*found.borrow(), 5);
+        assert_eq!(calls, 6);
+
+        // an absent match calls pred once per element
+        calls = 0;
+        assert!(list.rfind(|&x| { calls += 1; x == 7 }).is_none());
+        assert_eq!(calls, 6);
+        assert_eq!(list.rposition(|&x| x == 7), None);
+
+        let empty : CdlList<u32> = CdlList::new();
+        assert!(empty.rfind(|_| true).is_none());
+        assert_eq!(empty.rposition(|_| true), None);
+    }
+
+    #[test]
+    fn test_drain_into() {
+        let mut buf = Vec::new();
+        let mut list = cdl_list![1, 2, 3];
+        list.drain_into(&mut buf);
+        assert_eq!(buf, [1, 2, 3]);
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+
+        // existing contents are kept
+        let mut list = cdl_list![4, 5];
+        list.drain_back_into(&mut buf);
+        assert_eq!(buf, [1, 2, 3, 5, 4]);
+        assert!(list.is_empty());
+
+        // a reused buffer only grows when it has to, and never shrinks
+        buf.clear();
+        let mut capacity = buf.capacity();
+        for round in 0..10 {
+            let mut list : CdlList<_> = (0..100).collect();
+            if round % 2 == 0 {
+                list.drain_into(&mut buf);
+                assert_eq!(buf, (0..100).collect::<Vec<_>>());
+            } else {
+                list.drain_back_into(&mut buf);
+                assert_eq!(buf, (0..100).rev().collect::<Vec<_>>());
+            }
+
+            if round > 0 {
+                assert_eq!(buf.capacity(), capacity);
+            }
+            assert!(buf.capacity() >= capacity);
+            capacity = buf.capacity();
+            buf.clear();
+        }
+
+        let mut empty : CdlList<u32> = CdlList::new();
+        empty.drain_into(&mut buf);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_view() {
+        let list : CdlList<u32> = (0..8).collect();
+
+        let view = list.view(2..6).unwrap();
+        assert_eq!(view.len(), 4);
+        assert!(view == &[2, 3, 4, 5][..]);
+        assert!(view != &[2, 3, 4][..]);
+        assert!(view != &[2, 3, 4, 6][..]);
+        assert_eq!(view.iter().map(|e| *e.borrow()).collect::<Vec<_>>(), [2, 3, 4, 5]);
+        assert_eq!(view.iter().len(), 4);
+        assert_eq!(*view.get(3).unwrap().borrow(), 5);
+        assert!(view.get(4).is_none());
+        assert_eq!(view.to_string(), "... <=> 2 <=> 3 <=> 4 <=> 5 <=> ...");
+        assert_eq!(format!("{:?}", view), "[2, 3, 4, 5]");
+
+        // the whole list
+        let all = list.view(..).unwrap();
+        assert_eq!(all.to_string(), list.to_string());
+        assert!(all == &(0..8).collect::<Vec<_>>()[..]);
+
+        // empty and out of bounds ranges
+        let empty = list.view(8..).unwrap();
+        assert!(empty.is_empty());
+        assert!(empty == &[][..]);
+        assert_eq!(empty.to_string(), "None");
+        assert!(list.view(3..9).is_none());
+        assert!(list.view(9..).is_none());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = list.view(5..3);
+        assert!(reversed.is_none());
+    }
+
+    #[test]
+    fn test_freeze_and_thaw() {
+        let list = cdl_list![1, 2, 3];
+        let h = list.handle_at(1).unwrap();
+        let frozen = list.freeze();
+        let shared = frozen.clone();
+
+        // both owners see the same nodes
+        assert!(std::ptr::eq(&*frozen.get(0).unwrap().borrow(), &*shared.get(0).unwrap().borrow()));
+        assert_eq!(shared.iter().map(|e| *e.borrow()).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(frozen.size(), 3);
+        assert!(frozen.get(3).is_none());
+        assert_eq!(frozen.to_string(), "... <=> 1 <=> 2 <=> 3 <=> ...");
+        assert_eq!(format!("{:?}", frozen), "FrozenCdlList([1, 2, 3])");
+
+        // shared, so thawing copies and leaves the other owner alone
+        let shared = shared.try_thaw().unwrap_err();
+        let mut thawed = frozen.thaw();
+        assert!(thawed.peek_handle(&h).is_none());
+        thawed.push_back(4);
+        assert_list_eq!(thawed, [1, 2, 3, 4]);
+        assert_list_invariants!(thawed);
+        assert_eq!(shared.size(), 3);
+
+        // unique, so thawing reuses the nodes
+        let mut thawed = shared.thaw();
+        assert_eq!(thawed.handle_index(&h), Some(1));
+        thawed.push_front(0);
+        assert_list_eq!(thawed, [0, 1, 2, 3]);
+        assert_list_invariants!(thawed);
+
+        let empty : CdlList<u32> = CdlList::new();
+        let frozen = empty.freeze();
+        assert_eq!(frozen.iter().count(), 0);
+        assert!(frozen.try_thaw().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_insert_many() {
+        use cdl_list::{IndexOutOfBounds, ListEvent};
+        use std::{cell::RefCell, rc::Rc};
+
+        // applies the entries one at a time, shifting each index by the 
+        // insertions before it
+        fn model(vals: &[u32], entries: &[(usize, u32)]) -> Vec<u32> {
+            let mut sorted = entries.to_vec();
+            sorted.sort_by_key(|&(index, _)| index);
+            let mut out = vals.to_vec();
+            for (shift, (index, val)) in sorted.into_iter().enumerate() {
+                out.insert(index + shift, val);
+            }
+            out
+        }
+
+        let vals : Vec<u32> = (0..10).collect();
+        let patch = [(7, 100), (0, 101), (10, 102), (3, 103), (7, 104), (3, 105), (10, 106), (9, 107), (0, 108)];
+        let mut list = CdlList::from(vals.clone());
+        let h = list.handle_at(3).unwrap();
+        list.insert_many(&patch).unwrap();
+        assert_list_eq!(list, model(&vals, &patch));
+        assert_list_eq!(list, [101, 108, 0, 1, 2, 103, 105, 3, 4, 5, 6, 100, 104, 7, 8, 107, 9, 102, 106]);
+        assert_list_invariants!(list);
+        assert_eq!(list.handle_index(&h), Some(7));
+
+        // into an empty list, and with no entries
+        let mut empty : CdlList<u32> = CdlList::new();
+        empty.insert_many(&[(0, 1), (0, 2)]).unwrap();
+        assert_list_eq!(empty, [1, 2]);
+        assert_list_invariants!(empty);
+        empty.insert_many(&[]).unwrap();
+        assert_list_eq!(empty, [1, 2]);
+
+        // out of range entries reject the whole batch
+        let mut list = CdlList::from(vals.clone());
+        assert_eq!(list.insert_many(&[(2, 1), (11, 2), (12, 3)]), Err(IndexOutOfBounds { index: 11, size: 10 }));
+        assert_list_eq!(list, vals);
+
+        // observers see final indices, from head to tail
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        let mut list = cdl_list![10, 20];
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::InsertedAt { index, value } = event {
+                log.borrow_mut().push((index, *value));
+            }
+        }));
+        list.insert_many(&[(2, 3), (1, 2), (0, 1)]).unwrap();
+        assert_eq!(*events.borrow(), [(0, 1), (2, 2), (4, 3)]);
+    }
+
+    #[test]
+    fn test_remove_many() {
+        use cdl_list::IndexOutOfBounds;
+
+        let vals : Vec<u32> = (0..10).collect();
+        let mut list = CdlList::from(vals.clone());
+        let h = list.handle_at(5).unwrap();
+        assert_eq!(list.remove_many(&[9, 0, 4, 6, 0]), Ok(vec![0, 4, 6, 9]));
+        assert_list_eq!(list, [1, 2, 3, 5, 7, 8]);
+        assert_list_invariants!(list);
+        assert_eq!(list.handle_index(&h), Some(3));
+
+        assert_eq!(list.remove_many(&[]), Ok(vec![]));
+        assert_list_eq!(list, [1, 2, 3, 5, 7, 8]);
+
+        // out of range indices reject the whole batch
+        assert_eq!(list.remove_many(&[0, 6, 7]), Err(IndexOutOfBounds { index: 6, size: 6 }));
+        assert_list_eq!(list, [1, 2, 3, 5, 7, 8]);
+
+        // every index
+        assert_eq!(list.remove_many(&[5, 4, 3, 2, 1, 0]), Ok(vec![1, 2, 3, 5, 7, 8]));
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+        assert_eq!(list.remove_many(&[0]), Err(IndexOutOfBounds { index: 0, size: 0 }));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_remove_many_matches_model(
+            indices in proptest::collection::vec(0usize..200, 0..150),
+        ) {
+            let vals : Vec<u32> = (0..200).collect();
+            let mut list = CdlList::from(vals.clone());
+
+            let mut sorted = indices.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            let mut model = vals;
+            let mut expected = Vec::new();
+            for &index in sorted.iter().rev() {
+                expected.push(model.remove(index));
+            }
+            expected.reverse();
+
+            proptest::prop_assert_eq!(list.remove_many(&indices), Ok(expected));
+            assert_list_eq!(list, model);
+            proptest::prop_assert!(list.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_split_evenly() {
+        fn split(n: u32, k: usize) -> Vec<Vec<u32>> {
+            let shards = (0..n).collect::<CdlList<_>>().split_evenly(k);
+            assert_eq!(shards.len(), k);
+            shards.iter()
+                .map(|shard| {
+                    assert_list_invariants!(shard);
+                    shard.cycle_collect(shard.size())
+                })
+                .collect()
+        }
+
+        assert_eq!(split(8, 4), [vec![0, 1], vec![2, 3], vec![4, 5], vec![6, 7]]);
+        assert_eq!(split(10, 4), [vec![0, 1, 2], vec![3, 4, 5], vec![6, 7], vec![8, 9]]);
+        assert_eq!(split(5, 1), [vec![0, 1, 2, 3, 4]]);
+        assert_eq!(split(4, 4), [vec![0], vec![1], vec![2], vec![3]]);
+        assert_eq!(split(3, 5), [vec![0], vec![1], vec![2], vec![], vec![]]);
+        assert_eq!(split(0, 3), [Vec::<u32>::new(), vec![], vec![]]);
+
+        // handles follow their elements into the new lists
+        let list : CdlList<_> = (0..7).collect();
+        let h = list.handle_at(6).unwrap();
+        let shards = list.split_evenly(3);
+        assert_eq!(shards[2].handle_index(&h), Some(1));
+        assert!(shards[0].peek_handle(&h).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "0 lists")]
+    fn test_split_evenly_zero() {
+        cdl_list![1, 2].split_evenly(0);
+    }
+
+    #[test]
+    fn test_eq_ignoring_direction() {
+        fn check(a: &str, b: &str, undirected: bool, cyclic_undirected: bool) {
+            let (a_list, b_list) = (CdlList::from(a), CdlList::from(b));
+            assert_eq!(a_list.eq_ignoring_direction(&b_list), undirected, "{:?} vs {:?}", a, b);
+            assert_eq!(b_list.eq_ignoring_direction(&a_list), undirected, "{:?} vs {:?}", b, a);
+            assert_eq!(a_list.eq_cyclic_undirected(&b_list), cyclic_undirected, "{:?} vs {:?}", a, b);
+            assert_eq!(b_list.eq_cyclic_undirected(&a_list), cyclic_undirected, "{:?} vs {:?}", b, a);
+        }
+
+        // asymmetric
+        check("abcd", "abcd", true, true);
+        check("abcd", "dcba", true, true);
+        check("abcd", "bcda", false, true);
+        check("abcd", "cbad", false, true);
+        check("abcd", "acbd", false, false);
+        check("abcd", "abc", false, false);
+
+        // palindromes read the same either way
+        check("racecar", "racecar", true, true);
+        check("racecar", "acecarr", false, true);
+        check("abba", "baab", false, true);
+
+        // periodic
+        check("abab", "baba", true, true);
+        check("aabaab", "abaaba", false, true);
+        check("aab", "aba", false, true);
+        check("aab", "abb", false, false);
+        check("abcabc", "cbacba", true, true);
+        check("abcabc", "acbacb", false, true);
+        check("aaaa", "aaaa", true, true);
+        check("aaaa", "aaab", false, false);
+
+        check("", "", true, true);
+        check("a", "a", true, true);
+        check("a", "b", false, false);
+        check("", "a", false, false);
+    }
+
+    #[test]
+    fn test_iter() {
+        let list = cdl_list![1, 2, 3, 4, 5];
+        assert_eq!(list.iter().map(|x| *x.borrow()).sum::<u32>(), 15);
+        assert_eq!(list.iter().map(|x| *x.borrow()).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+        assert_eq!(list.iter().rev().map(|x| *x.borrow()).collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+        assert_eq!(list.iter().len(), 5);
+        assert_eq!(list.iter().count(), 5);
+
+        // both ends meet in the middle without repeating or wrapping
+        let mut it = list.iter();
+        assert_eq!(*it.next().unwrap().borrow(), 1);
+        assert_eq!(*it.next_back().unwrap().borrow(), 5);
+        assert_eq!(*it.next_back().unwrap().borrow(), 4);
+        assert_eq!(it.len(), 2);
+        assert_eq!(*it.next().unwrap().borrow(), 2);
+        assert_eq!(*it.next().unwrap().borrow(), 3);
+        assert!(it.next().is_none());
+        assert!(it.next_back().is_none());
+        drop(it);
+
+        let mut sum = 0;
+        for x in &list {
+            sum += *x.borrow();
+        }
+        assert_eq!(sum, 15);
+
+        // the list is still usable after iterating by reference
+        let mut list = list;
+        list.push_back(6);
+        let mut seen = Vec::new();
+        for x in &list {
+            seen.push(*x.borrow());
+        }
+        assert_eq!(seen, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.pop_back(), Some(6));
+
+        // iterating borrows rather than pops, and starts at the current head
+        list.rotate_left(2);
+        assert_eq!(list.iter().map(|x| *x.borrow()).collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+        assert_eq!(list.iter().filter(|x| *x.borrow() % 2 == 1).count(), 3);
+        assert_eq!(list.size(), 5);
+        assert_list_eq!(list, [3, 4, 5, 1, 2]);
+
+        let empty : CdlList<u32> = CdlList::new();
+        assert!(empty.iter().next().is_none());
+        assert!(empty.iter().next_back().is_none());
+        assert_eq!(empty.iter().fold(0, |acc, x| acc + *x.borrow()), 0);
+    }
+
+    #[test]
+    fn test_cycle_iter() {
+        let mut list = CdlList::from(vec![1, 2, 3]);
+        let seen : Vec<u32> = list.cycle_iter().take(8).map(|x| *x.borrow()).collect();
+        assert_eq!(seen, [1, 2, 3, 1, 2, 3, 1, 2]);
+        assert_eq!(seen, list.cycle_collect(8));
+        assert_eq!(list.cycle_iter().size_hint(), (usize::MAX, None));
+
+        for start in 0..7 {
+            let seen : Vec<u32> = list.cycle_iter_from(start).take(5).map(|x| *x.borrow()).collect();
+            assert_eq!(seen, list.cycle_collect_from(start, 5));
+        }
+
+        // a clone carries on from the same place independently
+        let mut it = list.cycle_iter_from(1);
+        it.next();
+        let ahead = it.clone();
+        assert_eq!(*it.next().unwrap().borrow(), 3);
+        assert_eq!(ahead.map(|x| *x.borrow()).take(3).collect::<Vec<_>>(), [3, 1, 2]);
+        drop(it);
+
+        // the iterator starts at the head as it is now
+        list.rotate_left(1);
+        assert_eq!(*list.cycle_iter().next().unwrap().borrow(), 2);
+
+        let single = CdlList::from(vec![7]);
+        assert!(single.cycle_iter().take(100).all(|x| *x.borrow() == 7));
+
+        let empty : CdlList<u32> = CdlList::new();
+        assert!(empty.cycle_iter().next().is_none());
+        assert!(empty.cycle_iter_from(3).next().is_none());
+        assert_eq!(empty.cycle_iter().size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list = CdlList::from(vec![1, 2, 3, 4, 5]);
+        for mut x in list.iter_mut() {
+            *x.borrow_mut() *= 2;
+        }
+        assert_list_eq!(list, [2, 4, 6, 8, 10]);
+
+        for mut x in &mut list {
+            *x.borrow_mut() += 1;
+        }
+        assert_list_eq!(list, [3, 5, 7, 9, 11]);
+
+        // walking back from the tail follows the weak prev links
+        let mut n = 0;
+        for mut x in list.iter_mut().rev() {
+            n += 1;
+            *x.borrow_mut() = n;
+        }
+        assert_list_eq!(list, [5, 4, 3, 2, 1]);
+        assert_list_invariants!(list);
+
+        // both ends meet in the middle without repeating or wrapping
+        let mut it = list.iter_mut();
+        assert_eq!(it.len(), 5);
+        *it.next().unwrap().borrow_mut() = 10;
+        *it.next_back().unwrap().borrow_mut() = 50;
+        *it.next_back().unwrap().borrow_mut() = 40;
+        *it.next().unwrap().borrow_mut() = 20;
+        assert_eq!(it.size_hint(), (1, Some(1)));
+        *it.next_back().unwrap().borrow_mut() = 30;
+        assert!(it.next().is_none());
+        assert!(it.next_back().is_none());
+        drop(it);
+        assert_list_eq!(list, [10, 20, 30, 40, 50]);
+
+        // several guards can be held at once, since each borrows its own node
+        let mut elems : Vec<_> = list.iter_mut().collect();
+        let (first, rest) = elems.split_first_mut().unwrap();
+        std::mem::swap(&mut *first.borrow_mut(), &mut *rest[3].borrow_mut());
+        drop(elems);
+        assert_list_eq!(list, [50, 20, 30, 40, 10]);
+
+        let mut empty : CdlList<u32> = CdlList::new();
+        assert!(empty.iter_mut().next().is_none());
+        assert!(empty.iter_mut().next_back().is_none());
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list = cdl_list![1, 2, 3, 4];
+        let mut seen = Vec::new();
+        for x in list {
+            seen.push(x);
+        }
+        assert_eq!(seen, [1, 2, 3, 4]);
+
+        assert_eq!(cdl_list![1, 2, 3].into_iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+
+        let mut it = cdl_list![1, 2, 3, 4].into_iter();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.next_back(), Some(4));
+        assert_eq!(it.collect::<Vec<_>>(), [2, 3]);
+
+        // strings are moved out, not cloned
+        let words = cdl_list![String::from("a"), String::from("b")];
+        assert_eq!(words.into_iter().collect::<String>(), "ab");
+
+        let empty : CdlList<u32> = CdlList::new();
+        assert!(empty.into_iter().next().is_none());
+
+        // size_hint shrinks from both ends, and dropping the iterator part way
+        // through drops the values it never handed out
+        let rc = std::rc::Rc::new(());
+        let mut it = CdlList::from(vec![std::rc::Rc::clone(&rc); 5]).into_iter();
+        assert_eq!(it.size_hint(), (5, Some(5)));
+        drop(it.next());
+        drop(it.next_back());
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(std::rc::Rc::strong_count(&rc), 4);
+        drop(it);
+        assert_eq!(std::rc::Rc::strong_count(&rc), 1);
+
+        // a panic part way through a fold leaves the rest to be dropped 
+        // without recursing
+        let it = (0..1_000_000).collect::<CdlList<u32>>().into_iter();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            it.for_each(|x| assert!(x < 10, "too big"));
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_iter_fold_matches_default() {
+        let list : CdlList<_> = (0..100).collect();
+
+        // going through &mut I uses the default fold, built on next()
+        for take in [0, 1, 37, 100] {
+            let mut it = list.iter();
+            it.nth(take.min(99));
+            let mut default = it.clone();
+            let fast = it.fold(Vec::new(), |mut acc, x| { acc.push(*x.borrow()); acc });
+            let slow = (&mut default).fold(Vec::new(), |mut acc, x| { acc.push(*x.borrow()); acc });
+            assert_eq!(fast, slow);
+        }
+
+        assert_eq!(list.iter().map(|x| *x.borrow()).sum::<u32>(), (0..100).sum::<u32>());
+        assert_eq!(list.iter().rev().map(|x| *x.borrow()).fold(0, |acc, x| acc * 3 % 1009 + x),
+            (&mut list.iter().rev().map(|x| *x.borrow())).fold(0, |acc, x| acc * 3 % 1009 + x));
+
+        let mut fast = Vec::new();
+        list.iter().filter(|x| *x.borrow() % 7 == 0).for_each(|x| fast.push(*x.borrow()));
+        let mut slow = Vec::new();
+        (&mut list.iter()).filter(|x| *x.borrow() % 7 == 0).for_each(|x| slow.push(*x.borrow()));
+        assert_eq!(fast, slow);
+
+        let owned = || (0..50).collect::<CdlList<u32>>().into_iter();
+        assert_eq!(owned().fold(0, |acc, x| acc * 5 % 997 + x), (&mut owned()).fold(0, |acc, x| acc * 5 % 997 + x));
+        let mut fast = Vec::new();
+        owned().for_each(|x| fast.push(x));
+        assert_eq!(fast, (&mut owned()).collect::<Vec<_>>());
+        let mut partly = owned();
+        partly.next_back();
+        assert_eq!(partly.sum::<u32>(), (0..49).sum::<u32>());
+    }
+
+    #[test]
+    fn test_iter_find_matches_default() {
+        // from_fn only ever calls next(), so it goes the default way for every adapter
+        fn by_next<I: Iterator>(mut it: I) -> impl Iterator<Item = I::Item> {
+            std::iter::from_fn(move || it.next())
+        }
+
+        for n in [0, 1, 2, 5, 64] {
+            let list : CdlList<_> = (0..n).collect();
+            for (skip_front, skip_back) in [(0, 0), (1, 0), (0, 1), (2, 3)] {
+                // each pair is the overridden path and the default one, from the 
+                // same partly consumed position
+                let start = || {
+                    let mut it = list.iter();
+                    for _ in 0..skip_front {
+                        it.next();
+                    }
+                    for _ in 0..skip_back {
+                        it.next_back();
+                    }
+                    it
+                };
+                let value = |x: cdl_list::ElemRef<'_, u32>| *x.borrow();
+
+                assert_eq!(start().map(value).sum::<u32>(), by_next(start()).map(value).sum::<u32>());
+                assert_eq!(start().rev().map(value).sum::<u32>(), by_next(start().rev()).map(value).sum::<u32>());
+
+                let (mut fast, mut slow) = (Vec::new(), Vec::new());
+                start().for_each(|x| fast.push(*x.borrow()));
+                by_next(start()).for_each(|x| slow.push(*x.borrow()));
+                assert_eq!(fast, slow);
+
+                for target in [0, 3, n.saturating_sub(1), n + 1] {
+                    let (mut fast, mut slow) = (start(), by_next(start()));
+                    assert_eq!(fast.find(|x| *x.borrow() >= target).map(value), slow.find(|x| *x.borrow() >= target).map(value));
+                    // both carry on from just after the element found
+                    assert_eq!(fast.len(), slow.by_ref().count());
+                    assert_eq!(fast.map(value).collect::<Vec<_>>(), by_next(start()).skip_while(|x| *x.borrow() < target).skip(1).map(value).collect::<Vec<_>>());
+                }
+
+                // the same for the owning iterator
+                let start = || {
+                    let mut it = (0..n).collect::<CdlList<u32>>().into_iter();
+                    for _ in 0..skip_front {
+                        it.next();
+                    }
+                    for _ in 0..skip_back {
+                        it.next_back();
+                    }
+                    it
+                };
+                assert_eq!(start().sum::<u32>(), by_next(start()).sum::<u32>());
+                assert_eq!(start().rev().fold(0, |acc, x| acc * 3 % 1009 + x), by_next(start().rev()).fold(0, |acc, x| acc * 3 % 1009 + x));
+                for target in [0, 3, n + 1] {
+                    let (mut fast, mut slow) = (start(), by_next(start()));
+                    assert_eq!(fast.find(|x| *x >= target), slow.find(|x| *x >= target));
+                    assert_eq!(fast.collect::<Vec<_>>(), slow.collect::<Vec<_>>());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        let list : CdlList<u32> = (1..=10).collect();
+        assert_list_eq!(list, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(list.size(), 10);
+        assert_list_invariants!(list);
+
+        let empty : CdlList<u32> = std::iter::empty().collect();
+        assert!(empty.is_empty());
+        assert_list_invariants!(empty);
+
+        let pushed = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&pushed);
+        let mut list = CdlList::from(vec![1, 2, 3]);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::Pushed { front: false, value } = event {
+                log.borrow_mut().push(*value);
+            }
+        }));
+        list.extend(vec![4, 5, 6]);
+        list.extend(Vec::new());
+        assert_list_eq!(list, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.size(), 6);
+        assert_list_invariants!(list);
+        assert_eq!(*pushed.borrow(), [4, 5, 6]);
+
+        let mut empty : CdlList<u32> = CdlList::new();
+        empty.extend([7, 8]);
+        assert_list_eq!(empty, [7, 8]);
+        assert_list_invariants!(empty);
+
+        let list = CdlList::from([String::from("a"), String::from("b")]);
+        assert_list_eq!(list, [String::from("a"), String::from("b")]);
+        let list = CdlList::<u32>::from([]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_cdl_list_macro() {
+        let list = cdl_list![1, 2, 3];
+        assert_list_eq!(list, [1, 2, 3]);
+        assert_list_invariants!(list);
+
+        let list = cdl_list![String::from("a"), String::from("b"),];
+        assert_list_eq!(list, ["a", "b"]);
+
+        let list : CdlList<u32> = cdl_list![];
+        assert!(list.is_empty());
+
+        // the repeat form clones the value, and evaluates each operand once
+        let mut evaluated = 0;
+        let list = cdl_list![{ evaluated += 1; vec![7] }; 3];
+        assert_eq!(evaluated, 1);
+        assert_list_eq!(list, [vec![7], vec![7], vec![7]]);
+        assert_list_invariants!(list);
+
+        let list : CdlList<u32> = cdl_list![5; 0];
+        assert!(list.is_empty());
+
+        assert_eq!(cdl_list![1; 2], cdl_list![1, 1]);
+        assert_eq!(cdl_list![cdl_list![1, 2], cdl_list![3]].size(), 2);
+    }
+
+    #[test]
+    fn test_std_conversions() {
+        use std::collections::{LinkedList, VecDeque};
+
+        let list = CdlList::from(LinkedList::from([1, 2, 3]));
+        assert_list_eq!(list, [1, 2, 3]);
+        assert_list_invariants!(list);
+        let back : LinkedList<u32> = list.into();
+        assert_eq!(back, LinkedList::from([1, 2, 3]));
+
+        // a deque that has wrapped around its buffer still converts front to back
+        let mut deque = VecDeque::with_capacity(4);
+        deque.extend([3, 4, 5]);
+        deque.pop_front();
+        deque.push_back(6);
+        deque.push_front(3);
+        deque.push_front(2);
+        let list = CdlList::from(deque.clone());
+        assert_list_eq!(list, [2, 3, 4, 5, 6]);
+        assert_list_invariants!(list);
+        assert_eq!(VecDeque::from(list), deque);
+
+        // the list's current head becomes the front
+        let mut list = CdlList::from(vec![String::from("a"), String::from("b"), String::from("c")]);
+        list.rotate_right(1);
+        let deque = VecDeque::from(list);
+        assert_eq!(deque, ["c", "a", "b"]);
+        assert_eq!(LinkedList::from(CdlList::from(deque)).into_iter().collect::<String>(), "cab");
+
+        let empty : CdlList<u32> = CdlList::from(LinkedList::new());
+        assert!(empty.is_empty());
+        assert!(VecDeque::from(empty).is_empty());
+        assert!(CdlList::from(VecDeque::<u32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_extend_front() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        let mut list = CdlList::from(vec![4, 5, 6]);
+        list.extend_front(vec![1, 2, 3]);
+        assert_list_eq!(list, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.size(), 6);
+        assert_list_invariants!(list);
+
+        list.extend_front(Vec::new());
+        list.extend_front([0]);
+        assert_list_eq!(list, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.iter().rev().map(|x| *x.borrow()).collect::<Vec<_>>(), [6, 5, 4, 3, 2, 1, 0]);
+        assert_list_invariants!(list);
+
+        let mut empty : CdlList<u32> = CdlList::new();
+        empty.extend_front([7, 8]);
+        assert_list_eq!(empty, [7, 8]);
+        assert_list_invariants!(empty);
+
+        let mut single = CdlList::from(vec![9]);
+        single.extend_front([8]);
+        assert_list_eq!(single, [8, 9]);
+        assert_list_invariants!(single);
+
+        // replaying the events with push_front rebuilds the same list
+        let mirror = Rc::new(RefCell::new(CdlList::from(vec![3])));
+        let log = Rc::clone(&mirror);
+        let mut list = CdlList::from(vec![3]);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::Pushed { front: true, value } = event {
+                log.borrow_mut().push_front(*value);
+            }
+        }));
+        list.extend_front([0, 1, 2]);
+        assert_list_eq!(list, [0, 1, 2, 3]);
+        assert_eq!(*mirror.borrow(), list);
+
+        // every prepended node is freed with the list
+        let rc = Rc::new(());
+        let mut list = CdlList::from(vec![Rc::clone(&rc)]);
+        list.extend_front(vec![Rc::clone(&rc); 3]);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        drop(list);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_cursor_splicing() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        // round-robin over three tasks, dropping each once it has run twice
+        let mut list = CdlList::from([('a', 0), ('b', 0), ('c', 0)]);
+        let mut order = String::new();
+        let mut cursor = list.cursor_front_mut();
+        while cursor.index().is_some() {
+            let (name, runs) = {
+                let mut task = cursor.current().unwrap();
+                task.1 += 1;
+                *task
+            };
+            order.push(name);
+            if runs == 2 {
+                cursor.remove_current();
+            } else {
+                cursor.move_next();
+            }
+        }
+        drop(cursor);
+        assert_eq!(order, "abcabc");
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+
+        // inserting into an empty list moves the cursor onto the new element
+        let mut list : CdlList<u32> = CdlList::new();
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_before(2);
+        assert_eq!(cursor.index(), Some(0));
+        cursor.insert_before(1);
+        cursor.insert_after(3);
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(*cursor.current().unwrap(), 2);
+        drop(cursor);
+        assert_list_eq!(list, [1, 2, 3]);
+        assert_list_invariants!(list);
+
+        // splicing at the boundary between the tail and the head
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        list.set_observer(Box::new(move |event| {
+            log.borrow_mut().push(match event {
+                ListEvent::InsertedAt { index, value } => (true, index, *value),
+                ListEvent::RemovedAt { index, value } => (false, index, *value),
+                _ => unreachable!(),
+            });
+        }));
+        let mut cursor = list.cursor_back_mut();
+        cursor.insert_after(4);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        cursor.insert_before(0);
+        assert_eq!(cursor.index(), Some(1));
+        drop(cursor);
+        assert_list_eq!(list, [0, 1, 2, 3, 4]);
+        assert_list_invariants!(list);
+
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.remove_current(), Some(0));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(*cursor.current().unwrap(), 1);
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(*cursor.current().unwrap(), 3);
+        drop(cursor);
+        assert_list_eq!(list, [1, 3]);
+        assert_list_invariants!(list);
+
+        assert_eq!(*events.borrow(), [
+            (true, 3, 4), (true, 0, 0),
+            (false, 4, 4), (false, 0, 0), (false, 1, 2)
+        ]);
+    }
+
+    #[test]
+    fn test_cursor_split() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        let mut list = cdl_list![0, 1, 2, 3, 4, 5, 6];
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        list.set_observer(Box::new(move |event| {
+            log.borrow_mut().push(match event {
+                ListEvent::RemovedAt { index, value } => (index, *value),
+                _ => unreachable!(),
+            });
+        }));
+        let (h1, h5) = (list.handle_at(1).unwrap(), list.handle_at(5).unwrap());
+
+        // nothing to split at the ends
+        let mut cursor = list.cursor_front_mut();
+        assert!(cursor.split_before().is_empty());
+        cursor.move_prev();
+        assert!(cursor.split_after().is_empty());
+        assert_eq!(cursor.index(), Some(6));
+
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        let front = cursor.split_before();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(0), 2));
+        cursor.move_next();
+        cursor.move_next();
+        let back = cursor.split_after();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(2), 4));
+        cursor.move_next();
+        assert_eq!((cursor.index(), *cursor.current().unwrap()), (Some(0), 2));
+        drop(cursor);
+
+        assert_list_eq!(front, [0, 1]);
+        assert_list_eq!(list, [2, 3, 4]);
+        assert_list_eq!(back, [5, 6]);
+        assert_list_invariants!(front);
+        assert_list_invariants!(list);
+        assert_list_invariants!(back);
+        assert_eq!(*events.borrow(), [(0, 0), (0, 1), (3, 5), (3, 6)]);
+
+        // handles follow their elements into the new lists
+        assert!(list.peek_handle(&h1).is_none());
+        assert_eq!(front.handle_index(&h1), Some(1));
+        assert_eq!(back.handle_index(&h5), Some(0));
+
+        // a single element has nothing either side
+        let mut single = cdl_list![9];
+        let mut cursor = single.cursor_front_mut();
+        assert!(cursor.split_before().is_empty());
+        assert!(cursor.split_after().is_empty());
+        drop(cursor);
+        assert_list_eq!(single, [9]);
+
+        let mut empty : CdlList<u32> = CdlList::new();
+        let mut cursor = empty.cursor_front_mut();
+        assert!(cursor.split_before().is_empty());
+        assert!(cursor.split_after().is_empty());
+        assert_eq!(cursor.index(), None);
+    }
+
+    #[test]
+    fn test_cursor_edits_match_vec() {
+        // small LCG so the test is reproducible without extra dependencies
+        let mut state : u64 = 7;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+
+        let mut list : CdlList<usize> = CdlList::new();
+        let mut model : Vec<usize> = Vec::new();
+        let mut pos = 0;
+        let mut cursor = list.cursor_front_mut();
+        for step in 0..2000 {
+            match next() % 5 {
+                0 => {
+                    cursor.insert_before(step);
+                    model.insert(pos, step);
+                    pos += 1;
+                    if model.len() == 1 {
+                        pos = 0;
+                    }
+                },
+                1 => {
+                    cursor.insert_after(step);
+                    if model.is_empty() {
+                        model.push(step);
+                    } else {
+                        model.insert(pos + 1, step);
+                    }
+                },
+                2 => {
+                    let removed = if model.is_empty() { None } else { Some(model.remove(pos)) };
+                    assert_eq!(cursor.remove_current(), removed);
+                    if pos == model.len() {
+                        pos = 0;
+                    }
+                },
+                3 if !model.is_empty() => {
+                    cursor.move_next();
+                    pos = (pos + 1) % model.len();
+                },
+                _ if !model.is_empty() => {
+                    cursor.move_prev();
+                    pos = (pos + model.len() - 1) % model.len();
+                },
+                _ => {}
+            }
+
+            assert_eq!(cursor.index(), (!model.is_empty()).then_some(pos));
+            assert_eq!(cursor.current().map(|c| *c), model.get(pos).copied());
+        }
+        drop(cursor);
+        assert_list_eq!(list, model);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_get_and_peek_mut() {
+        let mut empty : CdlList<u32> = CdlList::new();
+        assert!(empty.peek_front_mut().is_none());
+        assert!(empty.peek_back_mut().is_none());
+        assert!(empty.get(0).is_none());
+        assert!(empty.get_mut(0).is_none());
+
+        // with one element, the head and tail are the same
+        let mut list = CdlList::from([1]);
+        *list.peek_front_mut().unwrap() += 1;
+        *list.peek_back_mut().unwrap() += 1;
+        assert_list_eq!(list, [3]);
+
+        let mut list : CdlList<_> = (0..7).collect();
+        for i in 0..7 {
+            assert_eq!(*list.get(i).unwrap().borrow(), i);
+            *list.get_mut(i).unwrap().borrow_mut() *= 10;
+        }
+        assert!(list.get(7).is_none());
+        assert!(list.get_mut(usize::MAX).is_none());
+        assert_list_eq!(list, [0, 10, 20, 30, 40, 50, 60]);
+
+        *list.peek_front_mut().unwrap() = 1;
+        *list.peek_back_mut().unwrap() = 2;
+        assert_list_eq!(list, [1, 10, 20, 30, 40, 50, 2]);
+        assert_list_invariants!(list);
+
+        let mut list = CdlList::from([String::from("a"), String::from("b")]);
+        list.get_mut(1).unwrap().borrow_mut().push('c');
+        list.peek_front_mut().unwrap().insert(0, 'z');
+        assert_list_eq!(list, [String::from("za"), String::from("bc")]);
+    }
+
+    #[test]
+    fn test_index_errors() {
+        use cdl_list::IndexOutOfBounds;
+
+        let mut list : CdlList<u32> = CdlList::new();
+        assert_eq!(list.insert_at(1, 1), Err(IndexOutOfBounds { index: 1, size: 0 }));
+        assert_eq!(list.try_remove_at(0), Err(IndexOutOfBounds { index: 0, size: 0 }));
+        assert_eq!(list.remove_at(1), None);
+        assert!(list.is_empty());
+
+        // a single element is both ends, so these push and pop
+        assert_eq!(list.insert_at(0, 1), Ok(()));
+        assert_eq!(list.insert_at(2, 2), Err(IndexOutOfBounds { index: 2, size: 1 }));
+        assert_eq!(list.try_remove_at(1), Err(IndexOutOfBounds { index: 1, size: 1 }));
+        assert_eq!(list.try_remove_at(0), Ok(1));
+        assert_list_invariants!(list);
+
+        let mut list = cdl_list![0, 1, 2, 3];
+        assert_eq!(list.insert_at(4, 4), Ok(()));
+        assert_eq!(list.insert_at(6, 6), Err(IndexOutOfBounds { index: 6, size: 5 }));
+        assert_eq!(list.try_remove_at(5), Err(IndexOutOfBounds { index: 5, size: 5 }));
+        assert_list_eq!(list, [0, 1, 2, 3, 4]);
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_index_past_midpoint() {
+        // every interior index of lists with odd and even sizes, so some are 
+        // reached walking backwards from the tail
+        for size in 5..=8u32 {
+            for index in 1..size as usize {
+                let mut list : CdlList<_> = (0..size).collect();
+                list.insert_at(index, 100).unwrap();
+                assert_list_invariants!(list);
+                assert_eq!(*list.get(index).unwrap().borrow(), 100);
+                assert_eq!(*list.get_from_back(size as usize - index).unwrap().borrow(), 100);
+
+                assert_eq!(list.try_remove_at(index), Ok(100));
+                assert_list_invariants!(list);
+                assert_list_eq!(list, (0..size).collect::<Vec<_>>());
+
+                let removed = list.try_remove_at(index).unwrap();
+                assert_eq!(removed, index as u32);
+                assert_list_invariants!(list);
+                let expected : Vec<u32> = (0..size).filter(|&x| x != removed).collect();
+                assert_list_eq!(list, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_full_turn() {
+        use std::{cell::Cell, rc::Rc};
+
+        // a full turn either way leaves every node where it was
+        let mut list = cdl_list![1, 2, 3, 4];
+        let head = list.handle_at(0).unwrap();
+        for n in [4, 8, 400] {
+            list.rotate_left(n);
+            list.rotate_right(n);
+            assert_list_eq!(list, [1, 2, 3, 4]);
+            assert_eq!(list.handle_index(&head), Some(0));
+            assert_eq!(*list.peek_front().unwrap(), 1);
+            assert_eq!(*list.peek_back().unwrap(), 4);
+            assert_eq!(list.to_string(), "... <=> 1 <=> 2 <=> 3 <=> 4 <=> ...");
+            assert_list_invariants!(list);
+        }
+
+        // rotating keeps exactly one weak next link, so dropping the list still 
+        // frees every node
+        struct Counted(Rc<Cell<usize>>);
+        impl std::fmt::Debug for Counted {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Counted")
+            }
+        }
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut list : CdlList<Counted> = (0..5).map(|_| Counted(Rc::clone(&drops))).collect();
+        for n in 1..7 {
+            list.rotate_left(n);
+            list.rotate_right(n + 1);
+        }
+        assert!(list.pop_front().is_some());
+        assert!(list.pop_back().is_some());
+        assert_eq!(drops.get(), 2);
+        drop(list);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_append() {
+        use cdl_list::ListEvent;
+        use std::{cell::RefCell, rc::Rc};
+
+        let mut a = cdl_list![1, 2];
+        let mut empty : CdlList<u32> = CdlList::new();
+        a.append(&mut empty);
+        assert_list_eq!(a, [1, 2]);
+        assert_list_invariants!(a);
+
+        empty.append(&mut a);
+        assert!(a.is_empty());
+        assert_list_eq!(empty, [1, 2]);
+        assert_list_invariants!(a);
+        assert_list_invariants!(empty);
+
+        let mut a = empty;
+        let mut b = cdl_list![3, 4, 5];
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let (a_log, b_log) = (Rc::clone(&events), Rc::clone(&events));
+        a.set_observer(Box::new(move |event| {
+            if let ListEvent::Pushed { front: false, value } = event {
+                a_log.borrow_mut().push(('a', *value));
+            }
+        }));
+        b.set_observer(Box::new(move |event| {
+            if let ListEvent::RemovedAt { index: 0, value } = event {
+                b_log.borrow_mut().push(('b', *value));
+            }
+        }));
+        let h = b.handle_at(1).unwrap();
+        a.append(&mut b);
+        assert_list_eq!(a, [1, 2, 3, 4, 5]);
+        assert_eq!(a.size(), 5);
+        assert!(b.is_empty());
+        assert_list_invariants!(a);
+        assert_list_invariants!(b);
+        assert_eq!(*events.borrow(), [('b', 3), ('b', 4), ('b', 5), ('a', 3), ('a', 4), ('a', 5)]);
+
+        // the handle follows its node, even through a second append
+        assert_eq!(a.handle_index(&h), Some(3));
+        assert!(b.peek_handle(&h).is_none());
+        b.push_back(6);
+        assert!(b.peek_handle(&h).is_none());
+        b.append(&mut a);
+        assert_list_eq!(b, [6, 1, 2, 3, 4, 5]);
+        assert_eq!(b.handle_index(&h), Some(4));
+        assert!(a.peek_handle(&h).is_none());
+
+        // and once split off, belongs to the new list
+        let c = b.split_off(3).unwrap();
+        assert_eq!(c.handle_index(&h), Some(1));
+        assert!(b.peek_handle(&h).is_none());
+    }
+
+    #[test]
+    fn test_splice_at() {
+        use cdl_list::{IndexOutOfBounds, ListEvent};
+        use std::{cell::RefCell, rc::Rc};
+
+        let mut list : CdlList<u32> = CdlList::new();
+        let mut other = cdl_list![3, 4];
+        assert_eq!(list.splice_at(1, &mut other), Err(IndexOutOfBounds { index: 1, size: 0 }));
+        assert_eq!(other.size(), 2);
+
+        // into an empty list, then at the back and the front
+        list.splice_at(0, &mut other).unwrap();
+        assert_list_eq!(list, [3, 4]);
+        assert!(other.is_empty());
+        list.splice_at(2, &mut cdl_list![7, 8]).unwrap();
+        list.splice_at(0, &mut cdl_list![1, 2]).unwrap();
+        assert_list_eq!(list, [1, 2, 3, 4, 7, 8]);
+        assert_list_invariants!(list);
+
+        // splicing an empty list changes nothing
+        list.splice_at(3, &mut other).unwrap();
+        assert_list_eq!(list, [1, 2, 3, 4, 7, 8]);
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::InsertedAt { index, value } = event {
+                log.borrow_mut().push((index, *value));
+            }
+        }));
+        let mut middle = cdl_list![5, 6];
+        let h = middle.handle_at(1).unwrap();
+        list.splice_at(4, &mut middle).unwrap();
+        assert_list_eq!(list, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(list.size(), 8);
+        assert!(middle.is_empty());
+        assert_list_invariants!(list);
+        assert_list_invariants!(middle);
+        assert_eq!(*events.borrow(), [(4, 5), (5, 6)]);
+
+        // the handle follows its node, and the emptied list can be reused
+        assert_eq!(list.handle_index(&h), Some(5));
+        assert!(middle.peek_handle(&h).is_none());
+        middle.push_back(0);
+        assert!(middle.peek_handle(&h).is_none());
+
+        // walking backwards crosses every spliced seam
+        let back : Vec<u32> = list.iter().rev().map(|x| *x.borrow()).collect();
+        assert_eq!(back, [8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_split_off() {
+        use cdl_list::IndexOutOfBounds;
+
+        let mut list = cdl_list![0, 1, 2, 3, 4, 5, 6];
+        assert_eq!(list.split_off(8).unwrap_err(), IndexOutOfBounds { index: 8, size: 7 });
+        assert_eq!(list.size(), 7);
+
+        let rest = list.split_off(7).unwrap();
+        assert!(rest.is_empty());
+        assert_list_eq!(list, [0, 1, 2, 3, 4, 5, 6]);
+
+        // past the midpoint, so the cut is found from the tail
+        let back = list.split_off(5).unwrap();
+        assert_list_eq!(list, [0, 1, 2, 3, 4]);
+        assert_list_eq!(back, [5, 6]);
+        assert_list_invariants!(list);
+        assert_list_invariants!(back);
+
+        let middle = list.split_off(1).unwrap();
+        assert_list_eq!(list, [0]);
+        assert_list_eq!(middle, [1, 2, 3, 4]);
+        assert_list_invariants!(list);
+        assert_list_invariants!(middle);
+
+        let all = list.split_off(0).unwrap();
+        assert!(list.is_empty());
+        assert_list_eq!(all, [0]);
+        assert_list_invariants!(list);
+        assert_list_invariants!(all);
+
+        let mut empty : CdlList<u32> = CdlList::new();
+        assert!(empty.split_off(0).unwrap().is_empty());
+        assert!(empty.split_off(1).is_err());
+    }
+
+    #[test]
+    fn test_append_and_split_off_drop() {
+        use std::{cell::Cell, rc::Rc};
+
+        struct Counted(Rc<Cell<usize>>);
+        impl std::fmt::Debug for Counted {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Counted")
+            }
+        }
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let counted = |n| -> CdlList<Counted> { (0..n).map(|_| Counted(Rc::clone(&drops))).collect() };
+
+        let mut a = counted(4);
+        let mut b = counted(3);
+        a.append(&mut b);
+        let c = a.split_off(2).unwrap();
+        let d = a.split_off(0).unwrap();
+        assert_eq!((a.size(), b.size(), c.size(), d.size()), (0, 0, 5, 2));
+        assert_eq!(drops.get(), 0);
+
+        drop(d);
+        assert_eq!(drops.get(), 2);
+        drop(c);
+        assert_eq!(drops.get(), 7);
+        drop((a, b));
+        assert_eq!(drops.get(), 7);
+
+        // a long chain of appends, each into a new list, frees without recursing
+        let mut list = counted(1);
+        for _ in 0..100_000 {
+            let mut next = CdlList::new();
+            next.append(&mut list);
+            list = next;
+        }
+        drop(list);
+        assert_eq!(drops.get(), 8);
+    }
+
+    #[test]
+    fn test_clone_and_eq() {
+        let empty : CdlList<u32> = CdlList::default();
+        let copy = empty.clone();
+        assert!(copy.is_empty());
+        assert_eq!(copy, empty);
+        assert_list_invariants!(copy);
+
+        // a single node links to itself, so the copy must link to its own node
+        let one = cdl_list![String::from("a")];
+        let mut copy = one.clone();
+        assert_eq!(copy, one);
+        assert_list_invariants!(copy);
+        copy.peek_back_mut().unwrap().push('b');
+        copy.push_front(String::from("z"));
+        assert_list_eq!(one, [String::from("a")]);
+        assert_list_eq!(copy, [String::from("z"), String::from("ab")]);
+        assert_list_invariants!(one);
+        assert_list_invariants!(copy);
+        drop(one);
+        assert_list_eq!(copy, [String::from("z"), String::from("ab")]);
+
+        let list = cdl_list![1, 2, 3, 4];
+        let mut copy = list.clone();
+        *copy.get_mut(2).unwrap().borrow_mut() = 30;
+        copy.rotate_left(1);
+        copy.pop_back();
+        assert_list_eq!(list, [1, 2, 3, 4]);
+        assert_list_eq!(copy, [2, 30, 4]);
+
+        // built differently, compared by contents
+        let mut built : CdlList<u32> = CdlList::new();
+        built.push_back(3);
+        built.push_front(1);
+        built.insert_at(1, 2).unwrap();
+        built.push_back(4);
+        assert_eq!(built, list);
+
+        let mut rotated = list.clone();
+        rotated.rotate_left(1);
+        assert_ne!(rotated, list);
+        assert_ne!(cdl_list![1, 2, 3], list);
+        assert_ne!(CdlList::new(), list);
+
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        struct Queue {
+            jobs: CdlList<u32>
+        }
+        let queue = Queue { jobs: list };
+        assert_eq!(queue.clone(), queue);
+        assert_eq!(Queue::default().jobs.size(), 0);
+    }
+
+    #[test]
+    fn test_eq_compares_sizes_first() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(Debug)]
+        struct Counted(u32);
+        impl PartialEq for Counted {
+            fn eq(&self, other: &Self) -> bool {
+                COMPARISONS.with(|c| c.set(c.get() + 1));
+                self.0 == other.0
+            }
+        }
+
+        let list = CdlList::from(vec![Counted(1), Counted(2), Counted(3)]);
+        let longer = CdlList::from(vec![Counted(1), Counted(2), Counted(3), Counted(4)]);
+        assert!(list != longer);
+        assert_eq!(COMPARISONS.with(Cell::get), 0);
+
+        // the walk stops at the first mismatch, and leaves both lists intact
+        let differs = CdlList::from(vec![Counted(1), Counted(9), Counted(3)]);
+        assert!(list != differs);
+        assert_eq!(COMPARISONS.with(Cell::get), 2);
+        assert_eq!(list.size(), 3);
+        assert_eq!(differs.size(), 3);
+
+        let same = CdlList::from(vec![Counted(1), Counted(2), Counted(3)]);
+        assert!(list == same);
+        assert_eq!(COMPARISONS.with(Cell::get), 5);
+    }
+
+    #[test]
+    fn test_eq_slices_and_vecs() {
+        let mut list = cdl_list![1, 2, 3];
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3][..]);
+        assert_eq!(list, &[1, 2, 3][..]);
+        assert_ne!(list, [1, 2]);
+        assert_ne!(list, [1, 2, 3, 4]);
+        assert_ne!(list, vec![1, 2, 4]);
+
+        // compared from the current head
+        list.rotate_right(1);
+        assert_eq!(list, [3, 1, 2]);
+        assert_ne!(list, [1, 2, 3]);
+
+        // elements only need to be comparable, not the same type
+        let words = cdl_list![String::from("a"), String::from("b")];
+        assert_eq!(words, ["a", "b"]);
+        assert_eq!(words, vec!["a", "b"]);
+
+        let empty : CdlList<u32> = CdlList::new();
+        assert_eq!(empty, [0u32; 0]);
+        assert_eq!(empty, Vec::<u32>::new());
+        assert_ne!(empty, [0]);
+    }
+
+    #[test]
+    fn test_ord() {
+        use std::{cmp::Ordering, collections::{BTreeSet, LinkedList}};
+
+        // agrees with Vec and LinkedList on every pair
+        let lists : Vec<Vec<u32>> = vec![vec![], vec![0], vec![1], vec![1, 2], vec![1, 2, 3], vec![1, 3], vec![2, 0]];
+        for a in &lists {
+            for b in &lists {
+                let (x, y) = (CdlList::from(a.clone()), CdlList::from(b.clone()));
+                assert_eq!(x.cmp(&y), a.cmp(b));
+                assert_eq!(x.partial_cmp(&y), Some(a.cmp(b)));
+                let (la, lb) : (LinkedList<u32>, LinkedList<u32>) = (x.into(), y.into());
+                assert_eq!(la.cmp(&lb), a.cmp(b));
+            }
+        }
+
+        // compared from the current head
+        let mut rotated = cdl_list![1, 2, 3];
+        rotated.rotate_left(2);
+        assert!(rotated > cdl_list![2, 3]);
+        assert_eq!(rotated.cmp(&cdl_list![3, 1, 2]), Ordering::Equal);
+
+        let mut sorted = vec![cdl_list![3], cdl_list![1, 2], cdl_list![], cdl_list![1]];
+        sorted.sort();
+        assert_eq!(sorted, [cdl_list![], cdl_list![1], cdl_list![1, 2], cdl_list![3]]);
+
+        // the nodes sit in RefCells, but nothing reachable through &CdlList can
+        // change an element, so a list's place in the set can't go stale
+        #[allow(clippy::mutable_key_type)]
+        let set : BTreeSet<CdlList<u32>> = sorted.into_iter().chain([cdl_list![1]]).collect();
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(&cdl_list![1, 2]));
+
+        // a pair that can't be compared stops the walk, unless an earlier pair differs
+        assert_eq!(cdl_list![1.0, f64::NAN].partial_cmp(&cdl_list![1.0, 2.0]), None);
+        assert_eq!(cdl_list![0.0, f64::NAN].partial_cmp(&cdl_list![1.0, 2.0]), Some(Ordering::Less));
+        assert!(cdl_list![1.5] > cdl_list![1.0, 9.0]);
+    }
+
+    #[test]
+    fn test_clone_is_deep() {
+        use std::rc::Rc;
+
+        // the copy has its own nodes, so handles into the original don't reach it
+        let list = cdl_list![10, 20, 30];
+        let h = list.handle_at(1).unwrap();
+        let copy = list.clone();
+        assert_eq!(list.handle_index(&h), Some(1));
+        assert_eq!(copy.handle_index(&h), None);
+        assert!(copy.peek_handle(&h).is_none());
+
+        // each element is cloned once, and dropping either list leaves the other whole
+        let rc = Rc::new(());
+        let list = CdlList::from(vec![Rc::clone(&rc); 3]);
+        let copy = list.clone();
+        assert_eq!(Rc::strong_count(&rc), 7);
+        drop(list);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        assert_eq!(copy.size(), 3);
+        assert_list_invariants!(copy);
+        drop(copy);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_non_debug_elements() {
+        // closures aren't Debug, but can still be stored and used
+        let mut steps : CdlList<Box<dyn Fn(u32) -> u32>> = CdlList::new();
+        steps.push_back(Box::new(|x| x + 1));
+        steps.push_back(Box::new(|x| x * 2));
+        steps.push_front(Box::new(|x| x * x));
+        assert_eq!(steps.iter().fold(3, |acc, f| f.borrow()(acc)), 20);
+
+        steps.rotate_left(1);
+        assert_eq!(steps.iter().fold(3, |acc, f| f.borrow()(acc)), 64);
+        assert_eq!(steps.cycle_iter().take(5).fold(1, |acc, f| f.borrow()(acc)), 34);
+        assert_list_invariants!(steps);
+
+        let doubled : Vec<_> = steps.extract_if(|f| f(1) == 2).collect();
+        assert_eq!(doubled.len(), 2);
+        assert_eq!(steps.size(), 1);
+
+        // the iterators and cursors only print their position
+        assert_eq!(format!("{:?}", steps.iter()), "Iter(1)");
+        assert_eq!(format!("{:?}", steps.cursor_front()), "Cursor(Some(0))");
+        assert_eq!(steps.into_iter().next().unwrap()(5), 25);
+    }
+
+    #[test]
+    fn test_default_and_take() {
+        let mut list = cdl_list![1, 2, 3];
+        let h = list.handle_at(2).unwrap();
+
+        // the taken list keeps its nodes, so handles follow it
+        let taken = std::mem::take(&mut list);
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+        assert_eq!(taken, [1, 2, 3]);
+        assert_eq!(taken.handle_index(&h), Some(2));
+        assert_eq!(list.handle_index(&h), None);
+
+        // what's left behind is an ordinary empty list
+        list.push_back(4);
+        list.extend_front([3]);
+        assert_eq!(list, [3, 4]);
+        assert_list_invariants!(list);
+
+        fn reset<C: Default>(c: &mut C) -> C {
+            std::mem::take(c)
+        }
+        let mut nested : CdlList<CdlList<u32>> = cdl_list![cdl_list![1], CdlList::default()];
+        let inner = reset(&mut *nested.peek_front_mut().unwrap());
+        assert_eq!(inner, [1]);
+        assert_eq!(nested, [CdlList::new(), CdlList::new()]);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        let hash = |list: &CdlList<u32>| state.hash_one(list);
+
+        let list = cdl_list![1, 2, 3];
+        let mut built = CdlList::new();
+        built.push_front(2);
+        built.push_back(3);
+        built.push_front(1);
+        assert_eq!(hash(&built), hash(&list));
+        assert_eq!(hash(&list.clone()), hash(&list));
+        assert_eq!(hash(&CdlList::new()), hash(&CdlList::default()));
+
+        assert_ne!(hash(&cdl_list![2, 3, 1]), hash(&list));
+        assert_ne!(hash(&cdl_list![1, 2]), hash(&list));
+        assert_ne!(hash(&CdlList::new()), hash(&list));
+
+        // the size prefix keeps nested lists with the same flattened elements apart
+        let nested = |list: &CdlList<CdlList<u32>>| state.hash_one(list);
+        assert_ne!(nested(&cdl_list![cdl_list![1], cdl_list![2, 3]]), nested(&cdl_list![cdl_list![1, 2], cdl_list![3]]));
+        assert_ne!(nested(&cdl_list![cdl_list![]]), nested(&cdl_list![cdl_list![], cdl_list![]]));
+
+        // see test_ord for why the lint doesn't apply
+        #[allow(clippy::mutable_key_type)]
+        let mut counts : std::collections::HashMap<CdlList<u32>, u32> = std::collections::HashMap::new();
+        for key in [cdl_list![1, 2], cdl_list![2, 1], cdl_list![1, 2], CdlList::new()] {
+            *counts.entry(key).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&cdl_list![1, 2]], 2);
+        assert_eq!(counts[&CdlList::new()], 1);
+    }
+
+    #[test]
+    fn test_contains_and_remove_value() {
+        let mut empty : CdlList<u32> = CdlList::new();
+        assert!(!empty.contains(&1));
+        assert_eq!(empty.position_of(&1), None);
+        assert_eq!(empty.remove_value(&1), None);
+        assert!(empty.is_empty());
+
+        let mut list = cdl_list![1, 2, 3, 2, 4, 5];
+        assert!(list.contains(&5));
+        assert!(!list.contains(&6));
+        assert_eq!(list.position_of(&2), Some(1));
+        assert_eq!(list.position_of(&5), Some(5));
+        assert_eq!(list.remove_value(&6), None);
+        assert_list_eq!(list, [1, 2, 3, 2, 4, 5]);
+
+        // middle, taking the first of two matches
+        assert_eq!(list.remove_value(&2), Some(2));
+        assert_list_eq!(list, [1, 3, 2, 4, 5]);
+        assert_list_invariants!(list);
+
+        // head
+        assert_eq!(list.remove_value(&1), Some(1));
+        assert_list_eq!(list, [3, 2, 4, 5]);
+        assert_list_invariants!(list);
+
+        // tail
+        assert_eq!(list.remove_value(&5), Some(5));
+        assert_list_eq!(list, [3, 2, 4]);
+        assert_list_invariants!(list);
+        assert_eq!(list.to_string(), "... <=> 3 <=> 2 <=> 4 <=> ...");
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_front(), Some(3));
+
+        // the only element
+        assert_eq!(list.remove_value(&2), Some(2));
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+        assert_eq!(list.to_string(), "None");
+        list.push_back(7);
+        assert_list_eq!(list, [7]);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut list = cdl_list![1, 2, 3, 4, 5];
+        let mut drain = list.drain();
+        assert_eq!(drain.len(), 5);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next_back(), Some(5));
+        assert_eq!(drain.len(), 3);
+        drop(drain);
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+
+        list.push_back(6);
+        list.push_front(5);
+        assert_eq!(list.drain().rev().collect::<Vec<_>>(), [6, 5]);
+        assert!(list.is_empty());
+        assert_eq!(list.drain().next(), None);
+
+        list.push_back(7);
+        assert_eq!(list.pop_back(), Some(7));
+        assert_list_invariants!(list);
+    }
+
+    #[test]
+    fn test_drain_range() {
+        use std::ops::Bound;
+
+        let mut list = cdl_list![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert!(list.drain_range(3..11).is_none());
+        assert!(list.drain_range((Bound::Excluded(5), Bound::Included(4))).is_none());
+        assert_eq!(list.drain_range(4..4).unwrap().len(), 0);
+        assert_eq!(list.size(), 10);
+
+        // touching the head, then the tail, then the middle from the back
+        let h = list.handle_at(8).unwrap();
+        assert_eq!(list.drain_range(..2).unwrap().collect::<Vec<_>>(), [0, 1]);
+        assert_list_invariants!(list);
+        assert_eq!(list.drain_range(6..).unwrap().collect::<Vec<_>>(), [8, 9]);
+        assert_list_invariants!(list);
+        assert!(list.peek_handle(&h).is_none());
+        assert_eq!(list.drain_range(1..=3).unwrap().rev().collect::<Vec<_>>(), [5, 4, 3]);
+        assert_list_eq!(list, [2, 6, 7]);
+        assert_list_invariants!(list);
+
+        // unreturned elements are dropped with the iterator, not left in the list
+        let mut drained = list.drain_range(..).unwrap();
+        assert_eq!(drained.next(), Some(2));
+        assert!(list.is_empty());
+        assert_list_invariants!(list);
+        drop(drained);
+
+        list.push_back(1);
+        assert_list_eq!(list, [1]);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        use cdl_list::ListEvent;
+        use std::{cell::{Cell, RefCell}, rc::Rc};
+
+        // nothing, then a run across the seam from the tail around to the head
+        let mut list = cdl_list![8, 9, 1, 2, 3, 7, 8, 9];
+        assert_eq!(list.extract_if(|_| false).count(), 0);
+        assert_list_eq!(list, [8, 9, 1, 2, 3, 7, 8, 9]);
+
+        let removed = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&removed);
+        list.set_observer(Box::new(move |event| {
+            if let ListEvent::RemovedAt { index, value } = event {
+                log.borrow_mut().push((index, *value));
+            }
+        }));
+        let taken : Vec<u32> = list.extract_if(|x| *x >= 7).collect();
+        assert_eq!(taken, [8, 9, 7, 8, 9]);
+        assert_list_eq!(list, [1, 2, 3]);
+        assert_list_invariants!(list);
+        assert_eq!(*removed.borrow(), [(0, 8), (0, 9), (3, 7), (3, 8), (3, 9)]);
+
+        // rejected elements may be changed, and stopping early keeps the rest
+        let mut list = cdl_list![1, 2, 3, 4, 5, 6];
+        let mut evens = list.extract_if(|x| {
+            *x *= 10;
+            *x % 20 == 0
+        });
+        assert_eq!(evens.next(), Some(20));
+        drop(evens);
+        assert_list_eq!(list, [10, 3, 4, 5, 6]);
+        assert_list_invariants!(list);
+
+        // everything, leaving a genuinely empty list that still works
+        assert_eq!(list.extract_if(|_| true).collect::<Vec<_>>(), [10, 3, 4, 5, 6]);
+        assert!(list.is_empty());
+        assert!(list.peek_front().is_none() && list.peek_back().is_none());
+        assert_list_invariants!(list);
+        list.push_back(2);
+        list.push_front(1);
+        assert_list_eq!(list, [1, 2]);
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+
+        // the only element, and nothing leaks
+        #[derive(Debug)]
+        struct Counted(u32, Rc<Cell<usize>>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut list : CdlList<Counted> = (0..6).map(|i| Counted(i, Rc::clone(&drops))).collect();
+        let odd : Vec<Counted> = list.extract_if(|c| c.0 % 2 == 1).collect();
+        assert_eq!((odd.len(), list.size(), drops.get()), (3, 3, 0));
+        list.extract_if(|c| c.0 == 0).for_each(drop);
+        assert_eq!(drops.get(), 1);
+        drop(list.drain());
+        assert_eq!(drops.get(), 3);
+        drop(odd);
+        assert_eq!(drops.get(), 6);
+
+        let mut one = cdl_list![1];
+        assert_eq!(one.extract_if(|_| true).next(), Some(1));
+        assert!(one.is_empty());
+        assert_list_invariants!(one);
     }
 }
@@ -0,0 +1,155 @@
+//! A [`cdl_list!`](crate::cdl_list!) macro for building a [`CdlList`], and assertion macros for 
+//! testing code that uses a [`CdlList`] or a [`StaticCdlList`].
+
+use std::fmt::Debug;
+use crate::{cdl_list::CdlList, static_cdl_list::StaticCdlList, sync_cdl_list::SyncCdlList};
+
+/// Creates a [`CdlList`] holding the given elements, like [`vec!`].  The elements 
+/// are pushed to the back in the order given, so the first one becomes the head. 
+/// `cdl_list![value; n]` instead holds `n` clones of `value`, which must be 
+/// `Clone`.
+/// 
+/// ```rust
+/// use cdl_list_rs::{assert_list_eq, cdl_list, cdl_list::CdlList};
+/// 
+/// let list = cdl_list![1, 2, 3];
+/// assert_list_eq!(list, [1, 2, 3]);
+/// 
+/// let zeros : CdlList<u8> = cdl_list![0; 4];
+/// assert_list_eq!(zeros, [0, 0, 0, 0]);
+/// 
+/// let empty : CdlList<u32> = cdl_list![];
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! cdl_list {
+    () => {
+        $crate::cdl_list::CdlList::new()
+    };
+    ($elem:expr; $n:expr) => {
+        ::std::iter::repeat($elem).take($n).collect::<$crate::cdl_list::CdlList<_>>()
+    };
+    ($($x:expr),+ $(,)?) => {{
+        let mut list = $crate::cdl_list::CdlList::new();
+        $(list.push_back($x);)+
+        list
+    }};
+}
+
+/// Asserts that a list contains exactly the given elements, in order from head to 
+/// tail, without modifying the list.  The expected elements may be given as an 
+/// array, a `Vec`, or a slice.
+/// 
+/// On failure, the panic message shows both sequences and the first index at 
+/// which they differ.
+/// 
+/// ```rust
+/// use cdl_list_rs::{assert_list_eq, cdl_list::CdlList};
+/// 
+/// let mut list : CdlList<u32> = CdlList::new();
+/// list.push_back(1);
+/// list.push_back(2);
+/// list.push_front(3);
+/// 
+/// assert_list_eq!(list, [3, 1, 2]);
+/// assert_eq!(list.size(), 3);
+/// ```
+#[macro_export]
+macro_rules! assert_list_eq {
+    ($list:expr, $expected:expr $(,)?) => {
+        $crate::macros::assert_list_eq_impl(&$list, &$expected[..])
+    };
+}
+
+/// Asserts that a list's internal links are consistent, panicking with the 
+/// description returned by [`CdlList::validate()`] or [`StaticCdlList::validate()`] 
+/// otherwise.
+/// 
+/// ```rust
+/// use cdl_list_rs::{assert_list_invariants, cdl_list::CdlList};
+/// 
+/// let mut list : CdlList<u32> = CdlList::new();
+/// list.push_back(1);
+/// list.push_back(2);
+/// list.remove_at(0);
+/// 
+/// assert_list_invariants!(list);
+/// ```
+#[macro_export]
+macro_rules! assert_list_invariants {
+    ($list:expr $(,)?) => {
+        if let Err(e) = $list.validate() {
+            panic!("list invariants violated: {}", e);
+        }
+    };
+}
+
+// Lets assert_list_eq! compare either kind of list.  with_elements calls f with 
+// the list's elements in order from head to tail.
+#[doc(hidden)]
+pub trait ListElements<T> {
+    fn with_elements(&self, f: &mut dyn FnMut(&[&T]));
+}
+
+impl<T> ListElements<T> for CdlList<T> {
+    fn with_elements(&self, f: &mut dyn FnMut(&[&T])) {
+        let elems : Vec<_> = self.iter().collect();
+        let borrows : Vec<_> = elems.iter().map(|e| e.borrow()).collect();
+        let refs : Vec<&T> = borrows.iter().map(|b| &**b).collect();
+        f(&refs);
+    }
+}
+
+impl<T, const N: usize> ListElements<T> for StaticCdlList<T, N> {
+    fn with_elements(&self, f: &mut dyn FnMut(&[&T])) {
+        let mut refs = Vec::with_capacity(self.size());
+        self.walk(|val| refs.push(val));
+        f(&refs);
+    }
+}
+
+impl<T> ListElements<T> for SyncCdlList<T> {
+    fn with_elements(&self, f: &mut dyn FnMut(&[&T])) {
+        let elems : Vec<_> = self.iter().collect();
+        let guards : Vec<_> = elems.iter().map(|e| e.read()).collect();
+        let refs : Vec<&T> = guards.iter().map(|g| &**g).collect();
+        f(&refs);
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_list_eq_impl<L, T, U>(list: &L, expected: &[U])
+where
+    L: ListElements<T>,
+    T: Debug + PartialEq<U>,
+    U: Debug,
+{
+    // format the failure inside the closure, but panic here so the 
+    // caller's location is reported
+    let mut failure = None;
+    list.with_elements(&mut |actual| failure = check_elements(actual, expected));
+
+    if let Some(msg) = failure {
+        panic!("{}", msg);
+    }
+}
+
+fn check_elements<T, U>(actual: &[&T], expected: &[U]) -> Option<String>
+where
+    T: Debug + PartialEq<U>,
+    U: Debug,
+{
+    let mismatch = actual.iter().zip(expected.iter())
+        .position(|(a, e)| **a != *e)
+        .or(if actual.len() != expected.len() {
+            Some(actual.len().min(expected.len()))
+        } else {
+            None
+        });
+
+    mismatch.map(|i| format!(
+        "assertion `list == expected` failed at index {}\n  actual: {:?}\nexpected: {:?}",
+        i, actual, expected
+    ))
+}
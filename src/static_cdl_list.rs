@@ -0,0 +1,409 @@
+//! A fixed-capacity circular doubly linked list that never allocates.  It has the 
+//! same shape as [`CdlList`](crate::cdl_list::CdlList), but its nodes live in an 
+//! array of `N` slots stored inside the list itself, and links are slot indices 
+//! rather than `Rc<T>`s:
+//! 
+//! ```text
+//!  slots:   [0]     [1]     [2]     [3]
+//!         ┌─────┐ ┌─────┐ ┌─────┐ ┌─────┐
+//!         │  2  │ │  -  │ │  1  │ │  3  │     head = 2, size = 3
+//!         │n: 3 │ │n: - │ │n: 0 │ │n: 2 │     free = 1
+//!         │p: 2 │ │p: - │ │p: 3 │ │p: 0 │
+//!         └─────┘ └─────┘ └─────┘ └─────┘
+//! ```
+//! 
+//! The slots that aren't holding an element are chained together through their 
+//! next index to form a free list, so claiming or releasing a slot takes constant 
+//! time.  Since the list is circular, the tail is always `head.prev` and doesn't 
+//! need to be stored.
+//! 
+//! This module only depends on `core`, so it is available when the crate is built 
+//! without its default `std` feature.
+
+use core::fmt::{self, Debug};
+
+// A single node.  Empty slots have no value and only use next, which points to 
+// the following free slot.
+struct Slot<T> {
+    value: Option<T>,
+    next: usize,
+    prev: usize
+}
+
+/// A circular doubly linked list holding at most `N` elements, as defined in the 
+/// [module-level documentation](`crate::static_cdl_list`).
+/// 
+/// Methods that add an element give it back as `Err(value)` if the list is full.
+pub struct StaticCdlList<T, const N: usize> {
+    slots: [Slot<T>; N],
+    head: usize,
+    free: usize,
+    size: usize
+}
+
+impl<T: Debug, const N: usize> Debug for StaticCdlList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.walk(|val| { list.entry(val); });
+        list.finish()
+    }
+}
+
+impl<T, const N: usize> Default for StaticCdlList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> StaticCdlList<T, N> {
+    /// Creates a new, empty list with room for `N` elements.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// assert_eq!(list.capacity(), 8);
+    /// ```
+    pub fn new() -> StaticCdlList<T, N> {
+        StaticCdlList {
+            // every slot starts out free, chained in order
+            slots: core::array::from_fn(|i| Slot { value: None, next: i + 1, prev: i }),
+            head: 0,
+            free: 0,
+            size: 0
+        }
+    }
+
+    /// Returns whether or not the list is empty.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// assert!(list.is_empty());
+    /// 
+    /// list.push_back(1).unwrap();
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns whether or not the list holds `N` elements, in which case any 
+    /// further insertions will fail.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 2> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// 
+    /// assert!(list.is_full());
+    /// assert_eq!(list.push_back(3), Err(3));
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.size == N
+    }
+
+    /// Returns the number of elements in the list.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// assert_eq!(list.size(), 0);
+    /// 
+    /// list.push_back(1).unwrap();
+    /// assert_eq!(list.size(), 1);
+    /// ```
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the maximum number of elements the list can hold, which is `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Adds an element to the head of the list, or returns it as `Err(t)` if the 
+    /// list is full.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 2> = StaticCdlList::new();
+    /// list.push_front(1).unwrap();
+    /// list.push_front(2).unwrap();
+    /// assert_eq!(list.push_front(3), Err(3));
+    /// 
+    /// assert_eq!(list.peek_front(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self, t: T) -> Result<(), T> {
+        self.insert_at(0, t)
+    }
+
+    /// Adds an element to the tail of the list, or returns it as `Err(t)` if the 
+    /// list is full.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 2> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// assert_eq!(list.push_back(3), Err(3));
+    /// 
+    /// assert_eq!(list.peek_back(), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, t: T) -> Result<(), T> {
+        self.insert_at(self.size, t)
+    }
+
+    /// Removes the element at the head of the list and returns it, or `None` if 
+    /// the list is empty.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// 
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove_at(0)
+    }
+
+    /// Removes the element at the tail of the list and returns it, or `None` if 
+    /// the list is empty.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// 
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove_at(self.size.checked_sub(1)?)
+    }
+
+    /// Returns a reference to the element at the head of the list, or `None` if 
+    /// the list is empty.  Unlike [`CdlList`](crate::cdl_list::CdlList), this is a 
+    /// plain reference, since no `RefCell` is involved.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// assert!(list.peek_front().is_none());
+    /// 
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// assert_eq!(*list.peek_front().unwrap(), 1);
+    /// ```
+    pub fn peek_front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the element at the tail of the list, or `None` if 
+    /// the list is empty.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// assert!(list.peek_back().is_none());
+    /// 
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// assert_eq!(*list.peek_back().unwrap(), 2);
+    /// ```
+    pub fn peek_back(&self) -> Option<&T> {
+        self.get(self.size.checked_sub(1)?)
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if `index` is out 
+    /// of range.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// list.push_back(3).unwrap();
+    /// 
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.size {
+            return None;
+        }
+
+        self.slots[self.slot_at(index)].value.as_ref()
+    }
+
+    /// Inserts an element so that it ends up at `index`, like 
+    /// [`CdlList::insert_at()`](crate::cdl_list::CdlList::insert_at).  The element 
+    /// is given back as `Err(val)` if the list is full or `index > size()`.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 4> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// list.push_back(4).unwrap();
+    /// 
+    /// list.insert_at(2, 3).unwrap();
+    /// assert_eq!(list.get(2), Some(&3));
+    /// 
+    /// // full
+    /// assert_eq!(list.insert_at(0, 0), Err(0));
+    /// ```
+    pub fn insert_at(&mut self, index: usize, val: T) -> Result<(), T> {
+        if index > self.size || self.free == N {
+            return Err(val);
+        }
+
+        // claim the first free slot
+        let slot = self.free;
+        self.free = self.slots[slot].next;
+        self.slots[slot].value = Some(val);
+
+        if self.size == 0 {
+            self.slots[slot].next = slot;
+            self.slots[slot].prev = slot;
+            self.head = slot;
+        } else {
+            // index == size links in before the head, which is the tail position 
+            // of a circular list
+            let next = if index == self.size { self.head } else { self.slot_at(index) };
+            let prev = self.slots[next].prev;
+
+            self.slots[slot].next = next;
+            self.slots[slot].prev = prev;
+            self.slots[prev].next = slot;
+            self.slots[next].prev = slot;
+
+            if index == 0 {
+                self.head = slot;
+            }
+        }
+
+        self.size += 1;
+        Ok(())
+    }
+
+    /// Removes the element at `index` and returns it, or `None` if `index` is out 
+    /// of range.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_back(2).unwrap();
+    /// list.push_back(3).unwrap();
+    /// 
+    /// assert_eq!(list.remove_at(1), Some(2));
+    /// assert_eq!(list.remove_at(2), None);
+    /// assert_eq!(list.size(), 2);
+    /// ```
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.size {
+            return None;
+        }
+
+        let slot = self.slot_at(index);
+        let next = self.slots[slot].next;
+        let prev = self.slots[slot].prev;
+        self.slots[prev].next = next;
+        self.slots[next].prev = prev;
+
+        if index == 0 {
+            self.head = next;
+        }
+        self.size -= 1;
+
+        // return the slot to the free list
+        self.slots[slot].next = self.free;
+        self.free = slot;
+        self.slots[slot].value.take()
+    }
+
+    /// Checks that the list's internal links are consistent, returning a 
+    /// description of the first problem found.  Walking forward from the head must 
+    /// visit `size()` occupied slots before returning to the head, every slot's 
+    /// prev index must point back at the slot before it, and the remaining slots 
+    /// must all be on the free list.
+    /// 
+    /// This is intended for tests and debugging; a list built only through this 
+    /// crate's API should always be valid.
+    /// 
+    /// ```rust
+    /// # use cdl_list_rs::static_cdl_list::StaticCdlList;
+    /// let mut list : StaticCdlList<u32, 8> = StaticCdlList::new();
+    /// list.push_back(1).unwrap();
+    /// list.push_front(2).unwrap();
+    /// list.insert_at(1, 3).unwrap();
+    /// assert!(list.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let mut slot = self.head;
+        for _ in 0..self.size {
+            let next = self.slots[slot].next;
+            if self.slots[slot].value.is_none() {
+                return Err("a linked slot is empty");
+            }
+            if next >= N || self.slots[next].prev != slot {
+                return Err("a next link is not mirrored by a prev link");
+            }
+            slot = next;
+        }
+        if self.size > 0 && slot != self.head {
+            return Err("the list does not loop back to the head after size elements");
+        }
+
+        let mut free = 0;
+        let mut slot = self.free;
+        while slot != N {
+            if slot > N || free == N {
+                return Err("the free list is malformed");
+            }
+            if self.slots[slot].value.is_some() {
+                return Err("a free slot holds a value");
+            }
+            slot = self.slots[slot].next;
+            free += 1;
+        }
+        if free + self.size != N {
+            return Err("some slots are neither linked nor free");
+        }
+
+        Ok(())
+    }
+
+    // Calls f on each element from head to tail.
+    pub(crate) fn walk<'a, F: FnMut(&'a T)>(&'a self, mut f: F) {
+        let mut slot = self.head;
+        for _ in 0..self.size {
+            f(self.slots[slot].value.as_ref().unwrap());
+            slot = self.slots[slot].next;
+        }
+    }
+
+    // Returns the slot holding the element at index, walking from whichever end 
+    // is closer.
+    fn slot_at(&self, index: usize) -> usize {
+        let mut slot = self.head;
+        if index <= self.size / 2 {
+            for _ in 0..index {
+                slot = self.slots[slot].next;
+            }
+        } else {
+            // traverse list in reverse, starting at the tail
+            for _ in index..self.size {
+                slot = self.slots[slot].prev;
+            }
+        }
+        slot
+    }
+}
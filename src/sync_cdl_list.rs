@@ -0,0 +1,610 @@
+//! A circular doubly linked list that can be shared between threads.  It has the 
+//! same shape as [`CdlList`](crate::cdl_list::CdlList), with strong next links, a 
+//! weak link from the tail back to the head, and weak prev links, but each node 
+//! is an `Arc<RwLock<_>>` instead of an `Rc<RefCell<_>>`.  A [`SyncCdlList`] is 
+//! therefore `Send` and `Sync` whenever its elements are.
+//! 
+//! Like `CdlList`, adding, removing, or changing elements takes `&mut self`, so a 
+//! list shared between threads lives behind an `Arc<Mutex<_>>` or 
+//! `Arc<RwLock<_>>`.  Reading through `&self` only takes each node's read lock, 
+//! so several threads can read the same list at once.
+//! 
+//! ```rust
+//! use cdl_list_rs::sync_cdl_list::SyncCdlList;
+//! use std::{sync::{Arc, Mutex}, thread};
+//! 
+//! let list = Arc::new(Mutex::new(SyncCdlList::new()));
+//! let workers : Vec<_> = (0..4).map(|i| {
+//!     let list = Arc::clone(&list);
+//!     thread::spawn(move || list.lock().unwrap().push_back(i))
+//! }).collect();
+//! for worker in workers {
+//!     worker.join().unwrap();
+//! }
+//! 
+//! let list = list.lock().unwrap();
+//! assert_eq!(list.size(), 4);
+//! assert_eq!(list.iter().map(|x| *x.read()).sum::<u32>(), 6);
+//! ```
+//! 
+//! Only the core of `CdlList`'s API is available here.  The list's own methods 
+//! never leave a node's links half updated, so a lock poisoned by a panic while 
+//! an element was borrowed is ignored, just as a `RefCell` would be.
+
+use std::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak},
+};
+
+use crate::cdl_list::IndexOutOfBounds;
+
+type NodeRef<T> = Arc<RwLock<Node<T>>>;
+type WeakNodeRef<T> = Weak<RwLock<Node<T>>>;
+
+// node->next, which is only a weak link from the tail to the head
+enum Link<T> {
+    Strong(NodeRef<T>),
+    Weak(WeakNodeRef<T>),
+}
+
+// A single node.  A node that isn't linked in yet has dangling weak links.
+struct Node<T> {
+    next: Link<T>,
+    prev: WeakNodeRef<T>,
+    data: T
+}
+
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<T> Node<T> {
+    fn new_ref(t: T) -> NodeRef<T> {
+        Arc::new(RwLock::new(Node { next: Link::Weak(Weak::new()), prev: Weak::new(), data: t }))
+    }
+
+    // Each of these takes the node's lock only for as long as it needs, so none
+    // of them can deadlock against another lock of the same node.
+    fn next_of(node: &NodeRef<T>) -> NodeRef<T> {
+        match &read(node).next {
+            Link::Strong(next) => Arc::clone(next),
+            Link::Weak(next) => next.upgrade().unwrap(),
+        }
+    }
+
+    fn prev_of(node: &NodeRef<T>) -> NodeRef<T> {
+        read(node).prev.upgrade().unwrap()
+    }
+
+    fn set_next(node: &NodeRef<T>, next: Link<T>) {
+        write(node).next = next;
+    }
+
+    fn set_prev(node: &NodeRef<T>, prev: &NodeRef<T>) {
+        write(node).prev = Arc::downgrade(prev);
+    }
+
+    // Takes the data out of a node that has already been unlinked, and so has
+    // exactly one strong reference.
+    fn into_data(node: NodeRef<T>) -> T {
+        match Arc::try_unwrap(node) {
+            Ok(lock) => lock.into_inner().unwrap_or_else(PoisonError::into_inner).data,
+            Err(_) => unreachable!("An unlinked node has no other strong references."),
+        }
+    }
+}
+
+/// A circular doubly linked list built on `Arc` and `RwLock`, as defined in the 
+/// [module-level documentation](`crate::sync_cdl_list`).
+pub struct SyncCdlList<T> {
+    head: Option<NodeRef<T>>,
+    tail: Option<NodeRef<T>>,
+    size: usize
+}
+
+/// A read lock on an element of a [`SyncCdlList`], which dereferences to the 
+/// element.
+pub struct ReadGuard<'a, T>(RwLockReadGuard<'a, Node<T>>);
+
+impl<T> Deref for ReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0.data
+    }
+}
+
+/// A write lock on an element of a [`SyncCdlList`], which dereferences to the 
+/// element.
+pub struct WriteGuard<'a, T>(RwLockWriteGuard<'a, Node<T>>);
+
+impl<T> Deref for WriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0.data
+    }
+}
+
+impl<T> DerefMut for WriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0.data
+    }
+}
+
+/// An element somewhere inside a [`SyncCdlList`], returned by 
+/// [`SyncCdlList::get()`] and [`SyncCdlList::iter()`].  It borrows the list, and 
+/// locks the element for reading with [`ElemRef::read()`].
+pub struct ElemRef<'a, T> {
+    node: NodeRef<T>,
+    _list: PhantomData<&'a SyncCdlList<T>>
+}
+
+impl<T> ElemRef<'_, T> {
+    /// Locks the element for reading.  Any number of threads can read it at once.
+    pub fn read(&self) -> ReadGuard<'_, T> {
+        ReadGuard(read(&self.node))
+    }
+}
+
+// ElemRef holds a strong reference to its node.  This keeps the list borrowed
+// until the ElemRef is dropped, rather than until its last use, so the node
+// can't be removed while it is still referenced.
+impl<T> Drop for ElemRef<'_, T> {
+    fn drop(&mut self) {}
+}
+
+/// A mutable borrow of an element somewhere inside a [`SyncCdlList`], returned 
+/// by [`SyncCdlList::get_mut()`].  It mutably borrows the list, and locks the 
+/// element for writing with [`ElemMut::write()`].
+pub struct ElemMut<'a, T> {
+    node: NodeRef<T>,
+    _list: PhantomData<&'a mut SyncCdlList<T>>
+}
+
+impl<T> ElemMut<'_, T> {
+    /// Locks the element for reading.
+    pub fn read(&self) -> ReadGuard<'_, T> {
+        ReadGuard(read(&self.node))
+    }
+
+    /// Locks the element for writing.
+    pub fn write(&mut self) -> WriteGuard<'_, T> {
+        WriteGuard(write(&self.node))
+    }
+}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for ElemMut<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<T: Debug> Debug for SyncCdlList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for elem in self.iter() {
+            list.entry(&*elem.read());
+        }
+        list.finish()
+    }
+}
+
+impl<T> Default for SyncCdlList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for SyncCdlList<T> {
+    // Dropping the nodes one at a time from the head, rather than letting each
+    // node drop the next, avoids recursing once per node.
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = match Arc::try_unwrap(node).map(|lock| lock.into_inner().unwrap_or_else(PoisonError::into_inner).next) {
+                Ok(Link::Strong(node)) => Some(node),
+                _ => None,
+            };
+        }
+    }
+}
+
+impl<T> SyncCdlList<T> {
+    /// Returns a new, empty list.
+    pub fn new() -> Self {
+        SyncCdlList { head: None, tail: None, size: 0 }
+    }
+
+    /// Returns whether or not the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Adds an element to the head of the list.
+    pub fn push_front(&mut self, t: T) {
+        self.push_back(t);
+        if self.size > 1 {
+            // the ring already runs from the new tail to the old head, so
+            // stepping the head and tail back one place makes it the head
+            let new_tail = Node::prev_of(self.tail.as_ref().unwrap());
+            self.reseat(new_tail);
+        }
+    }
+
+    /// Adds an element to the tail of the list.
+    pub fn push_back(&mut self, t: T) {
+        let ref_n = Node::new_ref(t);
+
+        match (&self.head, self.tail.take()) {
+            (Some(head), Some(tail)) => {
+                {
+                    let mut node = write(&ref_n);
+                    node.next = Link::Weak(Arc::downgrade(head));
+                    node.prev = Arc::downgrade(&tail);
+                }
+
+                Node::set_prev(head, &ref_n);
+                Node::set_next(&tail, Link::Strong(Arc::clone(&ref_n)));
+            },
+            _ => {
+                // node's next and prev links point to self
+                {
+                    let mut node = write(&ref_n);
+                    node.next = Link::Weak(Arc::downgrade(&ref_n));
+                    node.prev = Arc::downgrade(&ref_n);
+                }
+
+                self.head = Some(Arc::clone(&ref_n));
+            }
+        }
+
+        self.tail = Some(ref_n);
+        self.size += 1;
+    }
+
+    /// Removes and returns the element at the head of the list, or `None` if the 
+    /// list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.size > 1 {
+            // make the head the tail, then pop that
+            let new_tail = Arc::clone(self.head.as_ref().unwrap());
+            self.reseat(new_tail);
+        }
+        self.pop_back()
+    }
+
+    /// Removes and returns the element at the tail of the list, or `None` if the 
+    /// list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail.take()?;
+        self.size -= 1;
+
+        if self.size == 0 {
+            self.head = None;
+            return Some(Node::into_data(tail));
+        }
+
+        // replacing prev->next drops the only other strong link to the tail
+        let prev = Node::prev_of(&tail);
+        let head = self.head.as_ref().unwrap();
+        Node::set_next(&prev, Link::Weak(Arc::downgrade(head)));
+        Node::set_prev(head, &prev);
+
+        self.tail = Some(prev);
+        Some(Node::into_data(tail))
+    }
+
+    /// Locks the element at the head of the list for reading, or returns `None` if 
+    /// the list is empty.
+    pub fn peek_front(&self) -> Option<ReadGuard<'_, T>> {
+        self.head.as_ref().map(|head| ReadGuard(read(head)))
+    }
+
+    /// Locks the element at the tail of the list for reading, or returns `None` if 
+    /// the list is empty.
+    pub fn peek_back(&self) -> Option<ReadGuard<'_, T>> {
+        self.tail.as_ref().map(|tail| ReadGuard(read(tail)))
+    }
+
+    /// Locks the element at the head of the list for writing, or returns `None` if 
+    /// the list is empty.
+    pub fn peek_front_mut(&mut self) -> Option<WriteGuard<'_, T>> {
+        self.head.as_ref().map(|head| WriteGuard(write(head)))
+    }
+
+    /// Locks the element at the tail of the list for writing, or returns `None` if 
+    /// the list is empty.
+    pub fn peek_back_mut(&mut self) -> Option<WriteGuard<'_, T>> {
+        self.tail.as_ref().map(|tail| WriteGuard(write(tail)))
+    }
+
+    /// Returns the element at `index`, or `None` if `index` is out of range.  The 
+    /// list is walked from whichever end is closer.
+    pub fn get(&self, index: usize) -> Option<ElemRef<'_, T>> {
+        (index < self.size).then(|| ElemRef { node: self.node_at(index), _list: PhantomData })
+    }
+
+    /// Returns the element at `index` so that it can be changed, or `None` if 
+    /// `index` is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<ElemMut<'_, T>> {
+        (index < self.size).then(|| ElemMut { node: self.node_at(index), _list: PhantomData })
+    }
+
+    /// Returns an iterator over the elements, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.clone(), remaining: self.size, _list: PhantomData }
+    }
+
+    /// Inserts an element at `index`, so `insert_at(0, T)` inserts `T` at the head 
+    /// and `insert_at(size(), T)` at the tail.  Returns an [`IndexOutOfBounds`] if 
+    /// `index` is greater than `size()`, in which case the list is unchanged.
+    pub fn insert_at(&mut self, index: usize, val: T) -> Result<(), IndexOutOfBounds> {
+        if index > self.size {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+        if index == 0 {
+            self.push_front(val);
+            return Ok(());
+        }
+        if index == self.size {
+            self.push_back(val);
+            return Ok(());
+        }
+
+        // prev is not the tail, so prev->next is a strong link
+        let prev = self.node_at(index - 1);
+        let next = Node::next_of(&prev);
+        let ref_n = Node::new_ref(val);
+        {
+            let mut node = write(&ref_n);
+            node.next = Link::Strong(Arc::clone(&next));
+            node.prev = Arc::downgrade(&prev);
+        }
+
+        Node::set_prev(&next, &ref_n);
+        Node::set_next(&prev, Link::Strong(ref_n));
+        self.size += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, or `None` if `index` is out of 
+    /// range.
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.size {
+            return None;
+        }
+        if index == 0 {
+            return self.pop_front();
+        }
+        if index + 1 == self.size {
+            return self.pop_back();
+        }
+
+        // node is in the middle, so node->prev->next and node->next are strong
+        let node = self.node_at(index);
+        let prev = Node::prev_of(&node);
+        let next = Node::next_of(&node);
+        Node::set_next(&node, Link::Weak(Weak::new()));
+
+        Node::set_prev(&next, &prev);
+        Node::set_next(&prev, Link::Strong(next));
+        self.size -= 1;
+
+        Some(Node::into_data(node))
+    }
+
+    /// Rotates the list `n` places to the left, so the element at index `n` becomes 
+    /// the new head.  `n` is taken modulo `size()`, and no elements are moved.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.size < 2 {
+            return;
+        }
+        let mid = n % self.size;
+        if mid == 0 {
+            return;
+        }
+
+        let new_head = self.node_at(mid);
+        self.reseat(Node::prev_of(&new_head));
+    }
+
+    /// Rotates the list `n` places to the right, so the element at index 
+    /// `size() - n` becomes the new head.  `n` is taken modulo `size()`.
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.size < 2 {
+            return;
+        }
+        self.rotate_left(self.size - n % self.size);
+    }
+
+    /// Checks that the list's internal links are consistent, returning a 
+    /// description of the first problem found.  The checks are the same as 
+    /// [`CdlList::validate()`](crate::cdl_list::CdlList::validate).
+    pub fn validate(&self) -> Result<(), String> {
+        let (head, tail) = match (&self.head, &self.tail) {
+            (None, None) if self.size == 0 => return Ok(()),
+            (Some(head), Some(tail)) if self.size > 0 => (head, tail),
+            _ => return Err(format!("head/tail pointers do not match size {}", self.size)),
+        };
+
+        let mut node = Arc::clone(head);
+        for i in 0..self.size {
+            let is_tail = i == self.size - 1;
+
+            let next = match &read(&node).next {
+                Link::Strong(next) if !is_tail => Arc::clone(next),
+                Link::Weak(next) if is_tail => match next.upgrade() {
+                    Some(up) => up,
+                    None => return Err(String::from("tail->next points to a dropped node")),
+                },
+                _ => return Err(format!("node {} has the wrong kind of next link", i)),
+            };
+
+            if !read(&next).prev.upgrade().is_some_and(|p| Arc::ptr_eq(&p, &node)) {
+                return Err(format!("node {}->next->prev does not point back to it", i));
+            }
+
+            if is_tail {
+                if !Arc::ptr_eq(&node, tail) {
+                    return Err(format!("node {} should be the tail", i));
+                }
+                if !Arc::ptr_eq(&next, head) {
+                    return Err(String::from("tail->next does not point to the head"));
+                }
+            } else if Arc::ptr_eq(&next, head) {
+                return Err(format!("list loops back to the head after {} nodes", i + 1));
+            }
+
+            node = next;
+        }
+
+        Ok(())
+    }
+
+    // Returns the node at index, which must be in range, walking from whichever
+    // end is closer.
+    fn node_at(&self, index: usize) -> NodeRef<T> {
+        if index < self.size / 2 {
+            let mut node = Arc::clone(self.head.as_ref().unwrap());
+            for _ in 0..index {
+                node = Node::next_of(&node);
+            }
+            node
+        } else {
+            let mut node = Arc::clone(self.tail.as_ref().unwrap());
+            for _ in index + 1..self.size {
+                node = Node::prev_of(&node);
+            }
+            node
+        }
+    }
+
+    // Makes new_tail, and the node after it, the list's tail and head.  Only the
+    // links at the old and new seams change.
+    fn reseat(&mut self, new_tail: NodeRef<T>) {
+        let old_head = self.head.take().unwrap();
+        let old_tail = self.tail.take().unwrap();
+        let new_head = Node::next_of(&new_tail);
+
+        // close the old seam with a strong link, then open the new one, which
+        // becomes the only weak next link
+        Node::set_next(&old_tail, Link::Strong(old_head));
+        Node::set_next(&new_tail, Link::Weak(Arc::downgrade(&new_head)));
+
+        self.head = Some(new_head);
+        self.tail = Some(new_tail);
+    }
+}
+
+impl<T> FromIterator<T> for SyncCdlList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SyncCdlList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for SyncCdlList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push_back(val);
+        }
+    }
+}
+
+/// An iterator over the elements of a [`SyncCdlList`], created by 
+/// [`SyncCdlList::iter()`].
+pub struct Iter<'a, T> {
+    next: Option<NodeRef<T>>,
+    remaining: usize,
+    _list: PhantomData<&'a SyncCdlList<T>>
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = ElemRef<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.next.take()?;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.next = Some(Node::next_of(&node));
+        }
+        Some(ElemRef { node, _list: PhantomData })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+// See the Drop impl for ElemRef.
+impl<T> Drop for Iter<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<'a, T> IntoIterator for &'a SyncCdlList<T> {
+    type Item = ElemRef<'a, T>;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator that moves the elements out of a [`SyncCdlList`], created by its 
+/// [`IntoIterator`] implementation.
+pub struct IntoIter<T> {
+    list: SyncCdlList<T>
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size(), Some(self.list.size()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for SyncCdlList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
@@ -0,0 +1,431 @@
+//! A circular doubly linked list whose borrows are checked at compile time, 
+//! enabled with the `qcell` feature.  It has the same shape as 
+//! [`CdlList`](crate::cdl_list::CdlList), with strong next links, a weak link from 
+//! the tail back to the head, and weak prev links, but each node is a 
+//! [`TLCell`] from the [`qcell`] crate instead of a `RefCell`.
+//! 
+//! A `TLCell` has no borrow flag.  Instead, its contents can only be reached 
+//! through a [`TLCellOwner`], a token of which there is at most one per marker 
+//! type per thread.  Reading an element takes `&TLCellOwner` and changing one takes 
+//! `&mut TLCellOwner`, so the compiler rules out conflicting borrows and none of 
+//! the list's methods can panic with a borrow error.  Since the token is borrowed 
+//! rather than a guard being created, elements are returned as plain `&T` and 
+//! `&mut T`.
+//! 
+//! ```rust
+//! use cdl_list_rs::token_cdl_list::{TLCellOwner, TokenCdlList};
+//! 
+//! struct Marker;
+//! let mut token = TLCellOwner::<Marker>::new();
+//! 
+//! let mut list : TokenCdlList<Marker, u32> = TokenCdlList::new();
+//! list.push_back(1, &mut token);
+//! list.push_back(2, &mut token);
+//! 
+//! *list.peek_back_mut(&mut token).unwrap() += 10;
+//! assert_eq!(list.peek_back(&token), Some(&12));
+//! ```
+//! 
+//! One token can be used with any number of lists of the same marker type.  Only 
+//! the core of [`CdlList`](crate::cdl_list::CdlList)'s API is available here.
+
+use std::{fmt::{self, Debug}, rc::{Rc, Weak}};
+
+pub use qcell::{TLCell, TLCellOwner};
+
+use crate::cdl_list::IndexOutOfBounds;
+
+type NodeRef<Q, T> = Rc<TLCell<Q, Node<Q, T>>>;
+type WeakNodeRef<Q, T> = Weak<TLCell<Q, Node<Q, T>>>;
+
+// node->next, which is only a weak link from the tail to the head
+enum Link<Q, T> {
+    Strong(NodeRef<Q, T>),
+    Weak(WeakNodeRef<Q, T>),
+}
+
+// A single node.  A node that isn't linked in yet has dangling weak links.
+struct Node<Q, T> {
+    next: Link<Q, T>,
+    prev: WeakNodeRef<Q, T>,
+    data: T
+}
+
+impl<Q, T> Node<Q, T> {
+    fn new_ref(t: T) -> NodeRef<Q, T> {
+        Rc::new(TLCell::new(Node { next: Link::Weak(Weak::new()), prev: Weak::new(), data: t }))
+    }
+
+    fn next_of(node: &NodeRef<Q, T>, owner: &TLCellOwner<Q>) -> NodeRef<Q, T> {
+        match &node.ro(owner).next {
+            Link::Strong(next) => Rc::clone(next),
+            Link::Weak(next) => next.upgrade().unwrap(),
+        }
+    }
+
+    fn prev_of(node: &NodeRef<Q, T>, owner: &TLCellOwner<Q>) -> NodeRef<Q, T> {
+        node.ro(owner).prev.upgrade().unwrap()
+    }
+
+    // Takes the data out of a node that has already been unlinked, and so has
+    // exactly one strong reference.
+    fn into_data(node: NodeRef<Q, T>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(cell) => cell.into_inner().data,
+            Err(_) => unreachable!("An unlinked node has no other strong references."),
+        }
+    }
+}
+
+/// A circular doubly linked list whose nodes are borrowed through a 
+/// [`TLCellOwner<Q>`] token, as defined in the 
+/// [module-level documentation](`crate::token_cdl_list`).
+pub struct TokenCdlList<Q: 'static, T> {
+    head: Option<NodeRef<Q, T>>,
+    tail: Option<NodeRef<Q, T>>,
+    size: usize
+}
+
+impl<Q: 'static, T> Debug for TokenCdlList<Q, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the elements can't be reached without the token
+        f.debug_struct("TokenCdlList")
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Q: 'static, T> Default for TokenCdlList<Q, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q: 'static, T> Drop for TokenCdlList<Q, T> {
+    // Dropping the nodes one at a time from the head, rather than letting each
+    // node drop the next, avoids recursing once per node.  No token is needed,
+    // since every node is taken apart by value.
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = match Rc::try_unwrap(node).map(|cell| cell.into_inner().next) {
+                Ok(Link::Strong(node)) => Some(node),
+                _ => None,
+            };
+        }
+    }
+}
+
+impl<Q: 'static, T> TokenCdlList<Q, T> {
+    /// Returns a new, empty list.
+    pub fn new() -> Self {
+        TokenCdlList { head: None, tail: None, size: 0 }
+    }
+
+    /// Returns whether or not the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Adds an element to the head of the list.
+    pub fn push_front(&mut self, t: T, owner: &mut TLCellOwner<Q>) {
+        self.push_back(t, owner);
+        if self.size > 1 {
+            // the ring already runs from the new tail to the old head, so
+            // stepping the head and tail back one place makes it the head
+            let new_tail = Node::prev_of(self.tail.as_ref().unwrap(), owner);
+            self.rotate_back(new_tail, owner);
+        }
+    }
+
+    /// Adds an element to the tail of the list.
+    pub fn push_back(&mut self, t: T, owner: &mut TLCellOwner<Q>) {
+        let ref_n = Node::new_ref(t);
+
+        match (&self.head, self.tail.take()) {
+            (Some(head), Some(tail)) => {
+                let node = ref_n.rw(owner);
+                node.next = Link::Weak(Rc::downgrade(head));
+                node.prev = Rc::downgrade(&tail);
+
+                head.rw(owner).prev = Rc::downgrade(&ref_n);
+                tail.rw(owner).next = Link::Strong(Rc::clone(&ref_n));
+            },
+            _ => {
+                // node's next and prev links point to self
+                let node = ref_n.rw(owner);
+                node.next = Link::Weak(Rc::downgrade(&ref_n));
+                node.prev = Rc::downgrade(&ref_n);
+
+                self.head = Some(Rc::clone(&ref_n));
+            }
+        }
+
+        self.tail = Some(ref_n);
+        self.size += 1;
+    }
+
+    /// Removes and returns the element at the head of the list, or `None` if the 
+    /// list is empty.
+    pub fn pop_front(&mut self, owner: &mut TLCellOwner<Q>) -> Option<T> {
+        if self.size > 1 {
+            // make the head the tail, then pop that
+            let new_head = Node::next_of(self.head.as_ref().unwrap(), owner);
+            self.rotate_forward(new_head, owner);
+        }
+        self.pop_back(owner)
+    }
+
+    /// Removes and returns the element at the tail of the list, or `None` if the 
+    /// list is empty.
+    pub fn pop_back(&mut self, owner: &mut TLCellOwner<Q>) -> Option<T> {
+        let tail = self.tail.take()?;
+        self.size -= 1;
+
+        if self.size == 0 {
+            self.head = None;
+            return Some(Node::into_data(tail));
+        }
+
+        // replacing prev->next drops the only other strong link to the tail
+        let prev = Node::prev_of(&tail, owner);
+        let head = self.head.as_ref().unwrap();
+        prev.rw(owner).next = Link::Weak(Rc::downgrade(head));
+        head.rw(owner).prev = Rc::downgrade(&prev);
+
+        self.tail = Some(prev);
+        Some(Node::into_data(tail))
+    }
+
+    /// Returns a reference to the element at the head of the list, or `None` if 
+    /// the list is empty.
+    pub fn peek_front<'a>(&'a self, owner: &'a TLCellOwner<Q>) -> Option<&'a T> {
+        self.head.as_ref().map(|head| &head.ro(owner).data)
+    }
+
+    /// Returns a reference to the element at the tail of the list, or `None` if 
+    /// the list is empty.
+    pub fn peek_back<'a>(&'a self, owner: &'a TLCellOwner<Q>) -> Option<&'a T> {
+        self.tail.as_ref().map(|tail| &tail.ro(owner).data)
+    }
+
+    /// Returns a mutable reference to the element at the head of the list, or 
+    /// `None` if the list is empty.  Only the token needs to be borrowed mutably, 
+    /// since it is what guards the elements.
+    pub fn peek_front_mut<'a>(&'a self, owner: &'a mut TLCellOwner<Q>) -> Option<&'a mut T> {
+        self.head.as_ref().map(|head| &mut head.rw(owner).data)
+    }
+
+    /// Returns a mutable reference to the element at the tail of the list, or 
+    /// `None` if the list is empty.
+    pub fn peek_back_mut<'a>(&'a self, owner: &'a mut TLCellOwner<Q>) -> Option<&'a mut T> {
+        self.tail.as_ref().map(|tail| &mut tail.rw(owner).data)
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if `index` is out 
+    /// of range.  The list is walked forward from the head along its strong links, 
+    /// without touching any reference counts.
+    pub fn get<'a>(&'a self, index: usize, owner: &'a TLCellOwner<Q>) -> Option<&'a T> {
+        self.iter(owner).nth(index)
+    }
+
+    /// Returns an iterator over references to the elements, from head to tail.
+    /// 
+    /// ```rust
+    /// use cdl_list_rs::token_cdl_list::{TLCellOwner, TokenCdlList};
+    /// 
+    /// struct Marker;
+    /// let mut token = TLCellOwner::<Marker>::new();
+    /// 
+    /// let mut list : TokenCdlList<Marker, u32> = TokenCdlList::new();
+    /// list.push_back(2, &mut token);
+    /// list.push_front(1, &mut token);
+    /// 
+    /// assert_eq!(list.iter(&token).sum::<u32>(), 3);
+    /// ```
+    pub fn iter<'a>(&'a self, owner: &'a TLCellOwner<Q>) -> Iter<'a, Q, T> {
+        Iter { next: self.head.as_ref(), remaining: self.size, owner }
+    }
+
+    /// Inserts an element at `index`, so `insert_at(0, T)` inserts `T` at the head 
+    /// and `insert_at(size(), T)` at the tail.  Returns an [`IndexOutOfBounds`] if 
+    /// `index` is greater than `size()`, in which case the list is unchanged.
+    pub fn insert_at(&mut self, index: usize, val: T, owner: &mut TLCellOwner<Q>) -> Result<(), IndexOutOfBounds> {
+        if index > self.size {
+            return Err(IndexOutOfBounds { index, size: self.size });
+        }
+        if index == 0 {
+            self.push_front(val, owner);
+            return Ok(());
+        }
+        if index == self.size {
+            self.push_back(val, owner);
+            return Ok(());
+        }
+
+        // prev is not the tail, so prev->next is a strong link
+        let prev = self.node_at(index - 1, owner);
+        let next = Node::next_of(&prev, owner);
+        let ref_n = Node::new_ref(val);
+
+        let node = ref_n.rw(owner);
+        node.next = Link::Strong(Rc::clone(&next));
+        node.prev = Rc::downgrade(&prev);
+
+        next.rw(owner).prev = Rc::downgrade(&ref_n);
+        prev.rw(owner).next = Link::Strong(ref_n);
+        self.size += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, or `None` if `index` is out of 
+    /// range.
+    pub fn remove_at(&mut self, index: usize, owner: &mut TLCellOwner<Q>) -> Option<T> {
+        if index == 0 {
+            return self.pop_front(owner);
+        }
+        if index + 1 >= self.size {
+            return if index + 1 == self.size { self.pop_back(owner) } else { None };
+        }
+
+        // node is in the middle, so node->prev->next and node->next are strong
+        let node = self.node_at(index, owner);
+        let prev = Node::prev_of(&node, owner);
+        let next = Node::next_of(&node, owner);
+        node.rw(owner).next = Link::Weak(Weak::new());
+
+        next.rw(owner).prev = Rc::downgrade(&prev);
+        prev.rw(owner).next = Link::Strong(next);
+        self.size -= 1;
+
+        Some(Node::into_data(node))
+    }
+
+    /// Checks that the list's internal links are consistent, returning a 
+    /// description of the first problem found.  The checks are the same as 
+    /// [`CdlList::validate()`](crate::cdl_list::CdlList::validate).
+    pub fn validate(&self, owner: &TLCellOwner<Q>) -> Result<(), String> {
+        let (head, tail) = match (&self.head, &self.tail) {
+            (None, None) if self.size == 0 => return Ok(()),
+            (Some(head), Some(tail)) if self.size > 0 => (head, tail),
+            _ => return Err(format!("head/tail pointers do not match size {}", self.size)),
+        };
+
+        let mut node = Rc::clone(head);
+        for i in 0..self.size {
+            let is_tail = i == self.size - 1;
+
+            let next = match &node.ro(owner).next {
+                Link::Strong(next) if !is_tail => Rc::clone(next),
+                Link::Weak(next) if is_tail => match next.upgrade() {
+                    Some(up) => up,
+                    None => return Err(String::from("tail->next points to a dropped node")),
+                },
+                _ => return Err(format!("node {} has the wrong kind of next link", i)),
+            };
+
+            if !next.ro(owner).prev.upgrade().is_some_and(|p| Rc::ptr_eq(&p, &node)) {
+                return Err(format!("node {}->next->prev does not point back to it", i));
+            }
+
+            if is_tail {
+                if !Rc::ptr_eq(&node, tail) {
+                    return Err(format!("node {} should be the tail", i));
+                }
+                if !Rc::ptr_eq(&next, head) {
+                    return Err(String::from("tail->next does not point to the head"));
+                }
+            } else if Rc::ptr_eq(&next, head) {
+                return Err(format!("list loops back to the head after {} nodes", i + 1));
+            }
+
+            node = next;
+        }
+
+        Ok(())
+    }
+
+    // Returns the node at index, which must be in range, walking from whichever
+    // end is closer.
+    fn node_at(&self, index: usize, owner: &TLCellOwner<Q>) -> NodeRef<Q, T> {
+        if index < self.size / 2 {
+            let mut node = Rc::clone(self.head.as_ref().unwrap());
+            for _ in 0..index {
+                node = Node::next_of(&node, owner);
+            }
+            node
+        } else {
+            let mut node = Rc::clone(self.tail.as_ref().unwrap());
+            for _ in index + 1..self.size {
+                node = Node::prev_of(&node, owner);
+            }
+            node
+        }
+    }
+
+    // Moves the head and tail forward one place, so new_head (the old head's
+    // next) becomes the head.  Only the links at the seam change.
+    fn rotate_forward(&mut self, new_head: NodeRef<Q, T>, owner: &mut TLCellOwner<Q>) {
+        let old_head = self.head.take().unwrap();
+        let old_tail = self.tail.take().unwrap();
+
+        old_tail.rw(owner).next = Link::Strong(Rc::clone(&old_head));
+        old_head.rw(owner).next = Link::Weak(Rc::downgrade(&new_head));
+
+        self.head = Some(new_head);
+        self.tail = Some(old_head);
+    }
+
+    // Moves the head and tail back one place, so new_tail (the old tail's prev)
+    // becomes the tail.
+    fn rotate_back(&mut self, new_tail: NodeRef<Q, T>, owner: &mut TLCellOwner<Q>) {
+        let old_head = self.head.take().unwrap();
+        let old_tail = self.tail.take().unwrap();
+
+        new_tail.rw(owner).next = Link::Weak(Rc::downgrade(&old_tail));
+        old_tail.rw(owner).next = Link::Strong(old_head);
+
+        self.head = Some(old_tail);
+        self.tail = Some(new_tail);
+    }
+}
+
+/// An iterator over references to the elements of a [`TokenCdlList`], created by 
+/// [`TokenCdlList::iter()`].
+pub struct Iter<'a, Q: 'static, T> {
+    next: Option<&'a NodeRef<Q, T>>,
+    remaining: usize,
+    owner: &'a TLCellOwner<Q>
+}
+
+impl<'a, Q: 'static, T> Iterator for Iter<'a, Q, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.next?.ro(self.owner);
+        self.remaining -= 1;
+
+        // the tail's next link is weak, but the walk stops there anyway
+        self.next = match &node.next {
+            Link::Strong(next) => Some(next),
+            Link::Weak(_) => None,
+        };
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<Q: 'static, T> ExactSizeIterator for Iter<'_, Q, T> {}